@@ -0,0 +1,78 @@
+//! Benchmark the [`rumi::index`] candidate pre-filter against ~10k synthetic
+//! rules, to demonstrate the speedup `evaluate` would see from consulting it
+//! before running the full linear scan.
+//!
+//! Requires a `[[bench]]` entry (and a `criterion` dev-dependency) in this
+//! crate's `Cargo.toml`, which isn't part of this snapshot — run with
+//! `cargo bench` once that manifest exists.
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rumi::index::{build_index, candidates};
+use rumi::{FieldMatcherConfig, MatcherConfig, OnMatchConfig, PredicateConfig, SinglePredicateConfig, StringMatchSpec, TypedConfig, ValueMatchConfig};
+
+const RULE_COUNT: usize = 10_000;
+
+/// `RULE_COUNT` rules, each gated on an exact `role` value drawn from a
+/// small pool — mimicking a real rule set where many rules share an input
+/// but few distinct values, so most rules are *not* candidates for a given
+/// context.
+fn synthetic_config() -> MatcherConfig<TypedConfig> {
+    let roles = ["admin", "viewer", "owner", "editor", "auditor"];
+    let matchers = (0..RULE_COUNT)
+        .map(|i| FieldMatcherConfig {
+            predicate: PredicateConfig::Single(SinglePredicateConfig {
+                input: TypedConfig {
+                    type_url: "role".into(),
+                    config: serde_json::json!({}),
+                },
+                matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(
+                    roles[i % roles.len()].to_string(),
+                )),
+                capture: None,
+            }),
+            on_match: OnMatchConfig::Action {
+                action: TypedConfig {
+                    type_url: format!("rule_{i}"),
+                    config: serde_json::json!({}),
+                },
+                keep_matching: false,
+                rewrite: None,
+            },
+        })
+        .collect();
+    MatcherConfig {
+        matchers,
+        on_no_match: None,
+    }
+}
+
+fn linear_scan_first_match(config: &MatcherConfig<TypedConfig>, context: &HashMap<String, String>) -> Option<usize> {
+    config.matchers.iter().position(|fm| match &fm.predicate {
+        PredicateConfig::Single(sp) => match &sp.matcher {
+            ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(value)) => {
+                context.get(&sp.input.type_url) == Some(value)
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+fn bench_candidate_index(c: &mut Criterion) {
+    let config = synthetic_config();
+    let index = build_index(&config);
+    let context: HashMap<String, String> = [("role".to_string(), "auditor".to_string())].into();
+
+    c.bench_function("linear_scan_10k_rules", |b| {
+        b.iter(|| black_box(linear_scan_first_match(&config, &context)))
+    });
+
+    c.bench_function("candidate_index_10k_rules", |b| {
+        b.iter(|| black_box(candidates(&index, &context)))
+    });
+}
+
+criterion_group!(benches, bench_candidate_index);
+criterion_main!(benches);
@@ -0,0 +1,309 @@
+//! Static reachability and shadowing analysis over a converted
+//! `MatcherConfig<TypedConfig>`, without evaluating it against any context.
+//!
+//! [`analyze`] walks the tree once (the same shape of traversal
+//! `Matcher::evaluate_with_trace` does at runtime, but over config instead
+//! of live data) and records, per node:
+//!
+//! - every action type URL reached through an `OnMatchConfig::Action`,
+//!   structurally reachable by traversal (this does *not* account for
+//!   shadowing below — an action behind a fully-shadowed `FieldMatcher` is
+//!   still counted as "reachable" here, since it's reachable by *walking*
+//!   the tree; use `shadowed` to know it can never actually fire)
+//! - every input type URL referenced by a `SinglePredicateConfig`
+//! - every `FieldMatcher` that can statically never fire because an
+//!   earlier entry in the same first-match-wins list has an
+//!   equal-or-more-general predicate (see [`predicate_subsumes`])
+//!
+//! The traversal recurses through `OnMatchConfig::Matcher`, both as a
+//! `FieldMatcher.on_match` and as `on_no_match`, so nested matchers are
+//! covered too. Shadowing is only checked *within* one `MatcherConfig`'s own
+//! `matchers` list — first-match-wins only orders siblings at the same
+//! level, so an entry in a nested matcher can't shadow (or be shadowed by)
+//! one at a different level.
+
+use std::collections::BTreeSet;
+
+use crate::{MatcherConfig, OnMatchConfig, PredicateConfig, StringMatchSpec, TypedConfig, ValueMatchConfig};
+
+/// A `FieldMatcher` that can statically never fire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowedMatcher {
+    /// Index path of nested matchers leading to the one containing this
+    /// entry: the `FieldMatcher` index at each level on the way down.
+    /// Empty for the top-level matcher. A level reached via `on_no_match`
+    /// rather than a `FieldMatcher.on_match` is marked with `None`.
+    pub matcher_path: Vec<Option<usize>>,
+    /// Index of the shadowed entry within its `MatcherConfig.matchers`.
+    pub index: usize,
+    /// Index of the earlier entry (in the same list) that always fires first.
+    pub shadowed_by_index: usize,
+}
+
+/// The result of [`analyze`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnalysisReport {
+    /// Action type URLs reachable by traversing the tree.
+    pub reachable_actions: BTreeSet<String>,
+    /// Input type URLs referenced by any predicate in the tree.
+    pub referenced_inputs: BTreeSet<String>,
+    /// `FieldMatcher`s that can statically never fire.
+    pub shadowed: Vec<ShadowedMatcher>,
+}
+
+/// Run the static analysis described in the module docs over `matcher`.
+pub fn analyze(matcher: &MatcherConfig<TypedConfig>) -> AnalysisReport {
+    let mut report = AnalysisReport::default();
+    let mut path = Vec::new();
+    analyze_matcher(matcher, &mut report, &mut path);
+    report
+}
+
+fn analyze_matcher(
+    matcher: &MatcherConfig<TypedConfig>,
+    report: &mut AnalysisReport,
+    path: &mut Vec<Option<usize>>,
+) {
+    for (index, field_matcher) in matcher.matchers.iter().enumerate() {
+        collect_inputs(&field_matcher.predicate, &mut report.referenced_inputs);
+
+        if let Some(shadowed_by_index) = (0..index)
+            .find(|&earlier| predicate_subsumes(&matcher.matchers[earlier].predicate, &field_matcher.predicate))
+        {
+            report.shadowed.push(ShadowedMatcher {
+                matcher_path: path.clone(),
+                index,
+                shadowed_by_index,
+            });
+        }
+
+        path.push(Some(index));
+        analyze_on_match(&field_matcher.on_match, report, path);
+        path.pop();
+    }
+
+    if let Some(on_no_match) = &matcher.on_no_match {
+        path.push(None);
+        analyze_on_match(on_no_match, report, path);
+        path.pop();
+    }
+}
+
+fn analyze_on_match(
+    on_match: &OnMatchConfig<TypedConfig>,
+    report: &mut AnalysisReport,
+    path: &mut Vec<Option<usize>>,
+) {
+    match on_match {
+        OnMatchConfig::Action { action, .. } => {
+            report.reachable_actions.insert(action.type_url.clone());
+        }
+        OnMatchConfig::Matcher { matcher, .. } => analyze_matcher(matcher, report, path),
+    }
+}
+
+fn collect_inputs(predicate: &PredicateConfig, inputs: &mut BTreeSet<String>) {
+    match predicate {
+        PredicateConfig::Single(sp) => {
+            inputs.insert(sp.input.type_url.clone());
+        }
+        PredicateConfig::And { predicates } | PredicateConfig::Or { predicates } => {
+            for p in predicates {
+                collect_inputs(p, inputs);
+            }
+        }
+        PredicateConfig::Not { predicate } => collect_inputs(predicate, inputs),
+    }
+}
+
+/// Whether `a` is equal-or-more-general than `b`: whenever `b` would match,
+/// `a` is guaranteed to match too, so if `a` fires first in a
+/// first-match-wins list, `b` can never fire.
+///
+/// This is intentionally conservative (sound but incomplete): it's built
+/// from a small set of rules that are cheap to check and cover the common
+/// cases (identical predicates, and a `Prefix` subsuming a more specific
+/// `Prefix`/`Exact` on the same input). Returning `false` when the two
+/// predicates are in fact equivalent is an acceptable miss; returning `true`
+/// when they're not would wrongly flag a live rule as dead, which isn't.
+pub fn predicate_subsumes(a: &PredicateConfig, b: &PredicateConfig) -> bool {
+    if a == b {
+        return true;
+    }
+
+    match a {
+        // An earlier Or subsumes b if any one branch alone already does:
+        // whichever branch subsumes b guarantees the Or as a whole matches.
+        PredicateConfig::Or { predicates } => predicates.iter().any(|p| predicate_subsumes(p, b)),
+        PredicateConfig::Single(a_single) => match b {
+            // A later And subsumed by a if a subsumes any one of its
+            // conjuncts — the conjunct being true is implied by the And
+            // being true, and a subsumes that conjunct.
+            PredicateConfig::And { predicates } => {
+                predicates.iter().any(|p| predicate_subsumes(a, p))
+            }
+            PredicateConfig::Single(b_single) => {
+                if a_single.input != b_single.input {
+                    return false;
+                }
+                match (&a_single.matcher, &b_single.matcher) {
+                    (
+                        ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(prefix)),
+                        ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(other)),
+                    ) => other.starts_with(prefix.as_str()),
+                    (
+                        ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(prefix)),
+                        ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(value)),
+                    ) => value.starts_with(prefix.as_str()),
+                    _ => false,
+                }
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldMatcherConfig, SinglePredicateConfig};
+
+    fn input(type_url: &str) -> TypedConfig {
+        TypedConfig {
+            type_url: type_url.into(),
+            config: serde_json::json!({}),
+        }
+    }
+
+    fn single(type_url: &str, spec: StringMatchSpec) -> PredicateConfig {
+        PredicateConfig::Single(SinglePredicateConfig {
+            input: input(type_url),
+            matcher: ValueMatchConfig::BuiltIn(spec),
+            capture: None,
+        })
+    }
+
+    fn action(type_url: &str) -> OnMatchConfig<TypedConfig> {
+        OnMatchConfig::Action {
+            action: input(type_url),
+            keep_matching: false,
+            rewrite: None,
+        }
+    }
+
+    #[test]
+    fn collects_reachable_actions_and_inputs() {
+        let config = MatcherConfig {
+            matchers: vec![FieldMatcherConfig {
+                predicate: single("role", StringMatchSpec::Exact("admin".into())),
+                on_match: action("allow"),
+            }],
+            on_no_match: Some(action("deny")),
+        };
+
+        let report = analyze(&config);
+        assert_eq!(
+            report.reachable_actions,
+            BTreeSet::from(["allow".to_string(), "deny".to_string()])
+        );
+        assert_eq!(report.referenced_inputs, BTreeSet::from(["role".to_string()]));
+        assert!(report.shadowed.is_empty());
+    }
+
+    #[test]
+    fn identical_predicate_shadows_later_entry() {
+        let config = MatcherConfig {
+            matchers: vec![
+                FieldMatcherConfig {
+                    predicate: single("role", StringMatchSpec::Exact("admin".into())),
+                    on_match: action("allow"),
+                },
+                FieldMatcherConfig {
+                    predicate: single("role", StringMatchSpec::Exact("admin".into())),
+                    on_match: action("allow_v2"),
+                },
+            ],
+            on_no_match: None,
+        };
+
+        let report = analyze(&config);
+        assert_eq!(report.shadowed.len(), 1);
+        assert_eq!(report.shadowed[0].index, 1);
+        assert_eq!(report.shadowed[0].shadowed_by_index, 0);
+    }
+
+    #[test]
+    fn empty_prefix_shadows_any_later_entry_on_same_input() {
+        let config = MatcherConfig {
+            matchers: vec![
+                FieldMatcherConfig {
+                    predicate: single("path", StringMatchSpec::Prefix(String::new())),
+                    on_match: action("catch_all"),
+                },
+                FieldMatcherConfig {
+                    predicate: single("path", StringMatchSpec::Exact("/health".into())),
+                    on_match: action("health_check"),
+                },
+            ],
+            on_no_match: None,
+        };
+
+        let report = analyze(&config);
+        assert_eq!(report.shadowed.len(), 1);
+        assert_eq!(report.shadowed[0].index, 1);
+    }
+
+    #[test]
+    fn distinct_predicates_do_not_shadow() {
+        let config = MatcherConfig {
+            matchers: vec![
+                FieldMatcherConfig {
+                    predicate: single("role", StringMatchSpec::Exact("admin".into())),
+                    on_match: action("allow"),
+                },
+                FieldMatcherConfig {
+                    predicate: single("role", StringMatchSpec::Exact("viewer".into())),
+                    on_match: action("deny"),
+                },
+            ],
+            on_no_match: None,
+        };
+
+        let report = analyze(&config);
+        assert!(report.shadowed.is_empty());
+    }
+
+    #[test]
+    fn recurses_into_nested_matcher_with_path() {
+        let config = MatcherConfig {
+            matchers: vec![FieldMatcherConfig {
+                predicate: single("stage", StringMatchSpec::Exact("inner".into())),
+                on_match: OnMatchConfig::Matcher {
+                    matcher: Box::new(MatcherConfig {
+                        matchers: vec![
+                            FieldMatcherConfig {
+                                predicate: single("role", StringMatchSpec::Prefix(String::new())),
+                                on_match: action("nested_allow"),
+                            },
+                            FieldMatcherConfig {
+                                predicate: single("role", StringMatchSpec::Exact("admin".into())),
+                                on_match: action("nested_dead"),
+                            },
+                        ],
+                        on_no_match: None,
+                    }),
+                    keep_matching: false,
+                },
+            }],
+            on_no_match: None,
+        };
+
+        let report = analyze(&config);
+        assert!(report.reachable_actions.contains("nested_allow"));
+        assert!(report.reachable_actions.contains("nested_dead"));
+        assert_eq!(report.shadowed.len(), 1);
+        assert_eq!(report.shadowed[0].matcher_path, vec![Some(0)]);
+        assert_eq!(report.shadowed[0].index, 1);
+    }
+}
@@ -0,0 +1,287 @@
+//! Named captures and `${name}` interpolation for matched values.
+//!
+//! Borrows SSR's metavariable idea (see [`crate::ssr`]): a
+//! [`SinglePredicateConfig`](crate::SinglePredicateConfig) can declare a
+//! `capture` name, and the value its input matched against is bound under
+//! that name in a [`Captures`] environment. [`interpolate`] then substitutes
+//! `${name}` references against that environment — e.g. so an action named
+//! `"grant_${role}"` becomes `"grant_admin"` once `role` has captured
+//! `"admin"`.
+//!
+//! This module only implements the pure, data-level building blocks:
+//! accumulating a predicate's own capture while walking the tree
+//! ([`accumulate`]), additionally pulling named/numbered groups out of a
+//! regex match ([`accumulate_regex_groups`]), and substituting all of it
+//! into a template string ([`interpolate`]). Threading a live [`Captures`]
+//! environment through `Matcher::evaluate`/`TestContext` and applying
+//! [`interpolate`] to the resolved action is the runtime engine's job; that
+//! engine isn't part of this crate's sources, so it isn't wired up here. A
+//! node's captures must include every capture produced by that node's own
+//! predicate (so sibling `and`-conjuncts see each other's bindings) before
+//! the runtime calls [`interpolate`] on the `on_match` action at that node.
+//!
+//! [`template_refs`] and [`declared_captures`] exist so `validate()` can
+//! fail closed on a template that can never resolve: every `${name}` an
+//! action template references should appear in its own `FieldMatcher`'s
+//! `declared_captures`, or the matcher should be rejected at load time
+//! rather than emit a literal `${name}` the first time a live context
+//! reaches that branch.
+
+use std::collections::HashMap;
+
+use crate::{PredicateConfig, SinglePredicateConfig};
+
+/// Capture name → matched value.
+pub type Captures = HashMap<String, String>;
+
+/// Record `predicate`'s capture (if it declares one) as having matched
+/// `value`, inserting it into `captures`.
+///
+/// For `and`/`or`/`not` compounds, the caller is expected to call this once
+/// per leaf [`SinglePredicateConfig`] as it evaluates each one — this
+/// function only handles a single node's own declaration, since only the
+/// runtime (which actually extracts input values from a live context) knows
+/// what "the value it matched" is for a given predicate.
+pub fn accumulate(predicate: &SinglePredicateConfig, value: &str, captures: &mut Captures) {
+    if let Some(name) = &predicate.capture {
+        captures.insert(name.clone(), value.to_string());
+    }
+}
+
+/// Bind a regex match's named and numbered groups into `captures`, in
+/// addition to whatever [`accumulate`] already bound for the predicate's own
+/// top-level `capture` name.
+///
+/// `re` must already be compiled — this is meant to be called with the
+/// automaton the `Registry` compiled once at load time (see
+/// [`crate::SinglePredicateConfig`]'s `value_match`/`StringMatchSpec::Regex`
+/// handling), not recompiled per evaluation. Named groups
+/// (`(?P<tenant>...)`) bind under their own name; unnamed groups bind under
+/// their 1-based index, stringified (`"1"`, `"2"`, ...). If `value` doesn't
+/// match `re` at all, nothing is bound.
+pub fn accumulate_regex_groups(re: &regex::Regex, value: &str, captures: &mut Captures) {
+    let Some(caps) = re.captures(value) else {
+        return;
+    };
+    for (index, name) in re.capture_names().enumerate() {
+        if index == 0 {
+            continue; // group 0 is the whole match, not a capture group
+        }
+        if let Some(m) = caps.get(index) {
+            let key = name.map(str::to_string).unwrap_or_else(|| index.to_string());
+            captures.insert(key, m.as_str().to_string());
+        }
+    }
+}
+
+/// Every `${name}` reference in `template`, in occurrence order (duplicates
+/// included if the same name is referenced more than once).
+///
+/// Pairs with [`declared_captures`] at `validate()` time: a `FieldMatcher`
+/// whose action template references a name its own predicate never declares
+/// is a load-time error (fail-closed) rather than a template that silently
+/// renders with a literal `${name}` left in it the first time a live
+/// context hits that branch.
+pub fn template_refs(template: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    let bytes = template.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'$' && bytes.get(i + 1) == Some(&b'{') {
+            if let Some(end) = template[i + 2..].find('}') {
+                refs.push(template[i + 2..i + 2 + end].to_string());
+                i += 2 + end + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    refs
+}
+
+/// Every capture name declared anywhere in `predicate` (`and`/`or`/`not`
+/// included), in tree order. Useful for validating that an action's
+/// `${name}` references are all satisfiable before a matcher is loaded.
+pub fn declared_captures(predicate: &PredicateConfig) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_declared(predicate, &mut names);
+    names
+}
+
+fn collect_declared(predicate: &PredicateConfig, names: &mut Vec<String>) {
+    match predicate {
+        PredicateConfig::Single(sp) => {
+            if let Some(name) = &sp.capture {
+                names.push(name.clone());
+            }
+        }
+        PredicateConfig::And { predicates } | PredicateConfig::Or { predicates } => {
+            for p in predicates {
+                collect_declared(p, names);
+            }
+        }
+        PredicateConfig::Not { predicate } => collect_declared(predicate, names),
+    }
+}
+
+/// Substitute every `${name}` reference in `template` with the matching
+/// entry from `captures`. A reference with no matching capture is left
+/// verbatim, so a missing binding fails loudly downstream (e.g. as a
+/// still-templated action name) rather than silently vanishing.
+pub fn interpolate(template: &str, captures: &Captures) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            // Unterminated `${` — treat the rest of the string as literal
+            // rather than silently truncating it.
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let name = &rest[start + 2..start + end];
+        match captures.get(name) {
+            Some(value) => out.push_str(value),
+            None => out.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StringMatchSpec, TypedConfig, ValueMatchConfig};
+
+    fn single(capture: Option<&str>) -> SinglePredicateConfig {
+        SinglePredicateConfig {
+            input: TypedConfig {
+                type_url: "test.Input".into(),
+                config: serde_json::json!({}),
+            },
+            matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Regex(".*".into())),
+            capture: capture.map(String::from),
+        }
+    }
+
+    #[test]
+    fn accumulate_inserts_named_capture() {
+        let predicate = single(Some("role"));
+        let mut captures = Captures::new();
+        accumulate(&predicate, "admin", &mut captures);
+        assert_eq!(captures.get("role"), Some(&"admin".to_string()));
+    }
+
+    #[test]
+    fn accumulate_skips_predicates_without_a_capture_name() {
+        let predicate = single(None);
+        let mut captures = Captures::new();
+        accumulate(&predicate, "admin", &mut captures);
+        assert!(captures.is_empty());
+    }
+
+    #[test]
+    fn accumulate_regex_groups_binds_named_group() {
+        let re = regex::Regex::new(r"^(?P<tenant>[a-z]+)-admin$").unwrap();
+        let mut captures = Captures::new();
+        accumulate_regex_groups(&re, "acme-admin", &mut captures);
+        assert_eq!(captures.get("tenant"), Some(&"acme".to_string()));
+    }
+
+    #[test]
+    fn accumulate_regex_groups_binds_unnamed_group_by_index() {
+        let re = regex::Regex::new(r"^([a-z]+)-([0-9]+)$").unwrap();
+        let mut captures = Captures::new();
+        accumulate_regex_groups(&re, "role-42", &mut captures);
+        assert_eq!(captures.get("1"), Some(&"role".to_string()));
+        assert_eq!(captures.get("2"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn accumulate_regex_groups_is_noop_when_value_does_not_match() {
+        let re = regex::Regex::new(r"^(?P<tenant>[a-z]+)-admin$").unwrap();
+        let mut captures = Captures::new();
+        accumulate_regex_groups(&re, "not-a-match", &mut captures);
+        assert!(captures.is_empty());
+    }
+
+    #[test]
+    fn interpolate_substitutes_known_captures() {
+        let mut captures = Captures::new();
+        captures.insert("role".into(), "admin".into());
+        assert_eq!(interpolate("grant_${role}", &captures), "grant_admin");
+    }
+
+    #[test]
+    fn interpolate_leaves_unresolved_references_verbatim() {
+        let captures = Captures::new();
+        assert_eq!(interpolate("grant_${role}", &captures), "grant_${role}");
+    }
+
+    #[test]
+    fn interpolate_handles_multiple_placeholders() {
+        let mut captures = Captures::new();
+        captures.insert("role".into(), "admin".into());
+        captures.insert("org".into(), "acme".into());
+        assert_eq!(
+            interpolate("${org}_${role}_grant", &captures),
+            "acme_admin_grant"
+        );
+    }
+
+    #[test]
+    fn interpolate_preserves_multi_byte_literal_text() {
+        let mut captures = Captures::new();
+        captures.insert("role".into(), "admin".into());
+        assert_eq!(
+            interpolate("café_${role}_\u{1f980}", &captures),
+            "café_admin_\u{1f980}"
+        );
+    }
+
+    #[test]
+    fn template_refs_finds_single_placeholder() {
+        assert_eq!(template_refs("grant_${role}"), vec!["role".to_string()]);
+    }
+
+    #[test]
+    fn template_refs_finds_multiple_placeholders_in_order() {
+        assert_eq!(
+            template_refs("${org}_${role}_grant"),
+            vec!["org".to_string(), "role".to_string()]
+        );
+    }
+
+    #[test]
+    fn template_refs_repeats_a_name_referenced_twice() {
+        assert_eq!(
+            template_refs("${role}_${role}"),
+            vec!["role".to_string(), "role".to_string()]
+        );
+    }
+
+    #[test]
+    fn template_refs_is_empty_for_a_plain_string() {
+        assert!(template_refs("deny").is_empty());
+    }
+
+    #[test]
+    fn declared_captures_walks_and_or_not() {
+        let predicate = PredicateConfig::And {
+            predicates: vec![
+                PredicateConfig::Single(single(Some("role"))),
+                PredicateConfig::Not {
+                    predicate: Box::new(PredicateConfig::Or {
+                        predicates: vec![PredicateConfig::Single(single(Some("org")))],
+                    }),
+                },
+            ],
+        };
+        assert_eq!(declared_captures(&predicate), vec!["role", "org"]);
+    }
+}
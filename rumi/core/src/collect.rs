@@ -0,0 +1,106 @@
+//! Dedup logic for `Matcher::evaluate_all`'s collect-all-matches mode.
+//!
+//! First-match-wins `Matcher::evaluate` returns at most one action. A
+//! `keep_matching` flag on `OnMatchConfig` (see [`crate::config`]) lets a
+//! `FieldMatcher` opt out of that short-circuit so evaluation continues
+//! past a hit; `Matcher::evaluate_all` is the runtime entry point that
+//! walks the whole `MatcherList` honoring that flag and accumulates every
+//! action reached, in first-match order. That traversal is the runtime
+//! engine's job and isn't part of this crate's sources, so it isn't wired
+//! up here — what *is* pure, data-level, and testable is the dedup pass
+//! `evaluate_all` runs over its accumulated hits before returning them,
+//! which [`dedupe_nested`] provides.
+//!
+//! A `keep_matching` nested matcher can produce a hit at an outer
+//! `FieldMatcher` *and* one or more hits further down its own subtree —
+//! the outer hit's predicate already "contains" whatever fired inside it.
+//! [`dedupe_nested`] removes any hit whose `matcher_path` is nested inside
+//! another hit's path, keeping only the outermost, the same way
+//! rust-analyzer's SSR nested-match filter keeps only the outermost of two
+//! overlapping matches.
+
+/// One hit accumulated by `Matcher::evaluate_all`: the action together with
+/// the path of `FieldMatcher` indices that led to it.
+///
+/// Uses the same encoding as
+/// [`crate::analysis::ShadowedMatcher::matcher_path`] (`Some(index)` for a
+/// `FieldMatcher.on_match`, `None` for an `on_no_match`), but runs one level
+/// further: the last entry is the hit's own position, so a hit at the
+/// top-level `FieldMatcher` 0 has the path `[Some(0)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectedMatch<A> {
+    pub matcher_path: Vec<Option<usize>>,
+    pub action: A,
+}
+
+/// Remove hits nested inside another hit's matcher subtree, keeping only the
+/// outermost per overlapping group. Preserves the relative order of the
+/// survivors, so this keeps `evaluate_all`'s first-match ordering intact.
+pub fn dedupe_nested<A>(hits: Vec<CollectedMatch<A>>) -> Vec<CollectedMatch<A>> {
+    hits.iter()
+        .enumerate()
+        .filter(|(i, hit)| {
+            !hits
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != *i && is_strict_ancestor(&other.matcher_path, &hit.matcher_path))
+        })
+        .map(|(_, hit)| hit.clone())
+        .collect()
+}
+
+/// Whether `ancestor` is a strict prefix of `path`: the `FieldMatcher` chain
+/// `ancestor` names is a shorter leading segment of the one `path` names, so
+/// `ancestor`'s hit structurally contains `path`'s.
+fn is_strict_ancestor(ancestor: &[Option<usize>], path: &[Option<usize>]) -> bool {
+    ancestor.len() < path.len() && ancestor == &path[..ancestor.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(path: &[Option<usize>], action: &str) -> CollectedMatch<String> {
+        CollectedMatch {
+            matcher_path: path.to_vec(),
+            action: action.to_string(),
+        }
+    }
+
+    #[test]
+    fn keeps_unrelated_hits() {
+        let hits = vec![hit(&[Some(0)], "a"), hit(&[Some(1)], "b")];
+        let result = dedupe_nested(hits.clone());
+        assert_eq!(result, hits);
+    }
+
+    #[test]
+    fn drops_hit_nested_inside_another() {
+        let outer = hit(&[Some(0)], "outer");
+        let inner = hit(&[Some(0), Some(1)], "inner");
+        let result = dedupe_nested(vec![outer.clone(), inner]);
+        assert_eq!(result, vec![outer]);
+    }
+
+    #[test]
+    fn keeps_outermost_even_when_inner_listed_first() {
+        let outer = hit(&[Some(0)], "outer");
+        let inner = hit(&[Some(0), Some(1)], "inner");
+        let result = dedupe_nested(vec![inner, outer.clone()]);
+        assert_eq!(result, vec![outer]);
+    }
+
+    #[test]
+    fn on_no_match_path_is_not_an_ancestor_of_a_sibling_field_matcher() {
+        let no_match_hit = hit(&[None], "fallback");
+        let sibling_hit = hit(&[Some(0)], "matched");
+        let result = dedupe_nested(vec![no_match_hit.clone(), sibling_hit.clone()]);
+        assert_eq!(result, vec![no_match_hit, sibling_hit]);
+    }
+
+    #[test]
+    fn empty_input_returns_empty_output() {
+        let result: Vec<CollectedMatch<String>> = dedupe_nested(vec![]);
+        assert!(result.is_empty());
+    }
+}
@@ -22,7 +22,7 @@ use serde::Deserialize;
 ///
 /// Deserializes from JSON/YAML and can be loaded into a runtime `Matcher`
 /// via [`Registry::load_matcher()`](crate::Registry::load_matcher).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(bound(deserialize = "A: Deserialize<'de>"))]
 pub struct MatcherConfig<A> {
     /// Field matchers to evaluate in order (first-match-wins).
@@ -34,7 +34,7 @@ pub struct MatcherConfig<A> {
 }
 
 /// Configuration for a [`FieldMatcher`](crate::FieldMatcher).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(bound(deserialize = "A: Deserialize<'de>"))]
 pub struct FieldMatcherConfig<A> {
     /// The predicate that gates this field matcher.
@@ -54,35 +54,87 @@ pub struct FieldMatcherConfig<A> {
 /// { "type": "or", "predicates": [...] }
 /// { "type": "not", "predicate": { ... } }
 /// ```
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type")]
+///
+/// Also accepts a tagless combinator shorthand — the shape `PyMatchGroup`'s
+/// `any_of`/`all_of`/`not_` lower into, and what any crust's `fromConfig`
+/// already parses for free since it just deserializes `PredicateConfig`:
+///
+/// ```json
+/// { "anyOf": [...] }
+/// { "allOf": [...] }
+/// { "not": { ... } }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub enum PredicateConfig {
     /// A single predicate: input + value match.
-    #[serde(rename = "single")]
     Single(SinglePredicateConfig),
 
     /// All predicates must match (logical AND).
-    #[serde(rename = "and")]
     And {
         /// Child predicates (all must match).
         predicates: Vec<PredicateConfig>,
     },
 
     /// Any predicate must match (logical OR).
-    #[serde(rename = "or")]
     Or {
         /// Child predicates (any must match).
         predicates: Vec<PredicateConfig>,
     },
 
     /// Inverts the inner predicate (logical NOT).
-    #[serde(rename = "not")]
     Not {
         /// The predicate to negate.
         predicate: Box<PredicateConfig>,
     },
 }
 
+impl<'de> Deserialize<'de> for PredicateConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Tried in order: the tagless `anyOf`/`allOf`/`not` shorthand first
+        // (distinguished by which field is present), falling back to the
+        // `{"type": "..."}` discriminated union.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            AnyOf {
+                #[serde(rename = "anyOf")]
+                predicates: Vec<PredicateConfig>,
+            },
+            AllOf {
+                #[serde(rename = "allOf")]
+                predicates: Vec<PredicateConfig>,
+            },
+            NotShorthand {
+                not: Box<PredicateConfig>,
+            },
+            Tagged(Tagged),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(tag = "type")]
+        enum Tagged {
+            #[serde(rename = "single")]
+            Single(SinglePredicateConfig),
+            #[serde(rename = "and")]
+            And { predicates: Vec<PredicateConfig> },
+            #[serde(rename = "or")]
+            Or { predicates: Vec<PredicateConfig> },
+            #[serde(rename = "not")]
+            Not { predicate: Box<PredicateConfig> },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::AnyOf { predicates } => PredicateConfig::Or { predicates },
+            Repr::AllOf { predicates } => PredicateConfig::And { predicates },
+            Repr::NotShorthand { not } => PredicateConfig::Not { predicate: not },
+            Repr::Tagged(Tagged::Single(sp)) => PredicateConfig::Single(sp),
+            Repr::Tagged(Tagged::And { predicates }) => PredicateConfig::And { predicates },
+            Repr::Tagged(Tagged::Or { predicates }) => PredicateConfig::Or { predicates },
+            Repr::Tagged(Tagged::Not { predicate }) => PredicateConfig::Not { predicate },
+        })
+    }
+}
+
 /// How to match the extracted value in a [`SinglePredicateConfig`].
 ///
 /// Mirrors Envoy's `oneof matcher` in `SinglePredicate`:
@@ -90,7 +142,7 @@ pub enum PredicateConfig {
 /// - `Custom` — custom matcher via registry (Envoy: `TypedExtensionConfig custom_match`)
 ///
 /// The enum makes illegal states unrepresentable: exactly one variant is active.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ValueMatchConfig {
     /// Built-in string matching (exact, prefix, suffix, contains, regex).
     BuiltIn(StringMatchSpec),
@@ -113,7 +165,12 @@ pub enum ValueMatchConfig {
 /// ```
 ///
 /// Exactly one of `value_match` or `custom_match` must be set.
-#[derive(Debug, Clone)]
+///
+/// An optional `capture` name binds the value this predicate matched into
+/// the evaluation-time capture environment (see [`crate::capture`]), so a
+/// sibling `and`-predicate or the `on_match` action at this node can
+/// reference it as `${name}`.
+#[derive(Debug, Clone, PartialEq)]
 pub struct SinglePredicateConfig {
     /// The input to extract data from context.
     /// Resolved at load time via the registry's `type_url` lookup.
@@ -121,6 +178,10 @@ pub struct SinglePredicateConfig {
 
     /// How to match the extracted value.
     pub matcher: ValueMatchConfig,
+
+    /// Name to bind the matched value under, for `${name}` interpolation
+    /// in the eventual action. See [`crate::capture`].
+    pub capture: Option<String>,
 }
 
 impl<'de> Deserialize<'de> for SinglePredicateConfig {
@@ -134,6 +195,8 @@ impl<'de> Deserialize<'de> for SinglePredicateConfig {
             value_match: Option<StringMatchSpec>,
             #[serde(default)]
             custom_match: Option<TypedConfig>,
+            #[serde(default)]
+            capture: Option<String>,
         }
 
         let helper = Helper::deserialize(deserializer)?;
@@ -154,6 +217,7 @@ impl<'de> Deserialize<'de> for SinglePredicateConfig {
         Ok(SinglePredicateConfig {
             input: helper.input,
             matcher,
+            capture: helper.capture,
         })
     }
 }
@@ -163,7 +227,7 @@ impl<'de> Deserialize<'de> for SinglePredicateConfig {
 /// Maps to xDS `TypedExtensionConfig`:
 /// - `type_url` identifies the registered type (input, matcher, or action)
 /// - `config` carries the type-specific configuration payload
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct TypedConfig {
     /// The type URL identifying the registered type.
     /// Must match a `type_url` registered in the [`Registry`](crate::Registry).
@@ -198,7 +262,7 @@ impl<'de> Deserialize<'de> for UnitConfig {
 ///
 /// Either an action (leaf) or a nested matcher (tree).
 /// `OnMatch` exclusivity is enforced by the enum: action XOR matcher.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(tag = "type")]
 #[serde(bound(deserialize = "A: Deserialize<'de>"))]
 pub enum OnMatchConfig<A> {
@@ -207,6 +271,19 @@ pub enum OnMatchConfig<A> {
     Action {
         /// The action value.
         action: A,
+
+        /// When `true`, evaluation continues past this match instead of
+        /// short-circuiting, so a collect-all-matches pass
+        /// (`Matcher::evaluate_all`) accumulates this action and keeps
+        /// walking the rest of the `MatcherList`. Ignored by the
+        /// first-match-wins `evaluate`.
+        #[serde(default)]
+        keep_matching: bool,
+
+        /// Ops to apply to the context alongside producing `action` — see
+        /// [`crate::rewrite`]. `None` leaves the context untouched.
+        #[serde(default)]
+        rewrite: Option<crate::rewrite::RewriteTemplate>,
     },
 
     /// Evaluate a nested matcher when the predicate matches.
@@ -214,6 +291,11 @@ pub enum OnMatchConfig<A> {
     Matcher {
         /// The nested matcher configuration.
         matcher: Box<MatcherConfig<A>>,
+
+        /// Same meaning as `Action.keep_matching`, for the nested matcher as
+        /// a whole.
+        #[serde(default)]
+        keep_matching: bool,
     },
 }
 
@@ -293,6 +375,47 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn deserialize_any_of_shorthand_as_or() {
+        let json = serde_json::json!({
+            "anyOf": [
+                { "type": "single", "input": { "type_url": "a" }, "value_match": { "Exact": "x" } },
+                { "type": "single", "input": { "type_url": "b" }, "value_match": { "Exact": "y" } }
+            ]
+        });
+
+        let predicate: PredicateConfig = serde_json::from_value(json).unwrap();
+        match predicate {
+            PredicateConfig::Or { predicates } => assert_eq!(predicates.len(), 2),
+            _ => panic!("expected Or"),
+        }
+    }
+
+    #[test]
+    fn deserialize_all_of_shorthand_as_and() {
+        let json = serde_json::json!({
+            "allOf": [
+                { "type": "single", "input": { "type_url": "a" }, "value_match": { "Exact": "x" } }
+            ]
+        });
+
+        let predicate: PredicateConfig = serde_json::from_value(json).unwrap();
+        match predicate {
+            PredicateConfig::And { predicates } => assert_eq!(predicates.len(), 1),
+            _ => panic!("expected And"),
+        }
+    }
+
+    #[test]
+    fn deserialize_not_shorthand() {
+        let json = serde_json::json!({
+            "not": { "type": "single", "input": { "type_url": "a" }, "value_match": { "Exact": "x" } }
+        });
+
+        let predicate: PredicateConfig = serde_json::from_value(json).unwrap();
+        assert!(matches!(predicate, PredicateConfig::Not { .. }));
+    }
+
     #[test]
     fn deserialize_nested_matcher() {
         let json = serde_json::json!({
@@ -320,11 +443,32 @@ mod tests {
 
         let config: MatcherConfig<String> = serde_json::from_value(json).unwrap();
         match &config.matchers[0].on_match {
-            OnMatchConfig::Matcher { matcher } => assert_eq!(matcher.matchers.len(), 1),
+            OnMatchConfig::Matcher { matcher, .. } => assert_eq!(matcher.matchers.len(), 1),
             _ => panic!("expected nested matcher"),
         }
     }
 
+    #[test]
+    fn capture_name_defaults_to_none() {
+        let json = serde_json::json!({
+            "input": { "type_url": "a" },
+            "value_match": { "Exact": "x" }
+        });
+        let sp: SinglePredicateConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(sp.capture, None);
+    }
+
+    #[test]
+    fn capture_name_is_deserialized() {
+        let json = serde_json::json!({
+            "input": { "type_url": "a" },
+            "value_match": { "Exact": "x" },
+            "capture": "role"
+        });
+        let sp: SinglePredicateConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(sp.capture.as_deref(), Some("role"));
+    }
+
     #[test]
     fn typed_config_defaults_to_empty_object() {
         let json = serde_json::json!({ "type_url": "test.Input" });
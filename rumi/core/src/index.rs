@@ -0,0 +1,234 @@
+//! Candidate pre-filtering index for large rule sets.
+//!
+//! `Matcher::evaluate` walks `MatcherConfig::matchers` in order and returns
+//! the first full match — an O(rules) scan per context once a rule set
+//! grows into the thousands. [`build_index`] builds, once at load time, an
+//! inverted index from every *anchor* it can find: a top-level rule whose
+//! predicate conjunction includes an `Exact` check directly (not inside an
+//! `Or`/`Not`, where it doesn't guarantee anything about the whole
+//! predicate) is indexed under that input's `type_url` and the exact value.
+//! A rule with no such anchor — pure prefix/suffix/contains/regex, or an
+//! `Or`/`Not` at the top — is kept as an "always-candidate", since no cheap
+//! necessary condition rules it out for any context.
+//!
+//! [`candidates`] then turns a context into the union of every anchor it
+//! hits plus every always-candidate, still in original rule order. This is
+//! a pure filter, never a decision: the real predicate still has to run
+//! against each candidate, and every rule the linear scan would ever match
+//! for a given context is guaranteed to appear in its candidate set (an
+//! anchored rule's `Exact` conjunct being false for the context is a sound
+//! reason to skip it; nothing else here is). Actually replacing the linear
+//! scan inside `Matcher::evaluate` with "run only the candidates, in order,
+//! return the first full match" is the runtime engine's job, so it isn't
+//! wired up here — what's pure, data-level, and testable is the index
+//! itself and the set it computes.
+
+use std::collections::HashMap;
+
+use crate::{MatcherConfig, PredicateConfig, StringMatchSpec, ValueMatchConfig};
+
+/// Position of a rule in `MatcherConfig::matchers`, used as its id here.
+pub type RuleId = usize;
+
+/// An inverted index over a `MatcherConfig`'s top-level rules. See the
+/// module docs for how it's built and used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CandidateIndex {
+    /// `anchors[type_url][value]` -> rule ids whose top-level conjunction
+    /// has an `Exact(value)` predicate on an input with that `type_url`.
+    anchors: HashMap<String, HashMap<String, Vec<RuleId>>>,
+
+    /// Rules with no exact anchor: always included as a candidate.
+    always_candidates: Vec<RuleId>,
+}
+
+/// Build a [`CandidateIndex`] over `matcher`'s top-level rules.
+///
+/// Only the top-level `matchers` list is indexed — a rule reached through a
+/// nested `OnMatchConfig::Matcher` is only ever considered once its parent
+/// has already matched, so it isn't a candidate for pre-filtering the outer
+/// scan.
+pub fn build_index<A>(matcher: &MatcherConfig<A>) -> CandidateIndex {
+    let mut index = CandidateIndex::default();
+    for (rule_id, field_matcher) in matcher.matchers.iter().enumerate() {
+        let anchors = exact_anchors(&field_matcher.predicate);
+        if anchors.is_empty() {
+            index.always_candidates.push(rule_id);
+            continue;
+        }
+        for (type_url, value) in anchors {
+            index
+                .anchors
+                .entry(type_url)
+                .or_default()
+                .entry(value)
+                .or_default()
+                .push(rule_id);
+        }
+    }
+    index
+}
+
+/// Every `Exact` predicate directly in `predicate`'s top-level conjunction:
+/// `predicate` itself if it's a bare `Exact` `Single`, or every `Exact`
+/// `Single` conjunct if it's an `And`. An `Or`/`Not` (or a non-`Exact`
+/// `Single`) contributes nothing — being false there doesn't imply the
+/// whole predicate is false, so it can't anchor a "skip this rule" decision.
+fn exact_anchors(predicate: &PredicateConfig) -> Vec<(String, String)> {
+    match predicate {
+        PredicateConfig::Single(sp) => match &sp.matcher {
+            ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(value)) => {
+                vec![(sp.input.type_url.clone(), value.clone())]
+            }
+            _ => Vec::new(),
+        },
+        PredicateConfig::And { predicates } => predicates.iter().flat_map(exact_anchors).collect(),
+        PredicateConfig::Or { .. } | PredicateConfig::Not { .. } => Vec::new(),
+    }
+}
+
+/// Compute `context`'s candidate rule ids: the union of every anchor it
+/// hits plus every always-candidate, deduplicated and returned in original
+/// rule order so a consumer can evaluate them in that order and stop at the
+/// first full match, exactly matching the linear scan's tie-breaking.
+pub fn candidates(index: &CandidateIndex, context: &HashMap<String, String>) -> Vec<RuleId> {
+    let mut hits: Vec<RuleId> = Vec::new();
+    for (key, value) in context {
+        if let Some(ids) = index.anchors.get(key).and_then(|by_value| by_value.get(value)) {
+            hits.extend(ids.iter().copied());
+        }
+    }
+    hits.extend(index.always_candidates.iter().copied());
+    hits.sort_unstable();
+    hits.dedup();
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldMatcherConfig, OnMatchConfig, SinglePredicateConfig, TypedConfig};
+
+    fn input(type_url: &str) -> TypedConfig {
+        TypedConfig {
+            type_url: type_url.into(),
+            config: serde_json::json!({}),
+        }
+    }
+
+    fn single(type_url: &str, spec: StringMatchSpec) -> PredicateConfig {
+        PredicateConfig::Single(SinglePredicateConfig {
+            input: input(type_url),
+            matcher: ValueMatchConfig::BuiltIn(spec),
+            capture: None,
+        })
+    }
+
+    fn action(type_url: &str) -> OnMatchConfig<TypedConfig> {
+        OnMatchConfig::Action {
+            action: input(type_url),
+            keep_matching: false,
+            rewrite: None,
+        }
+    }
+
+    fn rule(predicate: PredicateConfig) -> FieldMatcherConfig<TypedConfig> {
+        FieldMatcherConfig {
+            predicate,
+            on_match: action("hit"),
+        }
+    }
+
+    fn ctx(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn exact_rule_is_anchored_under_its_input_and_value() {
+        let config = MatcherConfig {
+            matchers: vec![rule(single("role", StringMatchSpec::Exact("admin".into())))],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        assert_eq!(candidates(&index, &ctx(&[("role", "admin")])), vec![0]);
+        assert!(candidates(&index, &ctx(&[("role", "viewer")])).is_empty());
+    }
+
+    #[test]
+    fn and_of_exacts_anchors_under_every_conjunct() {
+        let config = MatcherConfig {
+            matchers: vec![rule(PredicateConfig::And {
+                predicates: vec![
+                    single("role", StringMatchSpec::Exact("admin".into())),
+                    single("org", StringMatchSpec::Exact("acme".into())),
+                ],
+            })],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        assert_eq!(candidates(&index, &ctx(&[("role", "admin")])), vec![0]);
+        assert_eq!(candidates(&index, &ctx(&[("org", "acme")])), vec![0]);
+    }
+
+    #[test]
+    fn prefix_rule_has_no_anchor_and_is_always_a_candidate() {
+        let config = MatcherConfig {
+            matchers: vec![rule(single("path", StringMatchSpec::Prefix("/api".into())))],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        assert_eq!(candidates(&index, &ctx(&[("unrelated", "x")])), vec![0]);
+    }
+
+    #[test]
+    fn or_of_exacts_has_no_top_level_anchor() {
+        let config = MatcherConfig {
+            matchers: vec![rule(PredicateConfig::Or {
+                predicates: vec![
+                    single("role", StringMatchSpec::Exact("admin".into())),
+                    single("role", StringMatchSpec::Exact("owner".into())),
+                ],
+            })],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        // Being false for one branch doesn't rule the Or out, so it can
+        // only ever be an always-candidate, never excluded by role=viewer.
+        assert_eq!(candidates(&index, &ctx(&[("role", "viewer")])), vec![0]);
+    }
+
+    #[test]
+    fn candidates_are_unioned_and_deduplicated_in_rule_order() {
+        let config = MatcherConfig {
+            matchers: vec![
+                rule(single("role", StringMatchSpec::Exact("admin".into()))),
+                rule(single("path", StringMatchSpec::Prefix(String::new()))),
+                rule(single("org", StringMatchSpec::Exact("acme".into()))),
+            ],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        let result = candidates(&index, &ctx(&[("role", "admin"), ("org", "acme")]));
+        assert_eq!(result, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn context_key_with_no_matching_anchor_contributes_nothing() {
+        let config = MatcherConfig {
+            matchers: vec![rule(single("role", StringMatchSpec::Exact("admin".into())))],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        assert!(candidates(&index, &ctx(&[("unrelated_key", "admin")])).is_empty());
+    }
+
+    #[test]
+    fn empty_matcher_yields_no_candidates() {
+        let config = MatcherConfig::<TypedConfig> {
+            matchers: vec![],
+            on_no_match: None,
+        };
+        let index = build_index(&config);
+        assert!(candidates(&index, &ctx(&[("role", "admin")])).is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+//! Canonicalizes a `PredicateConfig` boolean tree after conversion.
+//!
+//! Intended to run optionally right after `convert_predicate` (or any other
+//! producer of `PredicateConfig`), this is a pure tree→tree transform that
+//! preserves evaluation semantics while making the tree compact and
+//! comparable:
+//!
+//! - flattens nested `And`/`Or` of the same kind into one n-ary node
+//!   (`And[And[a, b], c]` → `And[a, b, c]`)
+//! - collapses a single-child `And`/`Or` to that child
+//! - eliminates double negation (`Not(Not(p))` → `p`)
+//! - pushes `Not` through `And`/`Or` via De Morgan's laws, when requested
+//!   via [`NormalizeOptions::push_negations`]
+//! - deduplicates structurally-identical sibling predicates, relying on
+//!   `PredicateConfig`'s `PartialEq` impl
+//!
+//! A compact, canonical tree is what makes [`crate::ssr`] matching and
+//! reachability/shadowing analysis tractable: two predicates that differ
+//! only in same-kind nesting, single-child wrappers, double negation or
+//! duplicated siblings normalize to the same tree, so "is this predicate
+//! already covered by an earlier one" becomes a structural equality check
+//! for those cases. Sibling order is kept as written, so `And[a, b]` and
+//! `And[b, a]` stay distinct trees.
+
+use crate::{MatcherConfig, OnMatchConfig, PredicateConfig};
+
+/// Options controlling how aggressively [`normalize_predicate`] rewrites a tree.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizeOptions {
+    /// When `true`, push `Not` through `And`/`Or` via De Morgan's laws
+    /// (`Not(And[a, b])` → `Or[Not(a), Not(b)]`). Off by default: some
+    /// consumers display predicates to operators and prefer to keep an
+    /// explicit `Not` close to the original intent rather than distributing
+    /// it across every branch.
+    pub push_negations: bool,
+}
+
+/// Normalize a single `PredicateConfig` tree. See the module docs for the
+/// full list of rewrites applied.
+pub fn normalize_predicate(predicate: PredicateConfig, opts: &NormalizeOptions) -> PredicateConfig {
+    match predicate {
+        PredicateConfig::Single(sp) => PredicateConfig::Single(sp),
+        PredicateConfig::And { predicates } => {
+            finish_n_ary(flatten_same_kind(predicates, opts, true), true)
+        }
+        PredicateConfig::Or { predicates } => {
+            finish_n_ary(flatten_same_kind(predicates, opts, false), false)
+        }
+        PredicateConfig::Not { predicate } => normalize_not(*predicate, opts),
+    }
+}
+
+/// Normalize every predicate reachable from `matcher`, including those in
+/// matchers nested under `OnMatchConfig::Matcher`.
+pub fn normalize_matcher<A>(matcher: MatcherConfig<A>, opts: &NormalizeOptions) -> MatcherConfig<A> {
+    MatcherConfig {
+        matchers: matcher
+            .matchers
+            .into_iter()
+            .map(|fm| crate::FieldMatcherConfig {
+                predicate: normalize_predicate(fm.predicate, opts),
+                on_match: normalize_on_match(fm.on_match, opts),
+            })
+            .collect(),
+        on_no_match: matcher.on_no_match.map(|om| normalize_on_match(om, opts)),
+    }
+}
+
+fn normalize_on_match<A>(on_match: OnMatchConfig<A>, opts: &NormalizeOptions) -> OnMatchConfig<A> {
+    match on_match {
+        OnMatchConfig::Action {
+            action,
+            keep_matching,
+            rewrite,
+        } => OnMatchConfig::Action {
+            action,
+            keep_matching,
+            rewrite,
+        },
+        OnMatchConfig::Matcher { matcher, keep_matching } => OnMatchConfig::Matcher {
+            matcher: Box::new(normalize_matcher(*matcher, opts)),
+            keep_matching,
+        },
+    }
+}
+
+fn normalize_not(inner: PredicateConfig, opts: &NormalizeOptions) -> PredicateConfig {
+    match normalize_predicate(inner, opts) {
+        // Double negation: Not(Not(p)) -> p
+        PredicateConfig::Not { predicate } => *predicate,
+        PredicateConfig::And { predicates } if opts.push_negations => {
+            normalize_predicate(PredicateConfig::Or { predicates: negate_each(predicates) }, opts)
+        }
+        PredicateConfig::Or { predicates } if opts.push_negations => {
+            normalize_predicate(PredicateConfig::And { predicates: negate_each(predicates) }, opts)
+        }
+        other => PredicateConfig::Not {
+            predicate: Box::new(other),
+        },
+    }
+}
+
+fn negate_each(predicates: Vec<PredicateConfig>) -> Vec<PredicateConfig> {
+    predicates
+        .into_iter()
+        .map(|p| PredicateConfig::Not {
+            predicate: Box::new(p),
+        })
+        .collect()
+}
+
+/// Normalize each child, flatten same-kind `And`/`Or` nesting into this
+/// node, and deduplicate structurally-identical siblings (first occurrence
+/// wins, order otherwise preserved).
+fn flatten_same_kind(
+    predicates: Vec<PredicateConfig>,
+    opts: &NormalizeOptions,
+    is_and: bool,
+) -> Vec<PredicateConfig> {
+    let mut flattened = Vec::with_capacity(predicates.len());
+    for predicate in predicates {
+        let normalized = normalize_predicate(predicate, opts);
+        match normalized {
+            PredicateConfig::And { predicates: inner } if is_and => flattened.extend(inner),
+            PredicateConfig::Or { predicates: inner } if !is_and => flattened.extend(inner),
+            other => flattened.push(other),
+        }
+    }
+
+    let mut deduped: Vec<PredicateConfig> = Vec::with_capacity(flattened.len());
+    for predicate in flattened {
+        if !deduped.contains(&predicate) {
+            deduped.push(predicate);
+        }
+    }
+    deduped
+}
+
+/// Collapse a single-element `And`/`Or` to its sole child; otherwise
+/// rebuild the n-ary node from `predicates`.
+fn finish_n_ary(mut predicates: Vec<PredicateConfig>, is_and: bool) -> PredicateConfig {
+    if predicates.len() == 1 {
+        return predicates.remove(0);
+    }
+    if is_and {
+        PredicateConfig::And { predicates }
+    } else {
+        PredicateConfig::Or { predicates }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldMatcherConfig, SinglePredicateConfig, StringMatchSpec, TypedConfig, ValueMatchConfig};
+
+    fn exact(input_url: &str, value: &str) -> PredicateConfig {
+        PredicateConfig::Single(SinglePredicateConfig {
+            input: TypedConfig {
+                type_url: input_url.into(),
+                config: serde_json::json!({}),
+            },
+            matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(value.into())),
+            capture: None,
+        })
+    }
+
+    #[test]
+    fn flattens_nested_and_of_same_kind() {
+        let tree = PredicateConfig::And {
+            predicates: vec![
+                PredicateConfig::And {
+                    predicates: vec![exact("a", "1"), exact("b", "2")],
+                },
+                exact("c", "3"),
+            ],
+        };
+        let result = normalize_predicate(tree, &NormalizeOptions::default());
+        match result {
+            PredicateConfig::And { predicates } => assert_eq!(predicates.len(), 3),
+            other => panic!("expected And, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn collapses_single_child_or() {
+        let tree = PredicateConfig::Or {
+            predicates: vec![exact("a", "1")],
+        };
+        let result = normalize_predicate(tree, &NormalizeOptions::default());
+        assert_eq!(result, exact("a", "1"));
+    }
+
+    #[test]
+    fn eliminates_double_negation() {
+        let tree = PredicateConfig::Not {
+            predicate: Box::new(PredicateConfig::Not {
+                predicate: Box::new(exact("a", "1")),
+            }),
+        };
+        let result = normalize_predicate(tree, &NormalizeOptions::default());
+        assert_eq!(result, exact("a", "1"));
+    }
+
+    #[test]
+    fn leaves_not_alone_by_default() {
+        let tree = PredicateConfig::Not {
+            predicate: Box::new(PredicateConfig::And {
+                predicates: vec![exact("a", "1"), exact("b", "2")],
+            }),
+        };
+        let result = normalize_predicate(tree, &NormalizeOptions::default());
+        assert!(matches!(result, PredicateConfig::Not { .. }));
+    }
+
+    #[test]
+    fn pushes_negation_through_and_via_de_morgan() {
+        let tree = PredicateConfig::Not {
+            predicate: Box::new(PredicateConfig::And {
+                predicates: vec![exact("a", "1"), exact("b", "2")],
+            }),
+        };
+        let opts = NormalizeOptions {
+            push_negations: true,
+        };
+        let result = normalize_predicate(tree, &opts);
+        match result {
+            PredicateConfig::Or { predicates } => {
+                assert_eq!(predicates.len(), 2);
+                assert!(predicates
+                    .iter()
+                    .all(|p| matches!(p, PredicateConfig::Not { .. })));
+            }
+            other => panic!("expected Or, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn deduplicates_structurally_identical_siblings() {
+        let tree = PredicateConfig::Or {
+            predicates: vec![exact("a", "1"), exact("a", "1"), exact("b", "2")],
+        };
+        let result = normalize_predicate(tree, &NormalizeOptions::default());
+        match result {
+            PredicateConfig::Or { predicates } => assert_eq!(predicates.len(), 2),
+            other => panic!("expected Or, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn normalize_matcher_recurses_into_nested_matcher() {
+        let config = MatcherConfig::<String> {
+            matchers: vec![FieldMatcherConfig {
+                predicate: PredicateConfig::And {
+                    predicates: vec![PredicateConfig::Or {
+                        predicates: vec![exact("a", "1")],
+                    }],
+                },
+                on_match: OnMatchConfig::Matcher {
+                    matcher: Box::new(MatcherConfig {
+                        matchers: vec![FieldMatcherConfig {
+                            predicate: PredicateConfig::Or {
+                                predicates: vec![exact("b", "2")],
+                            },
+                            on_match: OnMatchConfig::Action {
+                                action: "hit".to_string(),
+                                keep_matching: false,
+                                rewrite: None,
+                            },
+                        }],
+                        on_no_match: None,
+                    }),
+                    keep_matching: false,
+                },
+            }],
+            on_no_match: None,
+        };
+
+        let result = normalize_matcher(config, &NormalizeOptions::default());
+        assert_eq!(result.matchers[0].predicate, exact("a", "1"));
+        match &result.matchers[0].on_match {
+            OnMatchConfig::Matcher { matcher, .. } => {
+                assert_eq!(matcher.matchers[0].predicate, exact("b", "2"));
+            }
+            other => panic!("expected nested matcher, got {other:?}"),
+        }
+    }
+}
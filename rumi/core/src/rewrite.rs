@@ -0,0 +1,258 @@
+//! Structured rewrite of a key-value context on match — SSR's search *and*
+//! replace (see [`crate::ssr`]), applied to a live context instead of a
+//! predicate tree.
+//!
+//! A [`RewriteTemplate`] is an ordered list of [`RewriteOp`]s attached to an
+//! [`OnMatchConfig::Action`](crate::OnMatchConfig::Action); once that node
+//! matches, the ops run against the context's key-value view, alongside (not
+//! instead of) producing the action. Op values are `${name}` templates
+//! filled from the same [`Captures`] map a matched node's action would
+//! [`interpolate`](crate::capture::interpolate) against — e.g. a
+//! `PreToolUse` rule can redact a secret argument or rewrite a path before
+//! it's passed downstream.
+//!
+//! This module only implements the pure, data-level pieces: the config
+//! shape and [`apply`], which mutates a plain `HashMap<String, String>` and
+//! records what it did. Threading a live context through
+//! `Matcher::evaluate`/`evaluate_with_trace` — i.e. deciding which concrete
+//! context type's fields a `key` addresses, and surfacing the resulting
+//! [`AppliedOp`]s on the trace — is the runtime engine's job; that engine
+//! isn't part of this crate's sources, so it isn't wired up here.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::capture::{interpolate, Captures};
+
+/// A single mutation to apply to a key-value context.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "op")]
+pub enum RewriteOp {
+    /// Insert or overwrite `key` with `value` (after `${name}` interpolation).
+    #[serde(rename = "set")]
+    Set {
+        /// The context key to write.
+        key: String,
+        /// Template filled from the matched node's captures.
+        value: String,
+    },
+    /// Delete `key` if present; a no-op otherwise.
+    #[serde(rename = "remove")]
+    Remove {
+        /// The context key to delete.
+        key: String,
+    },
+    /// Overwrite `key`'s existing value with `value` (after interpolation).
+    /// Unlike `set`, a no-op if `key` isn't already present — for rewriting
+    /// a value in place rather than introducing a new key.
+    #[serde(rename = "substitute")]
+    Substitute {
+        /// The context key to overwrite.
+        key: String,
+        /// Template filled from the matched node's captures.
+        value: String,
+    },
+}
+
+/// An ordered list of [`RewriteOp`]s to apply when the owning node matches.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct RewriteTemplate {
+    /// Ops applied in order; later ops see earlier ops' writes.
+    pub ops: Vec<RewriteOp>,
+}
+
+/// A [`RewriteOp`] as actually applied, for an audit trail (e.g. `trace`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AppliedOp {
+    /// `"set"`, `"remove"`, or `"substitute"`.
+    pub op: &'static str,
+    /// The context key the op targeted.
+    pub key: String,
+    /// The resolved value the op wrote, if any. `None` for `remove`, and for
+    /// a `substitute` whose `key` wasn't present (so nothing was written).
+    pub value: Option<String>,
+}
+
+/// Apply every op in `template` to `context`, in order, against `captures`,
+/// returning the audit trail of what was actually applied.
+pub fn apply(
+    template: &RewriteTemplate,
+    context: &mut HashMap<String, String>,
+    captures: &Captures,
+) -> Vec<AppliedOp> {
+    template
+        .ops
+        .iter()
+        .map(|op| apply_one(op, context, captures))
+        .collect()
+}
+
+fn apply_one(
+    op: &RewriteOp,
+    context: &mut HashMap<String, String>,
+    captures: &Captures,
+) -> AppliedOp {
+    match op {
+        RewriteOp::Set { key, value } => {
+            let resolved = interpolate(value, captures);
+            context.insert(key.clone(), resolved.clone());
+            AppliedOp {
+                op: "set",
+                key: key.clone(),
+                value: Some(resolved),
+            }
+        }
+        RewriteOp::Remove { key } => {
+            context.remove(key);
+            AppliedOp {
+                op: "remove",
+                key: key.clone(),
+                value: None,
+            }
+        }
+        RewriteOp::Substitute { key, value } => {
+            if context.contains_key(key) {
+                let resolved = interpolate(value, captures);
+                context.insert(key.clone(), resolved.clone());
+                AppliedOp {
+                    op: "substitute",
+                    key: key.clone(),
+                    value: Some(resolved),
+                }
+            } else {
+                AppliedOp {
+                    op: "substitute",
+                    key: key.clone(),
+                    value: None,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn set_inserts_a_new_key() {
+        let mut context = ctx(&[]);
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Set {
+                key: "path".into(),
+                value: "/safe".into(),
+            }],
+        };
+        let applied = apply(&template, &mut context, &Captures::new());
+        assert_eq!(context.get("path"), Some(&"/safe".to_string()));
+        assert_eq!(applied[0].value.as_deref(), Some("/safe"));
+    }
+
+    #[test]
+    fn set_interpolates_captures() {
+        let mut context = ctx(&[]);
+        let mut captures = Captures::new();
+        captures.insert("ticket".into(), "JIRA-1".into());
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Set {
+                key: "branch".into(),
+                value: "fix/${ticket}".into(),
+            }],
+        };
+        apply(&template, &mut context, &captures);
+        assert_eq!(context.get("branch"), Some(&"fix/JIRA-1".to_string()));
+    }
+
+    #[test]
+    fn remove_deletes_an_existing_key() {
+        let mut context = ctx(&[("secret", "hunter2")]);
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Remove {
+                key: "secret".into(),
+            }],
+        };
+        let applied = apply(&template, &mut context, &Captures::new());
+        assert!(!context.contains_key("secret"));
+        assert_eq!(applied[0].value, None);
+    }
+
+    #[test]
+    fn remove_is_a_noop_for_a_missing_key() {
+        let mut context = ctx(&[]);
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Remove {
+                key: "missing".into(),
+            }],
+        };
+        apply(&template, &mut context, &Captures::new());
+        assert!(context.is_empty());
+    }
+
+    #[test]
+    fn substitute_overwrites_an_existing_key() {
+        let mut context = ctx(&[("arg", "rm -rf /")]);
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Substitute {
+                key: "arg".into(),
+                value: "[redacted]".into(),
+            }],
+        };
+        let applied = apply(&template, &mut context, &Captures::new());
+        assert_eq!(context.get("arg"), Some(&"[redacted]".to_string()));
+        assert_eq!(applied[0].value.as_deref(), Some("[redacted]"));
+    }
+
+    #[test]
+    fn substitute_is_a_noop_when_key_is_absent() {
+        let mut context = ctx(&[]);
+        let template = RewriteTemplate {
+            ops: vec![RewriteOp::Substitute {
+                key: "arg".into(),
+                value: "[redacted]".into(),
+            }],
+        };
+        let applied = apply(&template, &mut context, &Captures::new());
+        assert!(context.is_empty());
+        assert_eq!(applied[0].value, None);
+    }
+
+    #[test]
+    fn ops_apply_in_order_and_see_earlier_writes() {
+        let mut context = ctx(&[]);
+        let template = RewriteTemplate {
+            ops: vec![
+                RewriteOp::Set {
+                    key: "path".into(),
+                    value: "/tmp".into(),
+                },
+                RewriteOp::Substitute {
+                    key: "path".into(),
+                    value: "/tmp/safe".into(),
+                },
+            ],
+        };
+        apply(&template, &mut context, &Captures::new());
+        assert_eq!(context.get("path"), Some(&"/tmp/safe".to_string()));
+    }
+
+    #[test]
+    fn deserializes_from_tagged_json() {
+        let json = serde_json::json!({
+            "ops": [
+                { "op": "set", "key": "path", "value": "/safe" },
+                { "op": "remove", "key": "secret" },
+                { "op": "substitute", "key": "arg", "value": "[redacted]" }
+            ]
+        });
+        let template: RewriteTemplate = serde_json::from_value(json).unwrap();
+        assert_eq!(template.ops.len(), 3);
+    }
+}
@@ -0,0 +1,263 @@
+//! Environment and secret interpolation over raw config JSON, before it's
+//! deserialized into a [`TypedConfig`]/[`MatcherConfig`].
+//!
+//! A [`TypedConfig`]'s `config` field is a `serde_json::Value` deserialized
+//! verbatim, so there's no way to keep an API key or environment-specific
+//! value (a hostname, a tenant ID) out of a config that's otherwise safe to
+//! commit. [`interpolate`] walks every string leaf of a `serde_json::Value`
+//! and expands `${ENV_VAR}`/`${secret:name}` placeholders via a pluggable
+//! [`SecretResolver`], the way a CI pipeline templates a committed manifest
+//! against its own secret store at deploy time. Run it over the raw JSON
+//! before handing it to `Registry::load_*` — unlike
+//! [`crate::capture::interpolate`]'s `${name}` syntax (substituted into a
+//! matched *action* at evaluation time, against values captured from a live
+//! request), this is a load-time, config-wide pass with its own `${...}`
+//! placeholder vocabulary distinguished by the `secret:` prefix.
+
+use std::collections::HashMap;
+
+/// Resolves a single placeholder name to its value.
+///
+/// Implement this against whatever secret store a deployment uses (Vault,
+/// AWS Secrets Manager, a mounted file, …); [`EnvSecretResolver`] is the
+/// built-in default for `${ENV_VAR}`-style placeholders.
+pub trait SecretResolver {
+    /// Resolve `name` (the text between `${` and `}`, stripped of any
+    /// `secret:` prefix) to its value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `name` isn't known to this resolver. The
+    /// caller (`interpolate`) turns that into a load failure rather than
+    /// silently leaving the placeholder in the config.
+    fn resolve(&self, name: &str) -> Result<String, String>;
+}
+
+/// Resolves `${ENV_VAR}` placeholders from the process environment.
+///
+/// Sees only the bare name, so a `${secret:name}` placeholder is looked up
+/// as the environment variable `name` — pair it with another
+/// [`SecretResolver`] via [`ChainedResolver`] for configs whose secrets
+/// live elsewhere.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvSecretResolver;
+
+impl SecretResolver for EnvSecretResolver {
+    fn resolve(&self, name: &str) -> Result<String, String> {
+        std::env::var(name).map_err(|_| format!("environment variable {name:?} is not set"))
+    }
+}
+
+/// A fixed name → value table, useful for tests or a resolver backed by a
+/// config file read once at startup.
+#[derive(Debug, Clone, Default)]
+pub struct StaticSecretResolver {
+    values: HashMap<String, String>,
+}
+
+impl StaticSecretResolver {
+    /// Create a resolver over `values`.
+    #[must_use]
+    pub fn new(values: HashMap<String, String>) -> Self {
+        Self { values }
+    }
+}
+
+impl SecretResolver for StaticSecretResolver {
+    fn resolve(&self, name: &str) -> Result<String, String> {
+        self.values
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("no secret named {name:?}"))
+    }
+}
+
+/// Falls back from `env` to `secret` by name, so a config mixing
+/// `${ENV_VAR}` and `${secret:name}` placeholders (both stripped to a bare
+/// name by the time [`SecretResolver::resolve`] is called, see
+/// [`interpolate`]) can be served by a single resolver built from
+/// [`EnvSecretResolver`] plus a secret-store-backed one.
+pub struct ChainedResolver<E, S> {
+    env: E,
+    secret: S,
+}
+
+impl<E: SecretResolver, S: SecretResolver> ChainedResolver<E, S> {
+    /// Create a resolver that tries `env` first, then `secret`.
+    pub fn new(env: E, secret: S) -> Self {
+        Self { env, secret }
+    }
+}
+
+impl<E: SecretResolver, S: SecretResolver> SecretResolver for ChainedResolver<E, S> {
+    fn resolve(&self, name: &str) -> Result<String, String> {
+        self.env
+            .resolve(name)
+            .or_else(|_| self.secret.resolve(name))
+    }
+}
+
+/// A single `${...}` placeholder found while scanning a config string. The
+/// `secret:` prefix is only a config-authoring convenience for readability
+/// ("this one's a secret, not an env var") — both forms resolve to the same
+/// bare name, so callers needing different backing stores per prefix should
+/// compose that into their own [`SecretResolver`] rather than rely on
+/// [`interpolate`] to route by prefix.
+fn strip_placeholder_prefix(token: &str) -> &str {
+    token.strip_prefix("secret:").unwrap_or(token)
+}
+
+/// Expand every `${ENV_VAR}`/`${secret:name}` placeholder in every string
+/// leaf of `value`, in place, resolving `secret:`-prefixed names and plain
+/// names both through `resolver`.
+///
+/// A string leaf may contain more than one placeholder and literal text
+/// around them (`"https://${HOST}:${PORT}/api"`); each is expanded
+/// independently. Object keys and non-string leaves (numbers, bools, null)
+/// are left untouched — only values that are themselves strings are
+/// scanned.
+///
+/// # Errors
+///
+/// Returns an error string identifying the first unresolved placeholder
+/// (wrapping whatever [`SecretResolver::resolve`] reported), so a config
+/// with a missing secret fails to load instead of shipping a literal
+/// `${...}` into production.
+pub fn interpolate(
+    value: &mut serde_json::Value,
+    resolver: &dyn SecretResolver,
+) -> Result<(), String> {
+    match value {
+        serde_json::Value::String(s) => {
+            *s = interpolate_str(s, resolver)?;
+            Ok(())
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                interpolate(item, resolver)?;
+            }
+            Ok(())
+        }
+        serde_json::Value::Object(map) => {
+            for (_, v) in map.iter_mut() {
+                interpolate(v, resolver)?;
+            }
+            Ok(())
+        }
+        serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+            Ok(())
+        }
+    }
+}
+
+/// Resolve every `${...}` placeholder in `input`, returning the substituted
+/// string (or `input` unchanged if it has none).
+fn interpolate_str(input: &str, resolver: &dyn SecretResolver) -> Result<String, String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            // Unterminated `${` — treat the rest of the string as literal
+            // rather than silently truncating it.
+            out.push_str(rest);
+            return Ok(out);
+        };
+        out.push_str(&rest[..start]);
+        let token = &rest[start + 2..start + end];
+        let name = strip_placeholder_prefix(token);
+        let resolved = resolver
+            .resolve(name)
+            .map_err(|source| format!("unresolved placeholder \"${{{token}}}\": {source}"))?;
+        out.push_str(&resolved);
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_a_single_env_placeholder() {
+        let resolver = StaticSecretResolver::new(HashMap::from([(
+            "API_HOST".to_string(),
+            "api.internal".to_string(),
+        )]));
+        let mut value = serde_json::json!({ "host": "${API_HOST}" });
+
+        interpolate(&mut value, &resolver).unwrap();
+
+        assert_eq!(value, serde_json::json!({ "host": "api.internal" }));
+    }
+
+    #[test]
+    fn expands_multiple_placeholders_in_one_string() {
+        let resolver = StaticSecretResolver::new(HashMap::from([
+            ("HOST".to_string(), "example.com".to_string()),
+            ("PORT".to_string(), "8443".to_string()),
+        ]));
+        let mut value = serde_json::json!("https://${HOST}:${PORT}/api");
+
+        interpolate(&mut value, &resolver).unwrap();
+
+        assert_eq!(value, serde_json::json!("https://example.com:8443/api"));
+    }
+
+    #[test]
+    fn strips_secret_prefix_before_resolving() {
+        let resolver =
+            StaticSecretResolver::new(HashMap::from([("token".to_string(), "sekret".to_string())]));
+        let mut value = serde_json::json!("${secret:token}");
+
+        interpolate(&mut value, &resolver).unwrap();
+
+        assert_eq!(value, serde_json::json!("sekret"));
+    }
+
+    #[test]
+    fn fails_on_unresolved_placeholder() {
+        let resolver = StaticSecretResolver::default();
+        let mut value = serde_json::json!("${MISSING}");
+
+        let err = interpolate(&mut value, &resolver).unwrap_err();
+        assert!(err.contains("MISSING"));
+    }
+
+    #[test]
+    fn recurses_into_arrays_and_nested_objects() {
+        let resolver = StaticSecretResolver::new(HashMap::from([(
+            "TENANT".to_string(),
+            "acme".to_string(),
+        )]));
+        let mut value = serde_json::json!({
+            "matchers": [
+                { "tenant": "${TENANT}" },
+                { "tenant": "static" }
+            ]
+        });
+
+        interpolate(&mut value, &resolver).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "matchers": [
+                    { "tenant": "acme" },
+                    { "tenant": "static" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn leaves_strings_without_placeholders_untouched() {
+        let resolver = StaticSecretResolver::default();
+        let mut value = serde_json::json!("plain value");
+
+        interpolate(&mut value, &resolver).unwrap();
+
+        assert_eq!(value, serde_json::json!("plain value"));
+    }
+}
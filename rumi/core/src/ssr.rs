@@ -0,0 +1,588 @@
+//! Structural search-and-replace over `PredicateConfig` trees.
+//!
+//! Lets operators describe a bulk policy migration as a declarative
+//! `{ pattern, replacement }` [`Rule`] instead of hand-editing every matching
+//! site in a `MatcherConfig`. A pattern mirrors the real config shapes
+//! ([`PredicatePattern`] mirrors [`PredicateConfig`], [`ValueMatchPattern`]
+//! mirrors [`ValueMatchConfig`]) except that any leaf may instead be a
+//! [`Var`](PredicatePattern::Var) metavariable, written as a name like
+//! `"input"` in the example below. A metavariable binds to whatever
+//! subtree or value it lines up against; if the same name appears twice in
+//! one pattern, every occurrence must bind to an equal value (the
+//! consistency invariant classic SSR tools rely on).
+//!
+//! The pipeline is the usual three steps:
+//!
+//! 1. **match** — [`match_predicate`] recursively unifies a pattern against
+//!    a `PredicateConfig` node, accumulating [`Bindings`].
+//! 2. **substitute** — [`substitute_predicate`] rebuilds a `PredicateConfig`
+//!    from a (typically different) pattern using those bindings.
+//! 3. **rewrite** — [`rewrite_matcher`] walks a whole `MatcherConfig`,
+//!    applying a [`Rule`] at every predicate it finds, including inside
+//!    nested matchers reached through `OnMatchConfig::Matcher`. Matches are
+//!    non-overlapping: once a node is rewritten, its replacement is not
+//!    re-examined for further matches of the same rule.
+//!
+//! # Example
+//!
+//! Rewrite every `Exact("admin")` predicate on a given input into an `Or`
+//! of two exact matches on that same input:
+//!
+//! ```ignore
+//! let rule = Rule {
+//!     pattern: PredicatePattern::Single(SinglePredicatePattern {
+//!         input: InputPattern::Var("input".into()),
+//!         matcher: ValueMatchPattern::Exact(StringLeaf::Literal("admin".into())),
+//!     }),
+//!     replacement: PredicatePattern::Or {
+//!         predicates: vec![
+//!             PredicatePattern::Single(SinglePredicatePattern {
+//!                 input: InputPattern::Var("input".into()),
+//!                 matcher: ValueMatchPattern::Exact(StringLeaf::Literal("root".into())),
+//!             }),
+//!             PredicatePattern::Single(SinglePredicatePattern {
+//!                 input: InputPattern::Var("input".into()),
+//!                 matcher: ValueMatchPattern::Exact(StringLeaf::Literal("superuser".into())),
+//!             }),
+//!         ],
+//!     },
+//! };
+//! let rewritten = rewrite_matcher(&mut config, &rule);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    FieldMatcherConfig, MatcherConfig, OnMatchConfig, PredicateConfig, SinglePredicateConfig,
+    StringMatchSpec, TypedConfig, ValueMatchConfig,
+};
+
+/// A value a metavariable can bind to.
+#[derive(Debug, Clone)]
+pub enum Binding {
+    /// Bound to a whole predicate subtree (via [`PredicatePattern::Var`]).
+    Predicate(PredicateConfig),
+    /// Bound to a value-match (via [`ValueMatchPattern::Var`]).
+    ValueMatch(ValueMatchConfig),
+    /// Bound to a typed input reference (via [`InputPattern::Var`]).
+    Input(TypedConfig),
+    /// Bound to a string leaf (via [`StringLeaf::Var`]).
+    Str(String),
+}
+
+/// Metavariable name → the value it bound to during a match.
+pub type Bindings = HashMap<String, Binding>;
+
+/// Pattern mirror of [`PredicateConfig`]; any node may be a [`Var`](Self::Var).
+#[derive(Debug, Clone)]
+pub enum PredicatePattern {
+    /// Matches a `PredicateConfig::Single`.
+    Single(SinglePredicatePattern),
+    /// Matches a `PredicateConfig::And` with exactly these child patterns, in order.
+    And { predicates: Vec<PredicatePattern> },
+    /// Matches a `PredicateConfig::Or` with exactly these child patterns, in order.
+    Or { predicates: Vec<PredicatePattern> },
+    /// Matches a `PredicateConfig::Not` wrapping this inner pattern.
+    Not { predicate: Box<PredicatePattern> },
+    /// Matches any predicate subtree, binding it whole to `name`.
+    Var(String),
+}
+
+/// Pattern mirror of [`SinglePredicateConfig`].
+#[derive(Debug, Clone)]
+pub struct SinglePredicatePattern {
+    pub input: InputPattern,
+    pub matcher: ValueMatchPattern,
+}
+
+/// Pattern mirror of [`SinglePredicateConfig::input`] (a [`TypedConfig`]).
+#[derive(Debug, Clone)]
+pub enum InputPattern {
+    /// Must match this exact `TypedConfig`.
+    Literal(TypedConfig),
+    /// Matches any input, binding it whole to `name`.
+    Var(String),
+}
+
+/// Pattern mirror of [`ValueMatchConfig`].
+#[derive(Debug, Clone)]
+pub enum ValueMatchPattern {
+    Exact(StringLeaf),
+    Prefix(StringLeaf),
+    Suffix(StringLeaf),
+    Contains(StringLeaf),
+    Regex(StringLeaf),
+    /// Matches `ValueMatchConfig::Custom` with this (literal or var) input.
+    Custom(InputPattern),
+    /// Matches any value-match, binding it whole to `name`.
+    Var(String),
+}
+
+/// Pattern mirror of a string leaf (e.g. the argument to `Exact`/`Prefix`).
+#[derive(Debug, Clone)]
+pub enum StringLeaf {
+    /// Must match this exact string.
+    Literal(String),
+    /// Matches any string, binding it to `name`.
+    Var(String),
+}
+
+/// A rewrite rule: match `pattern` against a predicate, substitute bound
+/// metavariables into `replacement`.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub pattern: PredicatePattern,
+    pub replacement: PredicatePattern,
+}
+
+/// Binds `name` to `value` in `bindings`, enforcing the consistency
+/// invariant: a metavariable seen twice must bind to equal values both
+/// times. Returns `false` (without mutating) on a conflicting rebind.
+fn bind(bindings: &mut Bindings, name: &str, value: Binding) -> bool {
+    match bindings.get(name) {
+        Some(existing) => binding_eq(existing, &value),
+        None => {
+            bindings.insert(name.to_string(), value);
+            true
+        }
+    }
+}
+
+fn binding_eq(a: &Binding, b: &Binding) -> bool {
+    match (a, b) {
+        (Binding::Predicate(a), Binding::Predicate(b)) => a == b,
+        (Binding::ValueMatch(a), Binding::ValueMatch(b)) => a == b,
+        (Binding::Input(a), Binding::Input(b)) => a == b,
+        (Binding::Str(a), Binding::Str(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Try to unify `pattern` against `node`, recording metavariable bindings.
+///
+/// Returns `false` (bindings from this call may be partially applied) on a
+/// structural mismatch or a metavariable consistency conflict.
+pub fn match_predicate(
+    pattern: &PredicatePattern,
+    node: &PredicateConfig,
+    bindings: &mut Bindings,
+) -> bool {
+    match pattern {
+        PredicatePattern::Var(name) => bind(bindings, name, Binding::Predicate(node.clone())),
+        PredicatePattern::Single(sp) => match node {
+            PredicateConfig::Single(single) => match_single_predicate(sp, single, bindings),
+            _ => false,
+        },
+        PredicatePattern::And { predicates: pats } => match node {
+            PredicateConfig::And { predicates: nodes } => {
+                match_predicate_list(pats, nodes, bindings)
+            }
+            _ => false,
+        },
+        PredicatePattern::Or { predicates: pats } => match node {
+            PredicateConfig::Or { predicates: nodes } => {
+                match_predicate_list(pats, nodes, bindings)
+            }
+            _ => false,
+        },
+        PredicatePattern::Not { predicate: inner } => match node {
+            PredicateConfig::Not { predicate: node } => match_predicate(inner, node, bindings),
+            _ => false,
+        },
+    }
+}
+
+fn match_predicate_list(
+    pats: &[PredicatePattern],
+    nodes: &[PredicateConfig],
+    bindings: &mut Bindings,
+) -> bool {
+    pats.len() == nodes.len()
+        && pats
+            .iter()
+            .zip(nodes)
+            .all(|(p, n)| match_predicate(p, n, bindings))
+}
+
+fn match_single_predicate(
+    pattern: &SinglePredicatePattern,
+    node: &SinglePredicateConfig,
+    bindings: &mut Bindings,
+) -> bool {
+    match_input(&pattern.input, &node.input, bindings)
+        && match_value_match(&pattern.matcher, &node.matcher, bindings)
+}
+
+fn match_input(pattern: &InputPattern, node: &TypedConfig, bindings: &mut Bindings) -> bool {
+    match pattern {
+        InputPattern::Var(name) => bind(bindings, name, Binding::Input(node.clone())),
+        InputPattern::Literal(expected) => expected == node,
+    }
+}
+
+fn match_value_match(
+    pattern: &ValueMatchPattern,
+    node: &ValueMatchConfig,
+    bindings: &mut Bindings,
+) -> bool {
+    match pattern {
+        ValueMatchPattern::Var(name) => bind(bindings, name, Binding::ValueMatch(node.clone())),
+        ValueMatchPattern::Custom(input_pattern) => match node {
+            ValueMatchConfig::Custom(input) => match_input(input_pattern, input, bindings),
+            _ => false,
+        },
+        _ => match node {
+            ValueMatchConfig::BuiltIn(spec) => match (pattern, spec) {
+                (ValueMatchPattern::Exact(leaf), StringMatchSpec::Exact(s)) => {
+                    match_string(leaf, s, bindings)
+                }
+                (ValueMatchPattern::Prefix(leaf), StringMatchSpec::Prefix(s)) => {
+                    match_string(leaf, s, bindings)
+                }
+                (ValueMatchPattern::Suffix(leaf), StringMatchSpec::Suffix(s)) => {
+                    match_string(leaf, s, bindings)
+                }
+                (ValueMatchPattern::Contains(leaf), StringMatchSpec::Contains(s)) => {
+                    match_string(leaf, s, bindings)
+                }
+                (ValueMatchPattern::Regex(leaf), StringMatchSpec::Regex(s)) => {
+                    match_string(leaf, s, bindings)
+                }
+                _ => false,
+            },
+            ValueMatchConfig::Custom(_) => false,
+        },
+    }
+}
+
+fn match_string(pattern: &StringLeaf, node: &str, bindings: &mut Bindings) -> bool {
+    match pattern {
+        StringLeaf::Literal(expected) => expected == node,
+        StringLeaf::Var(name) => bind(bindings, name, Binding::Str(node.to_string())),
+    }
+}
+
+/// Rebuild a `PredicateConfig` from `pattern`, substituting bound
+/// metavariables from `bindings`.
+///
+/// Returns `None` if `pattern` references a metavariable not present in
+/// `bindings`, or one bound to the wrong kind of value (e.g. substituting a
+/// string-bound variable into a predicate position).
+pub fn substitute_predicate(
+    pattern: &PredicatePattern,
+    bindings: &Bindings,
+) -> Option<PredicateConfig> {
+    match pattern {
+        PredicatePattern::Var(name) => match bindings.get(name)? {
+            Binding::Predicate(p) => Some(p.clone()),
+            _ => None,
+        },
+        PredicatePattern::Single(sp) => Some(PredicateConfig::Single(SinglePredicateConfig {
+            input: substitute_input(&sp.input, bindings)?,
+            matcher: substitute_value_match(&sp.matcher, bindings)?,
+            capture: None,
+        })),
+        PredicatePattern::And { predicates } => Some(PredicateConfig::And {
+            predicates: substitute_predicate_list(predicates, bindings)?,
+        }),
+        PredicatePattern::Or { predicates } => Some(PredicateConfig::Or {
+            predicates: substitute_predicate_list(predicates, bindings)?,
+        }),
+        PredicatePattern::Not { predicate } => Some(PredicateConfig::Not {
+            predicate: Box::new(substitute_predicate(predicate, bindings)?),
+        }),
+    }
+}
+
+fn substitute_predicate_list(
+    patterns: &[PredicatePattern],
+    bindings: &Bindings,
+) -> Option<Vec<PredicateConfig>> {
+    patterns
+        .iter()
+        .map(|p| substitute_predicate(p, bindings))
+        .collect()
+}
+
+fn substitute_input(pattern: &InputPattern, bindings: &Bindings) -> Option<TypedConfig> {
+    match pattern {
+        InputPattern::Literal(tc) => Some(tc.clone()),
+        InputPattern::Var(name) => match bindings.get(name)? {
+            Binding::Input(tc) => Some(tc.clone()),
+            _ => None,
+        },
+    }
+}
+
+fn substitute_value_match(
+    pattern: &ValueMatchPattern,
+    bindings: &Bindings,
+) -> Option<ValueMatchConfig> {
+    match pattern {
+        ValueMatchPattern::Var(name) => match bindings.get(name)? {
+            Binding::ValueMatch(vm) => Some(vm.clone()),
+            _ => None,
+        },
+        ValueMatchPattern::Custom(input) => Some(ValueMatchConfig::Custom(substitute_input(
+            input, bindings,
+        )?)),
+        ValueMatchPattern::Exact(leaf) => Some(ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(
+            substitute_string(leaf, bindings)?,
+        ))),
+        ValueMatchPattern::Prefix(leaf) => Some(ValueMatchConfig::BuiltIn(
+            StringMatchSpec::Prefix(substitute_string(leaf, bindings)?),
+        )),
+        ValueMatchPattern::Suffix(leaf) => Some(ValueMatchConfig::BuiltIn(
+            StringMatchSpec::Suffix(substitute_string(leaf, bindings)?),
+        )),
+        ValueMatchPattern::Contains(leaf) => Some(ValueMatchConfig::BuiltIn(
+            StringMatchSpec::Contains(substitute_string(leaf, bindings)?),
+        )),
+        ValueMatchPattern::Regex(leaf) => Some(ValueMatchConfig::BuiltIn(
+            StringMatchSpec::Regex(substitute_string(leaf, bindings)?),
+        )),
+    }
+}
+
+fn substitute_string(pattern: &StringLeaf, bindings: &Bindings) -> Option<String> {
+    match pattern {
+        StringLeaf::Literal(s) => Some(s.clone()),
+        StringLeaf::Var(name) => match bindings.get(name)? {
+            Binding::Str(s) => Some(s.clone()),
+            _ => None,
+        },
+    }
+}
+
+/// Apply `rule` once to `node` if it matches, replacing it in place.
+/// Returns whether a replacement happened.
+fn try_rewrite_predicate(node: &mut PredicateConfig, rule: &Rule) -> bool {
+    let mut bindings = Bindings::new();
+    if !match_predicate(&rule.pattern, node, &mut bindings) {
+        return false;
+    }
+    match substitute_predicate(&rule.replacement, &bindings) {
+        Some(replacement) => {
+            *node = replacement;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Walk `node`, rewriting every non-overlapping match of `rule`.
+///
+/// A node that's rewritten is not re-examined afterwards (its replacement
+/// may itself contain the pattern, e.g. an identity-like rule, but that's
+/// not considered a second match). Returns the number of rewrites made.
+fn rewrite_predicate(node: &mut PredicateConfig, rule: &Rule) -> usize {
+    if try_rewrite_predicate(node, rule) {
+        return 1;
+    }
+    match node {
+        PredicateConfig::Single(_) => 0,
+        PredicateConfig::And { predicates } | PredicateConfig::Or { predicates } => {
+            predicates.iter_mut().map(|p| rewrite_predicate(p, rule)).sum()
+        }
+        PredicateConfig::Not { predicate } => rewrite_predicate(predicate, rule),
+    }
+}
+
+/// Apply `rule` to every predicate reachable from `matcher`, including
+/// those inside matchers nested under `OnMatchConfig::Matcher` (both as a
+/// field matcher's `on_match` and as `on_no_match`). Returns the total
+/// number of rewrites made.
+pub fn rewrite_matcher<A>(matcher: &mut MatcherConfig<A>, rule: &Rule) -> usize {
+    let mut count = 0;
+    for field_matcher in &mut matcher.matchers {
+        count += rewrite_predicate(&mut field_matcher.predicate, rule);
+        count += rewrite_on_match(&mut field_matcher.on_match, rule);
+    }
+    if let Some(on_no_match) = &mut matcher.on_no_match {
+        count += rewrite_on_match(on_no_match, rule);
+    }
+    count
+}
+
+fn rewrite_on_match<A>(on_match: &mut OnMatchConfig<A>, rule: &Rule) -> usize {
+    match on_match {
+        OnMatchConfig::Action { .. } => 0,
+        OnMatchConfig::Matcher { matcher, .. } => rewrite_matcher(matcher, rule),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_single(input_url: &str, value: &str) -> PredicateConfig {
+        PredicateConfig::Single(SinglePredicateConfig {
+            input: TypedConfig {
+                type_url: input_url.into(),
+                config: serde_json::json!({}),
+            },
+            matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(value.into())),
+            capture: None,
+        })
+    }
+
+    fn admin_to_root_or_superuser_rule() -> Rule {
+        Rule {
+            pattern: PredicatePattern::Single(SinglePredicatePattern {
+                input: InputPattern::Var("input".into()),
+                matcher: ValueMatchPattern::Exact(StringLeaf::Literal("admin".into())),
+            }),
+            replacement: PredicatePattern::Or {
+                predicates: vec![
+                    PredicatePattern::Single(SinglePredicatePattern {
+                        input: InputPattern::Var("input".into()),
+                        matcher: ValueMatchPattern::Exact(StringLeaf::Literal("root".into())),
+                    }),
+                    PredicatePattern::Single(SinglePredicatePattern {
+                        input: InputPattern::Var("input".into()),
+                        matcher: ValueMatchPattern::Exact(StringLeaf::Literal("superuser".into())),
+                    }),
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn match_single_predicate_binds_input_var() {
+        let node = exact_single("role", "admin");
+        let rule = admin_to_root_or_superuser_rule();
+        let mut bindings = Bindings::new();
+        assert!(match_predicate(&rule.pattern, &node, &mut bindings));
+        match bindings.get("input") {
+            Some(Binding::Input(tc)) => assert_eq!(tc.type_url, "role"),
+            other => panic!("expected Input binding, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn match_fails_on_different_literal() {
+        let node = exact_single("role", "viewer");
+        let rule = admin_to_root_or_superuser_rule();
+        let mut bindings = Bindings::new();
+        assert!(!match_predicate(&rule.pattern, &node, &mut bindings));
+    }
+
+    #[test]
+    fn repeated_metavariable_must_bind_consistently() {
+        let pattern = PredicatePattern::And {
+            predicates: vec![
+                PredicatePattern::Single(SinglePredicatePattern {
+                    input: InputPattern::Var("x".into()),
+                    matcher: ValueMatchPattern::Var("m1".into()),
+                }),
+                PredicatePattern::Single(SinglePredicatePattern {
+                    input: InputPattern::Var("x".into()),
+                    matcher: ValueMatchPattern::Var("m2".into()),
+                }),
+            ],
+        };
+
+        let consistent = PredicateConfig::And {
+            predicates: vec![exact_single("role", "admin"), exact_single("role", "viewer")],
+        };
+        let mut bindings = Bindings::new();
+        assert!(match_predicate(&pattern, &consistent, &mut bindings));
+
+        let inconsistent = PredicateConfig::And {
+            predicates: vec![exact_single("role", "admin"), exact_single("org", "acme")],
+        };
+        let mut bindings = Bindings::new();
+        assert!(!match_predicate(&pattern, &inconsistent, &mut bindings));
+    }
+
+    #[test]
+    fn substitute_rebuilds_replacement_with_bindings() {
+        let node = exact_single("role", "admin");
+        let rule = admin_to_root_or_superuser_rule();
+        let mut bindings = Bindings::new();
+        assert!(match_predicate(&rule.pattern, &node, &mut bindings));
+        let replacement = substitute_predicate(&rule.replacement, &bindings).unwrap();
+        match replacement {
+            PredicateConfig::Or { predicates } => {
+                assert_eq!(predicates.len(), 2);
+                assert_eq!(predicates[0], exact_single("role", "root"));
+                assert_eq!(predicates[1], exact_single("role", "superuser"));
+            }
+            other => panic!("expected Or, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rewrite_matcher_replaces_every_matching_field_matcher() {
+        let rule = admin_to_root_or_superuser_rule();
+        let mut config = MatcherConfig::<String> {
+            matchers: vec![
+                FieldMatcherConfig {
+                    predicate: exact_single("role", "admin"),
+                    on_match: OnMatchConfig::Action {
+                        action: "allow".into(),
+                        keep_matching: false,
+                        rewrite: None,
+                    },
+                },
+                FieldMatcherConfig {
+                    predicate: exact_single("role", "viewer"),
+                    on_match: OnMatchConfig::Action {
+                        action: "deny".into(),
+                        keep_matching: false,
+                        rewrite: None,
+                    },
+                },
+            ],
+            on_no_match: None,
+        };
+
+        let count = rewrite_matcher(&mut config, &rule);
+        assert_eq!(count, 1);
+        assert!(matches!(
+            config.matchers[0].predicate,
+            PredicateConfig::Or { .. }
+        ));
+        assert!(matches!(
+            config.matchers[1].predicate,
+            PredicateConfig::Single(_)
+        ));
+    }
+
+    #[test]
+    fn rewrite_matcher_recurses_into_nested_matcher() {
+        let rule = admin_to_root_or_superuser_rule();
+        let mut config = MatcherConfig::<String> {
+            matchers: vec![FieldMatcherConfig {
+                predicate: exact_single("stage", "inner"),
+                on_match: OnMatchConfig::Matcher {
+                    matcher: Box::new(MatcherConfig {
+                        matchers: vec![FieldMatcherConfig {
+                            predicate: exact_single("role", "admin"),
+                            on_match: OnMatchConfig::Action {
+                                action: "allow".into(),
+                                keep_matching: false,
+                                rewrite: None,
+                            },
+                        }],
+                        on_no_match: None,
+                    }),
+                    keep_matching: false,
+                },
+            }],
+            on_no_match: None,
+        };
+
+        let count = rewrite_matcher(&mut config, &rule);
+        assert_eq!(count, 1);
+        match &config.matchers[0].on_match {
+            OnMatchConfig::Matcher { matcher, .. } => {
+                assert!(matches!(
+                    matcher.matchers[0].predicate,
+                    PredicateConfig::Or { .. }
+                ));
+            }
+            other => panic!("expected nested matcher, got {other:?}"),
+        }
+    }
+}
@@ -3,13 +3,25 @@
 //! These provide IDE autocomplete and type safety on the Python side,
 //! rather than exposing raw dicts (Ace recommendation from guild review).
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// How to match a string value.
 ///
 /// Bare strings passed to `HookMatch` fields are treated as exact matches.
+///
+/// # Named captures
+///
+/// A `Regex` pattern's named groups (`(?P<name>...)`) bind into a
+/// per-evaluation capture map, mirroring [`rumi::capture`]'s `${name}`
+/// scheme. A later field's `Ref { name }` then matches only if its value
+/// equals whatever that name captured — e.g. match `git_branch` only when it
+/// ends with the same ticket id `cwd` captured. `Regex` fields are evaluated
+/// first (binding captures), `Ref` fields second; see
+/// [`PyHookMatch::validate`] for the compile-time checks this depends on.
 #[pyclass]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PyStringMatch {
     /// Exact equality.
     Exact { value: String },
@@ -20,7 +32,11 @@ pub enum PyStringMatch {
     /// Contains substring.
     Contains { value: String },
     /// Matches regular expression (Rust `regex` crate syntax — linear time).
+    /// Named groups (`(?P<name>...)`) bind into the rule's capture map.
     Regex { pattern: String },
+    /// Matches only if the field's value equals the value a `Regex` named
+    /// group elsewhere in the same `HookMatch` captured under `name`.
+    Ref { name: String },
 }
 
 #[pymethods]
@@ -55,6 +71,13 @@ impl PyStringMatch {
         Self::Regex { pattern }
     }
 
+    /// Create a reference to a capture bound by a `Regex` match elsewhere in
+    /// the same `HookMatch`.
+    #[staticmethod]
+    fn r#ref(name: String) -> Self {
+        Self::Ref { name }
+    }
+
     fn __repr__(&self) -> String {
         match self {
             Self::Exact { value } => format!("StringMatch.exact({value:?})"),
@@ -62,6 +85,132 @@ impl PyStringMatch {
             Self::Suffix { value } => format!("StringMatch.suffix({value:?})"),
             Self::Contains { value } => format!("StringMatch.contains({value:?})"),
             Self::Regex { pattern } => format!("StringMatch.regex({pattern:?})"),
+            Self::Ref { name } => format!("StringMatch.ref({name:?})"),
+        }
+    }
+
+    /// Serialize to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse from a JSON string produced by `to_json`.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+/// A single mutation to apply to a key-value context when the owning
+/// `HookMatch` matches — mirrors [`rumi::rewrite::RewriteOp`] one-for-one,
+/// including its `{"op": "...", ...}` JSON shape.
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op")]
+pub enum PyRewriteOp {
+    /// Insert or overwrite `key` with `value` (after `${name}` interpolation).
+    #[serde(rename = "set")]
+    Set { key: String, value: String },
+    /// Delete `key` if present; a no-op otherwise.
+    #[serde(rename = "remove")]
+    Remove { key: String },
+    /// Overwrite `key`'s existing value with `value`; a no-op if `key` isn't
+    /// already present.
+    #[serde(rename = "substitute")]
+    Substitute { key: String, value: String },
+}
+
+#[pymethods]
+impl PyRewriteOp {
+    /// Insert or overwrite `key` with `value`.
+    #[staticmethod]
+    fn set(key: String, value: String) -> Self {
+        Self::Set { key, value }
+    }
+
+    /// Delete `key` if present.
+    #[staticmethod]
+    fn remove(key: String) -> Self {
+        Self::Remove { key }
+    }
+
+    /// Overwrite `key`'s existing value, leaving it untouched if absent.
+    #[staticmethod]
+    fn substitute(key: String, value: String) -> Self {
+        Self::Substitute { key, value }
+    }
+
+    fn __repr__(&self) -> String {
+        match self {
+            Self::Set { key, value } => format!("RewriteOp.set({key:?}, {value:?})"),
+            Self::Remove { key } => format!("RewriteOp.remove({key:?})"),
+            Self::Substitute { key, value } => format!("RewriteOp.substitute({key:?}, {value:?})"),
+        }
+    }
+
+    /// Serialize to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse from a JSON string produced by `to_json`.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl From<&PyRewriteOp> for rumi::rewrite::RewriteOp {
+    fn from(op: &PyRewriteOp) -> Self {
+        match op {
+            PyRewriteOp::Set { key, value } => rumi::rewrite::RewriteOp::Set {
+                key: key.clone(),
+                value: value.clone(),
+            },
+            PyRewriteOp::Remove { key } => rumi::rewrite::RewriteOp::Remove { key: key.clone() },
+            PyRewriteOp::Substitute { key, value } => rumi::rewrite::RewriteOp::Substitute {
+                key: key.clone(),
+                value: value.clone(),
+            },
+        }
+    }
+}
+
+/// An ordered list of [`PyRewriteOp`]s — the Python-facing counterpart of
+/// [`rumi::rewrite::RewriteTemplate`].
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PyRewriteTemplate {
+    pub(crate) ops: Vec<PyRewriteOp>,
+}
+
+#[pymethods]
+impl PyRewriteTemplate {
+    #[new]
+    fn new(ops: Vec<PyRewriteOp>) -> Self {
+        Self { ops }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RewriteTemplate({} ops)", self.ops.len())
+    }
+
+    /// Serialize to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse from a JSON string produced by `to_json`.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl From<&PyRewriteTemplate> for rumi::rewrite::RewriteTemplate {
+    fn from(template: &PyRewriteTemplate) -> Self {
+        rumi::rewrite::RewriteTemplate {
+            ops: template.ops.iter().map(Into::into).collect(),
         }
     }
 }
@@ -77,7 +226,7 @@ impl PyStringMatch {
 /// unless `match_all=True` is explicitly passed. This prevents accidental
 /// catch-all rules from deserialization bugs.
 #[pyclass]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PyHookMatch {
     pub(crate) event: Option<String>,
     pub(crate) tool_name: Option<PyStringMatch>,
@@ -86,6 +235,9 @@ pub struct PyHookMatch {
     pub(crate) cwd: Option<PyStringMatch>,
     pub(crate) git_branch: Option<PyStringMatch>,
     pub(crate) match_all: bool,
+    /// Mutations to apply to the context alongside the matched action — see
+    /// [`PyRewriteTemplate`]. `None` leaves the context untouched.
+    pub(crate) rewrite: Option<PyRewriteTemplate>,
 }
 
 #[pymethods]
@@ -102,6 +254,7 @@ impl PyHookMatch {
         cwd = None,
         git_branch = None,
         match_all = false,
+        rewrite = None,
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -112,6 +265,7 @@ impl PyHookMatch {
         cwd: Option<PyStringMatchOrStr>,
         git_branch: Option<PyStringMatchOrStr>,
         match_all: bool,
+        rewrite: Option<PyRewriteTemplate>,
     ) -> Self {
         Self {
             event,
@@ -125,15 +279,303 @@ impl PyHookMatch {
             cwd: cwd.map(Into::into),
             git_branch: git_branch.map(Into::into),
             match_all,
+            rewrite,
         }
     }
 
+    /// Check this rule for compile-time errors that deserialization alone
+    /// can't catch.
+    ///
+    /// Currently validates the named-capture graph across `Regex`/`Ref`
+    /// fields: every `Ref { name }` must reference a name some `Regex` field
+    /// in this same `HookMatch` actually binds, and no two `Regex` fields may
+    /// declare the same capture name (both would race to populate it, and
+    /// evaluation order between sibling fields isn't meaningful). A malformed
+    /// `Regex` pattern is also rejected here, since extracting its group
+    /// names requires compiling it.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if a `Regex` pattern fails to compile, if a
+    /// capture name is declared by more than one field, or if a `Ref` names a
+    /// capture no field declares.
+    fn validate(&self) -> PyResult<()> {
+        validate_captures(&self.fields()).map_err(PyValueError::new_err)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "HookMatch(event={:?}, tool_name={:?}, match_all={})",
             self.event, self.tool_name, self.match_all
         )
     }
+
+    /// Serialize to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse from a JSON string produced by `to_json`. Does not run
+    /// [`validate`](Self::validate) — call it separately if the input is
+    /// untrusted.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl PyHookMatch {
+    /// Every `PyStringMatch` field, paired with a name describing it for
+    /// error messages (`arguments` entries are named after their argument).
+    fn fields(&self) -> Vec<(String, &PyStringMatch)> {
+        let mut fields = Vec::new();
+        if let Some(m) = &self.tool_name {
+            fields.push(("tool_name".to_string(), m));
+        }
+        if let Some(m) = &self.session_id {
+            fields.push(("session_id".to_string(), m));
+        }
+        if let Some(m) = &self.cwd {
+            fields.push(("cwd".to_string(), m));
+        }
+        if let Some(m) = &self.git_branch {
+            fields.push(("git_branch".to_string(), m));
+        }
+        for (name, m) in &self.arguments {
+            fields.push((format!("arguments[{name}]"), m));
+        }
+        fields
+    }
+}
+
+/// Check a rule's capture graph: every `Regex` field's named groups are
+/// collected as declarations, then every `Ref { name }` field must resolve
+/// against that set, and no name may be declared twice.
+///
+/// Pulled out as a free function (rather than inlined in `validate`) so it
+/// can be exercised directly against a plain field list.
+fn validate_captures(fields: &[(String, &PyStringMatch)]) -> Result<(), String> {
+    let mut declared: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+
+    for (field, matcher) in fields {
+        if let PyStringMatch::Regex { pattern } = matcher {
+            let re = regex::Regex::new(pattern)
+                .map_err(|e| format!("{field}: invalid regex {pattern:?}: {e}"))?;
+            for name in re.capture_names().flatten() {
+                if let Some(first_field) = declared.insert(name.to_string(), field.clone()) {
+                    return Err(format!(
+                        "{field}: capture \"{name}\" is already declared by {first_field}"
+                    ));
+                }
+            }
+        }
+    }
+
+    for (field, matcher) in fields {
+        if let PyStringMatch::Ref { name } = matcher {
+            if !declared.contains_key(name) {
+                return Err(format!(
+                    "{field}: ref(\"{name}\") has no matching Regex capture in this HookMatch"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A boolean combinator tree over `PyHookMatch` leaves.
+///
+/// `PyHookMatch` alone can only AND its own fields together; `PyMatchGroup`
+/// adds `any_of`/`all_of`/`not_` so a rule can express e.g. "Bash or Shell,
+/// but not under /tmp". It mirrors `PredicateConfig::And`/`Or`/`Not`
+/// (see `rumi::PredicateConfig`) one-for-one; lowering a tree of these into
+/// the actual `MatcherConfig` the registry compiles is the domain
+/// compiler's job, which isn't part of this crate's sources, so it isn't
+/// wired up here.
+///
+/// # Fail-closed (Vector security requirement)
+///
+/// An empty `all_of([])` and a bare `not_` wrapping a catch-all branch both
+/// degenerate into an accidental always-match or always-reject rule, so both
+/// are rejected by [`PyMatchGroup::validate`] unless that node's own
+/// `match_all=True` opts in.
+///
+/// # Loading from JSON
+///
+/// [`to_json`](PyMatchGroup::to_json)/[`from_json`](PyMatchGroup::from_json)
+/// round-trip a tree through its `{"type": "hook" | "any_of" | "all_of" |
+/// "not", ...}` shape, so a rule can be loaded from a file or a gateway
+/// payload instead of built attribute-by-attribute in Python. Compiling the
+/// loaded tree into a `HookMatcher` is still the domain compiler's job (see
+/// above), so `HookMatcher` itself gets no matching `from_json` here.
+#[pyclass]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PyMatchGroup {
+    /// A flat `HookMatch` leaf.
+    #[serde(rename = "hook")]
+    Hook { hook: PyHookMatch },
+    /// Matches if any child matches.
+    #[serde(rename = "any_of")]
+    AnyOf {
+        children: Vec<PyMatchGroup>,
+        match_all: bool,
+    },
+    /// Matches if every child matches.
+    #[serde(rename = "all_of")]
+    AllOf {
+        children: Vec<PyMatchGroup>,
+        match_all: bool,
+    },
+    /// Matches if the child does not match.
+    #[serde(rename = "not")]
+    Not {
+        child: Box<PyMatchGroup>,
+        match_all: bool,
+    },
+}
+
+#[pymethods]
+impl PyMatchGroup {
+    /// Matches if any of `children` matches. An empty list never matches.
+    #[staticmethod]
+    #[pyo3(signature = (children, match_all = false))]
+    fn any_of(children: Vec<PyMatchGroupOrHook>, match_all: bool) -> Self {
+        Self::AnyOf {
+            children: children.into_iter().map(Into::into).collect(),
+            match_all,
+        }
+    }
+
+    /// Matches if every one of `children` matches. An empty list is a
+    /// catch-all and is rejected by `validate()` unless `match_all=True`.
+    #[staticmethod]
+    #[pyo3(signature = (children, match_all = false))]
+    fn all_of(children: Vec<PyMatchGroupOrHook>, match_all: bool) -> Self {
+        Self::AllOf {
+            children: children.into_iter().map(Into::into).collect(),
+            match_all,
+        }
+    }
+
+    /// Matches if `child` does not match.
+    #[staticmethod]
+    #[pyo3(signature = (child, match_all = false))]
+    fn not_(child: PyMatchGroupOrHook, match_all: bool) -> Self {
+        Self::Not {
+            child: Box::new(child.into()),
+            match_all,
+        }
+    }
+
+    /// Check this tree for compile-time errors: an unguarded empty
+    /// `all_of([])`, an unguarded `not_` wrapping a catch-all branch, or any
+    /// leaf `HookMatch` that fails its own
+    /// [`validate`](PyHookMatch::validate).
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` describing the first offending node found.
+    fn validate(&self) -> PyResult<()> {
+        validate_group(self).map_err(PyValueError::new_err)
+    }
+
+    fn __repr__(&self) -> String {
+        match self {
+            Self::Hook { hook } => format!("MatchGroup.hook({:?})", hook),
+            Self::AnyOf { children, .. } => format!("MatchGroup.any_of({} children)", children.len()),
+            Self::AllOf { children, .. } => format!("MatchGroup.all_of({} children)", children.len()),
+            Self::Not { .. } => "MatchGroup.not_(..)".to_string(),
+        }
+    }
+
+    /// Serialize to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Parse from a JSON string produced by `to_json`. Does not run
+    /// [`validate`](Self::validate) — call it separately if the input is
+    /// untrusted.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+/// Whether `group` structurally matches every possible context.
+fn is_catch_all(group: &PyMatchGroup) -> bool {
+    match group {
+        PyMatchGroup::Hook { hook } => hook.event.is_none() && hook.fields().is_empty(),
+        PyMatchGroup::AllOf { children, .. } => children.iter().all(is_catch_all),
+        PyMatchGroup::AnyOf { children, .. } => children.iter().any(is_catch_all),
+        PyMatchGroup::Not { child, .. } => is_never_match(child),
+    }
+}
+
+/// Whether `group` structurally matches no possible context.
+fn is_never_match(group: &PyMatchGroup) -> bool {
+    match group {
+        PyMatchGroup::Hook { .. } => false,
+        PyMatchGroup::AllOf { children, .. } => children.iter().any(is_never_match),
+        PyMatchGroup::AnyOf { children, .. } => children.iter().all(is_never_match),
+        PyMatchGroup::Not { child, .. } => is_catch_all(child),
+    }
+}
+
+fn validate_group(group: &PyMatchGroup) -> Result<(), String> {
+    match group {
+        PyMatchGroup::Hook { hook } => validate_captures(&hook.fields()),
+        PyMatchGroup::AllOf {
+            children,
+            match_all,
+        } => {
+            if children.is_empty() && !match_all {
+                return Err(
+                    "all_of([]) is a catch-all; pass match_all=True to allow it".to_string(),
+                );
+            }
+            children.iter().try_for_each(validate_group)
+        }
+        PyMatchGroup::AnyOf { children, .. } => children.iter().try_for_each(validate_group),
+        PyMatchGroup::Not { child, match_all } => {
+            if is_catch_all(child) && !match_all {
+                return Err(
+                    "not_(...) wraps a catch-all branch, so it never matches; pass \
+                     match_all=True to allow it"
+                        .to_string(),
+                );
+            }
+            validate_group(child)
+        }
+    }
+}
+
+/// Accept either `PyMatchGroup` or a bare `PyHookMatch` leaf.
+#[derive(Debug, Clone)]
+pub enum PyMatchGroupOrHook {
+    Group(PyMatchGroup),
+    Hook(PyHookMatch),
+}
+
+impl From<PyMatchGroupOrHook> for PyMatchGroup {
+    fn from(v: PyMatchGroupOrHook) -> Self {
+        match v {
+            PyMatchGroupOrHook::Group(g) => g,
+            PyMatchGroupOrHook::Hook(h) => PyMatchGroup::Hook { hook: h },
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for PyMatchGroupOrHook {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(hook) = ob.extract::<PyHookMatch>() {
+            Ok(Self::Hook(hook))
+        } else {
+            Ok(Self::Group(ob.extract::<PyMatchGroup>()?))
+        }
+    }
 }
 
 /// Accept either `PyStringMatch` or a bare `str` (→ exact match).
@@ -5,12 +5,112 @@
 //! by all implementations.
 
 use std::collections::HashMap;
+use std::time::Instant;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use rumi::prelude::*;
 use rumi_http::HttpRequest;
 
+/// Query params as either a `dict` (one value per key) or a list of
+/// `(key, value)` pairs — the list form preserves order and duplicate keys,
+/// which a `dict` can't represent, for query strings like `?tag=a&tag=b`.
+#[derive(Debug, Clone)]
+enum PyQueryParams {
+    Mapping(HashMap<String, String>),
+    Pairs(Vec<(String, String)>),
+}
+
+impl PyQueryParams {
+    /// Percent-decode every key and value, flattening to `(key, value)` pairs
+    /// in the order given (a `dict`'s order has no duplicates to preserve).
+    fn into_decoded_pairs(self) -> Vec<(String, String)> {
+        let pairs = match self {
+            PyQueryParams::Mapping(m) => m.into_iter().collect(),
+            PyQueryParams::Pairs(p) => p,
+        };
+        pairs
+            .into_iter()
+            .map(|(k, v)| (decode_query_component(&k), decode_query_component(&v)))
+            .collect()
+    }
+}
+
+impl<'py> FromPyObject<'py> for PyQueryParams {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(pairs) = ob.extract::<Vec<(String, String)>>() {
+            Ok(Self::Pairs(pairs))
+        } else {
+            Ok(Self::Mapping(ob.extract::<HashMap<String, String>>()?))
+        }
+    }
+}
+
+/// Decode `%XX` escapes and `+` (space, per `application/x-www-form-urlencoded`)
+/// in a raw query-string key or value. An escape that isn't valid hex is left
+/// verbatim — a malformed escape is a client bug, not a reason to fail a match.
+fn decode_query_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 3 <= bytes.len()
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).expect("ASCII hex digits");
+                out.push(u8::from_str_radix(hex, 16).expect("validated hex digits"));
+                i += 3;
+            }
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// One request out of an `evaluate_many()`/`trace_many()` batch — a `dict`
+/// with the same keys `evaluate()` takes as arguments.
+#[derive(Debug, Clone)]
+struct PyHttpRequestArgs {
+    method: String,
+    path: String,
+    headers: Option<HashMap<String, String>>,
+    query_params: Option<PyQueryParams>,
+    body: Option<Vec<u8>>,
+}
+
+impl<'py> FromPyObject<'py> for PyHttpRequestArgs {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(Self {
+            method: ob.get_item("method")?.extract()?,
+            path: ob.get_item("path")?.extract()?,
+            headers: dict_item_or_none(ob, "headers")?,
+            query_params: dict_item_or_none(ob, "query_params")?,
+            body: dict_item_or_none(ob, "body")?,
+        })
+    }
+}
+
+/// Extract an optional key from a `dict`-like object, treating a missing key
+/// the same as an explicit `None` value.
+fn dict_item_or_none<'py, T: FromPyObject<'py>>(
+    ob: &Bound<'py, PyAny>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    match ob.get_item(key) {
+        Ok(value) => value.extract(),
+        Err(_) => Ok(None),
+    }
+}
+
 /// An opaque compiled HTTP matcher.
 ///
 /// Created via `HttpMatcher.from_config()`, immutable after construction.
@@ -22,6 +122,11 @@ use rumi_http::HttpRequest;
 #[pyclass(frozen)]
 pub struct HttpMatcher {
     inner: Matcher<HttpRequest, String>,
+    /// Kept alongside `inner` so `path_params()` can re-walk the original
+    /// config for `xuma.http.v1.PathTemplateInput` predicates — see
+    /// `rumi_http::path_params`, since the compiled `inner` tree no longer
+    /// carries which type URL produced each predicate.
+    config: rumi::MatcherConfig<String>,
 }
 
 #[pymethods]
@@ -35,8 +140,21 @@ impl HttpMatcher {
     ///
     /// - `xuma.http.v1.PathInput` — request path (config: `{}`)
     /// - `xuma.http.v1.MethodInput` — HTTP method (config: `{}`)
-    /// - `xuma.http.v1.HeaderInput` — header value (config: `{"name": "..."}`)
-    /// - `xuma.http.v1.QueryParamInput` — query parameter (config: `{"name": "..."}`)
+    /// - `xuma.http.v1.HeaderInput` — header value (config: `{"name": "..."}`, or
+    ///   `{"name": "...", "mode": "any"|"all", "value_match": {...}}` to match every
+    ///   value of a repeated header)
+    /// - `xuma.http.v1.QueryParamInput` — query parameter (same config shape; see
+    ///   `evaluate()`'s `query_params` argument for how repeated keys and
+    ///   percent-encoding are handled before matching)
+    /// - `xuma.http.v1.PathTemplateInput` — path template (config: `{"template": "/users/{id}"}`)
+    /// - `xuma.http.v1.RoutePatternInput` — route template with wildcard/parameterized
+    ///   segments (config: `{"template": "/users/{id}/files/{rest:.*}"}` for a whole-path
+    ///   predicate, or add `"capture": "id"` to report that segment instead)
+    /// - `xuma.http.v1.BodyInput` — request body (config: `{}` for the raw body, or
+    ///   `{"pointer": "/event/type", "max_bytes": 65536}` for a JSON pointer into it)
+    ///
+    /// An action string may reference `${name}` placeholders bound by a
+    /// predicate's `capture` name (see `rumi::capture`).
     ///
     /// # Errors
     ///
@@ -45,6 +163,8 @@ impl HttpMatcher {
     /// - Unknown type URL (error lists available URLs)
     /// - Invalid regex pattern
     /// - Depth/width limits exceeded
+    /// - An action template references a `${name}` no predicate in its own
+    ///   `FieldMatcher` declares
     #[staticmethod]
     fn from_config(json_config: &str) -> PyResult<Self> {
         let config: rumi::MatcherConfig<String> = serde_json::from_str(json_config)
@@ -52,14 +172,17 @@ impl HttpMatcher {
 
         let registry = build_http_registry();
         let matcher = registry
-            .load_matcher(config)
+            .load_matcher(config.clone())
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
         matcher
             .validate()
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-        Ok(Self { inner: matcher })
+        Ok(Self {
+            inner: matcher,
+            config,
+        })
     }
 
     /// Evaluate an HTTP request against compiled matcher rules.
@@ -69,36 +192,70 @@ impl HttpMatcher {
     /// * `method` — HTTP method (e.g., "GET", "POST").
     /// * `path` — Request path (e.g., "/api/users").
     /// * `headers` — Request headers as key-value pairs (keys are case-insensitive).
-    /// * `query_params` — Query parameters as key-value pairs.
+    /// * `query_params` — Query parameters as a `dict` (one value per key) or a
+    ///   list of `(key, value)` pairs (preserves order and repeated keys, e.g.
+    ///   `?tag=a&tag=b`). Keys and values are percent-decoded either way.
+    /// * `body` — Request body bytes, for `xuma.http.v1.BodyInput`.
     ///
     /// # Returns
     ///
-    /// The action string if the request matched, or `None`.
-    #[pyo3(signature = (method, path, headers = None, query_params = None))]
+    /// The action string if the request matched, or `None`. Any `${name}`
+    /// placeholders a predicate's `capture` name would bind are *not*
+    /// substituted — threading a live `Captures` environment through
+    /// `Matcher::evaluate` and interpolating the resolved action is the
+    /// runtime engine's job (see `rumi::capture`), and that engine isn't
+    /// wired up here, so the action comes back with any `${name}` references
+    /// still literal. Does not report `PathTemplateInput` params — call
+    /// `path_params()` with the same path to get those alongside the action.
+    #[pyo3(signature = (method, path, headers = None, query_params = None, body = None))]
     fn evaluate(
         &self,
         method: &str,
         path: &str,
         headers: Option<HashMap<String, String>>,
-        query_params: Option<HashMap<String, String>>,
+        query_params: Option<PyQueryParams>,
+        body: Option<Vec<u8>>,
     ) -> Option<String> {
-        let req = build_request(method, path, headers, query_params);
+        let req = build_request(method, path, headers, query_params, body);
         self.inner.evaluate(&req)
     }
 
+    /// The path-template params captured for `path`, e.g. `{"id": "42"}` for a
+    /// `xuma.http.v1.PathTemplateInput` predicate compiled from `/users/{id}`.
+    /// Merges every matching template across the config; see
+    /// `rumi_http::path_params`.
+    fn path_params(&self, path: &str) -> HashMap<String, String> {
+        rumi_http::path_params(&self.config, path).into_iter().collect()
+    }
+
     /// Trace evaluation for debugging.
     ///
-    /// Returns the same result as `evaluate()` plus a detailed trace.
-    #[pyo3(signature = (method, path, headers = None, query_params = None))]
+    /// Returns the same result as `evaluate()` plus a detailed trace, one
+    /// step per top-level matcher evaluated before the first match. Does not report
+    /// `PathTemplateInput` params — call `path_params()` with the same path
+    /// to get those alongside the trace.
+    ///
+    /// `elapsed_ns`/`steps_evaluated`/`steps_skipped` profile which configs
+    /// dominate evaluation cost: `elapsed_ns` is wall-clock for this whole
+    /// call, `steps_evaluated` is `len(steps)`, and `steps_skipped` is how
+    /// many of the configured top-level matchers the first-match-wins
+    /// short-circuit never reached. Per-step timing and sub-match counts
+    /// aren't available here — recording those needs a monotonic clock
+    /// inside `Matcher::evaluate_with_trace` itself, which isn't part of
+    /// this crate's sources.
+    #[pyo3(signature = (method, path, headers = None, query_params = None, body = None))]
     fn trace(
         &self,
         method: &str,
         path: &str,
         headers: Option<HashMap<String, String>>,
-        query_params: Option<HashMap<String, String>>,
+        query_params: Option<PyQueryParams>,
+        body: Option<Vec<u8>>,
     ) -> super::matcher::PyTraceResult {
-        let req = build_request(method, path, headers, query_params);
+        let req = build_request(method, path, headers, query_params, body);
+        let started = Instant::now();
         let trace = self.inner.evaluate_with_trace(&req);
+        let elapsed_ns = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
 
         let steps: Vec<super::matcher::PyTraceStep> = trace
             .steps
@@ -109,14 +266,90 @@ impl HttpMatcher {
                 predicate: format!("{:?}", step.predicate_trace),
             })
             .collect();
+        let steps_evaluated = steps.len();
 
         super::matcher::PyTraceResult {
             result: trace.result,
             steps,
             used_fallback: trace.used_fallback,
+            elapsed_ns,
+            steps_evaluated,
+            steps_skipped: self.config.matchers.len().saturating_sub(steps_evaluated),
         }
     }
 
+    /// Evaluate a batch of requests in one call.
+    ///
+    /// Each element of `requests` is a `dict` with the same keys `evaluate()`
+    /// takes as arguments (`method`, `path`, and optionally `headers`,
+    /// `query_params`, `body`). Every `HttpRequest` is built first, with the
+    /// GIL held; running `Matcher::evaluate` over the whole batch then happens
+    /// with the GIL released — `Matcher<HttpRequest, String>` is immutable and `Send + Sync` once
+    /// compiled — which avoids paying per-call PyO3/GIL overhead in a Python
+    /// loop over thousands of requests (access logs, replayed traffic).
+    ///
+    /// Returns one action (or `None`) per request, in the same order.
+    fn evaluate_many(&self, py: Python<'_>, requests: Vec<PyHttpRequestArgs>) -> Vec<Option<String>> {
+        let built: Vec<HttpRequest> = requests
+            .into_iter()
+            .map(|r| build_request(&r.method, &r.path, r.headers, r.query_params, r.body))
+            .collect();
+
+        py.allow_threads(|| built.iter().map(|req| self.inner.evaluate(req)).collect())
+    }
+
+    /// Batched `trace()` — see `evaluate_many()` for the request batch shape
+    /// and the GIL-release rationale.
+    ///
+    /// Returns one trace per request, in the same order.
+    fn trace_many(
+        &self,
+        py: Python<'_>,
+        requests: Vec<PyHttpRequestArgs>,
+    ) -> Vec<super::matcher::PyTraceResult> {
+        let built: Vec<HttpRequest> = requests
+            .into_iter()
+            .map(|r| build_request(&r.method, &r.path, r.headers, r.query_params, r.body))
+            .collect();
+
+        let traced = py.allow_threads(|| {
+            built
+                .iter()
+                .map(|req| {
+                    let started = Instant::now();
+                    let trace = self.inner.evaluate_with_trace(req);
+                    let elapsed_ns = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+                    (trace, elapsed_ns)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        traced
+            .into_iter()
+            .map(|(trace, elapsed_ns)| {
+                let steps: Vec<super::matcher::PyTraceStep> = trace
+                    .steps
+                    .iter()
+                    .map(|step| super::matcher::PyTraceStep {
+                        index: step.index,
+                        matched: step.matched,
+                        predicate: format!("{:?}", step.predicate_trace),
+                    })
+                    .collect();
+                let steps_evaluated = steps.len();
+
+                super::matcher::PyTraceResult {
+                    result: trace.result,
+                    steps,
+                    used_fallback: trace.used_fallback,
+                    elapsed_ns,
+                    steps_evaluated,
+                    steps_skipped: self.config.matchers.len().saturating_sub(steps_evaluated),
+                }
+            })
+            .collect()
+    }
+
     #[allow(clippy::unused_self)]
     fn __repr__(&self) -> String {
         "HttpMatcher(<compiled>)".to_string()
@@ -133,7 +366,8 @@ fn build_request(
     method: &str,
     path: &str,
     headers: Option<HashMap<String, String>>,
-    query_params: Option<HashMap<String, String>>,
+    query_params: Option<PyQueryParams>,
+    body: Option<Vec<u8>>,
 ) -> HttpRequest {
     let mut builder = HttpRequest::builder().method(method).path(path);
 
@@ -144,10 +378,14 @@ fn build_request(
     }
 
     if let Some(params) = query_params {
-        for (k, v) in params {
+        for (k, v) in params.into_decoded_pairs() {
             builder = builder.query_param(k, v);
         }
     }
 
+    if let Some(body) = body {
+        builder = builder.body(body);
+    }
+
     builder.build()
 }
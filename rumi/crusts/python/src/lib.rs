@@ -5,7 +5,9 @@
 
 mod config;
 mod convert;
+mod http_matcher;
 mod matcher;
+mod test_matcher;
 
 use pyo3::prelude::*;
 
@@ -15,13 +17,23 @@ fn puma_crusty(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Config types
     m.add_class::<config::PyStringMatch>()?;
     m.add_class::<config::PyHookMatch>()?;
+    m.add_class::<config::PyMatchGroup>()?;
+    m.add_class::<config::PyRewriteOp>()?;
+    m.add_class::<config::PyRewriteTemplate>()?;
 
     // Compiled matchers
     m.add_class::<matcher::HookMatcher>()?;
+    m.add_class::<http_matcher::HttpMatcher>()?;
+    m.add_class::<test_matcher::TestMatcher>()?;
 
     // Trace types
     m.add_class::<matcher::PyTraceResult>()?;
     m.add_class::<matcher::PyTraceStep>()?;
 
+    // Fixture report types
+    m.add_class::<test_matcher::FixtureReport>()?;
+    m.add_class::<test_matcher::FixtureRecord>()?;
+    m.add_class::<test_matcher::FixtureCaseRecord>()?;
+
     Ok(())
 }
@@ -2,11 +2,18 @@
 //!
 //! Takes a JSON config string, compiles it via Rust registry, and evaluates
 //! against simple key-value contexts. Used for conformance testing.
+//!
+//! [`TestMatcher::match_batch`] evaluates many contexts in one GIL-released,
+//! rayon-parallel call. Only `TestMatcher` has this batch entry point:
+//! `HookMatcher` (in `matcher.rs`) gets no `match_batch` from this module,
+//! and nothing here calls into `HookMatcher` at all.
 
 use std::collections::HashMap;
+use std::time::Instant;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use rayon::prelude::*;
 use rumi::prelude::*;
 use rumi_test::TestContext;
 
@@ -21,30 +28,53 @@ use rumi_test::TestContext;
 #[pyclass(frozen)]
 pub struct TestMatcher {
     inner: Matcher<TestContext, String>,
+    /// Every context key a compiled predicate reads, for `required_keys()`
+    /// and strict evaluation. Collected from the config tree before compiling,
+    /// since the compiled `Matcher` type-erases its `DataInput`s.
+    required_keys: Vec<String>,
+    /// Number of top-level field matchers in the loaded config, for
+    /// `trace()`'s `steps_skipped` count — the compiled `Matcher` doesn't
+    /// expose this either.
+    total_matchers: usize,
 }
 
 #[pymethods]
 impl TestMatcher {
-    /// Load a matcher from a JSON config string.
+    /// Load a matcher from a config string.
+    ///
+    /// The config format is `MatcherConfig<String>` — the same shape used
+    /// by all x.uma implementations (rumi, puma, bumi). Accepts JSON, YAML,
+    /// TOML, or RON documents.
+    ///
+    /// # Arguments
     ///
-    /// The config format is `MatcherConfig<String>` — the same JSON shape used
-    /// by all x.uma implementations (rumi, puma, bumi).
+    /// * `config` — the config document.
+    /// * `format` — one of `"json"`, `"yaml"`, `"toml"`, `"ron"`. When omitted,
+    ///   the format is detected by sniffing the first non-whitespace byte.
     ///
     /// # Supported input type URLs
     ///
     /// - `xuma.test.v1.StringInput` — string lookup by key (config: `{"key": "..."}`)
     ///
+    /// An action string may reference `${name}` placeholders bound by a
+    /// predicate's `capture` name (see `rumi::capture`).
+    ///
     /// # Errors
     ///
     /// Raises `ValueError` if:
-    /// - JSON config is malformed
+    /// - the config is malformed, or `format` names an unsupported format
     /// - Unknown type URL (error lists available URLs)
     /// - Invalid regex pattern
     /// - Depth/width limits exceeded
+    /// - An action template references a `${name}` no predicate in its own
+    ///   `FieldMatcher` declares
     #[staticmethod]
-    fn from_config(json_config: &str) -> PyResult<Self> {
-        let config: rumi::MatcherConfig<String> = serde_json::from_str(json_config)
-            .map_err(|e| PyValueError::new_err(format!("invalid config JSON: {e}")))?;
+    #[pyo3(signature = (config, *, format = None))]
+    fn from_config(config: &str, format: Option<&str>) -> PyResult<Self> {
+        let config: rumi::MatcherConfig<String> =
+            parse_matcher_config(config, format).map_err(PyValueError::new_err)?;
+        let required_keys = collect_required_keys(&config);
+        let total_matchers = config.matchers.len();
 
         let registry = build_test_registry();
         let matcher = registry
@@ -55,7 +85,83 @@ impl TestMatcher {
             .validate()
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-        Ok(Self { inner: matcher })
+        Ok(Self {
+            inner: matcher,
+            required_keys,
+            total_matchers,
+        })
+    }
+
+    /// Load a matcher from multiple layered JSON config documents.
+    ///
+    /// Each layer is deep-merged into the next, in order: object keys merge
+    /// recursively with later layers overriding earlier ones, and arrays
+    /// (and any other value) are replaced wholesale by the last layer that
+    /// sets them — there is no positional concatenation, since merging
+    /// `matchers` entries by index would silently reorder first-match-wins
+    /// rules. The merged document is then run through the same
+    /// `registry.load_matcher` + `validate` pipeline as `from_config`.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if any layer is malformed JSON, or if the merged
+    /// document fails to deserialize, fails to load, or fails validation.
+    /// Deserialization errors name the path of the offending node and, when
+    /// known, which layer introduced it.
+    #[staticmethod]
+    fn from_configs(configs: Vec<String>) -> PyResult<Self> {
+        if configs.is_empty() {
+            return Err(PyValueError::new_err(
+                "from_configs requires at least one layer",
+            ));
+        }
+
+        let mut merged = serde_json::Value::Null;
+        let mut provenance: HashMap<String, usize> = HashMap::new();
+
+        for (layer, doc) in configs.iter().enumerate() {
+            let value: serde_json::Value = serde_json::from_str(doc)
+                .map_err(|e| PyValueError::new_err(format!("layer {layer}: invalid JSON: {e}")))?;
+            deep_merge(&mut merged, value, "", layer, &mut provenance);
+        }
+
+        let config: rumi::MatcherConfig<String> =
+            serde_path_to_error::deserialize(merged).map_err(|e| {
+                let path = e.path().to_string();
+                match provenance.get(&path) {
+                    Some(layer) => PyValueError::new_err(format!(
+                        "invalid merged config at `{path}` (introduced by layer {layer}): {e}"
+                    )),
+                    None => PyValueError::new_err(format!("invalid merged config: {e}")),
+                }
+            })?;
+
+        let required_keys = collect_required_keys(&config);
+        let total_matchers = config.matchers.len();
+
+        let registry = build_test_registry();
+        let matcher = registry
+            .load_matcher(config)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        matcher
+            .validate()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self {
+            inner: matcher,
+            required_keys,
+            total_matchers,
+        })
+    }
+
+    /// Every context key any predicate in this matcher reads.
+    ///
+    /// Only covers key-based inputs (`xuma.test.v1.StringInput` and
+    /// `xuma.test.v1.TransformInput`); a custom `DataInput` that doesn't
+    /// read a single named key is not represented here.
+    fn required_keys(&self) -> Vec<String> {
+        self.required_keys.clone()
     }
 
     /// Evaluate a key-value context against compiled matcher rules.
@@ -63,84 +169,149 @@ impl TestMatcher {
     /// # Arguments
     ///
     /// * `context` — A dictionary of string key-value pairs.
+    /// * `strict` — when `true`, raise `ValueError` instead of evaluating if
+    ///   `context` is missing a key from [`Self::required_keys`] or supplies
+    ///   a key the matcher never consults.
     ///
     /// # Returns
     ///
-    /// The action string if the context matched, or `None`.
-    fn evaluate(&self, context: HashMap<String, String>) -> Option<String> {
+    /// The action string if the context matched, or `None`. Any `${name}`
+    /// placeholders a predicate's `capture` name would bind are *not*
+    /// substituted — threading a live `Captures` environment through
+    /// `Matcher::evaluate` and interpolating the resolved action is the
+    /// runtime engine's job (see `rumi::capture`), and that engine isn't
+    /// wired up here, so the action comes back with any `${name}` references
+    /// still literal.
+    #[pyo3(signature = (context, strict = false))]
+    fn evaluate(&self, context: HashMap<String, String>, strict: bool) -> PyResult<Option<String>> {
+        if strict {
+            self.check_strict(&context)?;
+        }
         let ctx = build_context(context);
-        self.inner.evaluate(&ctx)
+        Ok(self.inner.evaluate(&ctx))
     }
 
     /// Trace evaluation for debugging.
     ///
-    /// Returns the same result as `evaluate()` plus a detailed trace.
-    fn trace(&self, context: HashMap<String, String>) -> super::matcher::PyTraceResult {
-        let ctx = build_context(context);
-        let trace = self.inner.evaluate_with_trace(&ctx);
-
-        let steps: Vec<super::matcher::PyTraceStep> = trace
-            .steps
-            .iter()
-            .map(|step| super::matcher::PyTraceStep {
-                index: step.index,
-                matched: step.matched,
-                predicate: format!("{:?}", step.predicate_trace),
-            })
-            .collect();
+    /// Returns the same result as `evaluate()` plus a detailed trace, one
+    /// step per top-level matcher evaluated before the first match. See `evaluate()` for the
+    /// meaning of `strict`.
+    ///
+    /// `elapsed_ns`/`steps_evaluated`/`steps_skipped` profile which configs
+    /// dominate evaluation cost: `elapsed_ns` is wall-clock for this whole
+    /// call, `steps_evaluated` is `len(steps)`, and `steps_skipped` is how
+    /// many of the configured top-level matchers the first-match-wins
+    /// short-circuit never reached. Per-step timing and sub-match counts
+    /// aren't available here — recording those needs a monotonic clock
+    /// inside `Matcher::evaluate_with_trace` itself, which isn't part of
+    /// this crate's sources.
+    #[pyo3(signature = (context, strict = false))]
+    fn trace(
+        &self,
+        context: HashMap<String, String>,
+        strict: bool,
+    ) -> PyResult<super::matcher::PyTraceResult> {
+        if strict {
+            self.check_strict(&context)?;
+        }
+        Ok(self.trace_one(&context))
+    }
 
-        super::matcher::PyTraceResult {
-            result: trace.result,
-            steps,
-            used_fallback: trace.used_fallback,
+    /// Trace many contexts in one call, releasing the GIL and fanning the
+    /// work across a rayon thread pool.
+    ///
+    /// `TestMatcher` is immutable after compilation (see "Thread Safety"
+    /// above), so evaluation is read-only and trivially parallelizable;
+    /// batching amortizes the Python↔Rust boundary crossing and lets callers
+    /// saturate cores when classifying large streams of events. `strict` is
+    /// checked up front, against every context, before any work is handed to
+    /// the pool.
+    ///
+    /// `max_threads` caps how many rayon workers this call uses — `None`
+    /// uses rayon's default (one per logical core) — so a caller embedding
+    /// this inside an async service can bound how much of the pool one call
+    /// claims.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if `max_threads` is `Some(0)`, or (with
+    /// `strict=True`) if any context is missing a required key or supplies
+    /// one the matcher never consults.
+    #[pyo3(signature = (contexts, strict = false, max_threads = None))]
+    fn match_batch(
+        &self,
+        py: Python<'_>,
+        contexts: Vec<HashMap<String, String>>,
+        strict: bool,
+        max_threads: Option<usize>,
+    ) -> PyResult<Vec<super::matcher::PyTraceResult>> {
+        if strict {
+            for context in &contexts {
+                self.check_strict(context)?;
+            }
         }
+        let pool = build_thread_pool(max_threads)?;
+        Ok(py.allow_threads(|| {
+            pool.install(|| {
+                contexts
+                    .par_iter()
+                    .map(|context| self.trace_one(context))
+                    .collect()
+            })
+        }))
     }
 
     /// Load and run conformance fixtures from a YAML file.
     ///
-    /// Returns a list of `(fixture_name, case_name, passed, detail)` tuples.
-    /// Used for running the `spec/tests/06_config/` conformance suite.
+    /// Returns a [`FixtureReport`] with per-fixture, per-case records and
+    /// aggregate counts, rather than the raw `(fixture, case, passed, detail)`
+    /// tuples this used to return. Used for running the `spec/tests/06_config/`
+    /// conformance suite.
     #[staticmethod]
-    fn run_fixtures(yaml_content: &str) -> PyResult<Vec<(String, String, bool, String)>> {
+    fn run_fixtures(yaml_content: &str) -> PyResult<FixtureReport> {
         let fixtures = rumi_test::config_fixture::ConfigFixture::from_yaml_multi(yaml_content)
             .map_err(|e| PyValueError::new_err(format!("invalid YAML: {e}")))?;
 
         let registry = build_test_registry();
-        let mut results = Vec::new();
+        let mut report = FixtureReport::default();
 
         for fixture in &fixtures {
+            let mut record = FixtureRecord {
+                fixture: fixture.name.clone(),
+                ..Default::default()
+            };
+
             if fixture.expect_error {
                 // Error fixtures: config should fail to load
                 let config_result: Result<rumi::MatcherConfig<String>, _> =
                     serde_json::from_value(fixture.config.clone());
-                match config_result {
-                    Err(_) => {
-                        results.push((
-                            fixture.name.clone(),
-                            "parse_error".into(),
-                            true,
-                            "correctly rejected at parse".into(),
-                        ));
-                    }
+                let case = match config_result {
+                    Err(_) => FixtureCaseRecord {
+                        case: "parse_error".into(),
+                        passed: true,
+                        phase: "parse".into(),
+                        got: Some("rejected".into()),
+                        ..Default::default()
+                    },
                     Ok(config) => match registry.load_matcher(config) {
-                        Err(_) => {
-                            results.push((
-                                fixture.name.clone(),
-                                "load_error".into(),
-                                true,
-                                "correctly rejected at load".into(),
-                            ));
-                        }
-                        Ok(_) => {
-                            results.push((
-                                fixture.name.clone(),
-                                "should_fail".into(),
-                                false,
-                                "expected error but config loaded successfully".into(),
-                            ));
-                        }
+                        Err(_) => FixtureCaseRecord {
+                            case: "load_error".into(),
+                            passed: true,
+                            phase: "load".into(),
+                            got: Some("rejected".into()),
+                            ..Default::default()
+                        },
+                        Ok(_) => FixtureCaseRecord {
+                            case: "should_fail".into(),
+                            passed: false,
+                            phase: "load".into(),
+                            got: Some("loaded successfully".into()),
+                            ..Default::default()
+                        },
                     },
-                }
+                };
+                record.push_case(case);
+                report.push_fixture(record);
                 continue;
             }
 
@@ -149,12 +320,14 @@ impl TestMatcher {
                 match serde_json::from_value(fixture.config.clone()) {
                     Ok(c) => c,
                     Err(e) => {
-                        results.push((
-                            fixture.name.clone(),
-                            "parse".into(),
-                            false,
-                            format!("config parse failed: {e}"),
-                        ));
+                        record.push_case(FixtureCaseRecord {
+                            case: "parse".into(),
+                            passed: false,
+                            phase: "parse".into(),
+                            got: Some(e.to_string()),
+                            ..Default::default()
+                        });
+                        report.push_fixture(record);
                         continue;
                     }
                 };
@@ -162,12 +335,14 @@ impl TestMatcher {
             let matcher = match registry.load_matcher(config) {
                 Ok(m) => m,
                 Err(e) => {
-                    results.push((
-                        fixture.name.clone(),
-                        "load".into(),
-                        false,
-                        format!("config load failed: {e}"),
-                    ));
+                    record.push_case(FixtureCaseRecord {
+                        case: "load".into(),
+                        passed: false,
+                        phase: "load".into(),
+                        got: Some(e.to_string()),
+                        ..Default::default()
+                    });
+                    report.push_fixture(record);
                     continue;
                 }
             };
@@ -175,17 +350,19 @@ impl TestMatcher {
             for case in &fixture.cases {
                 let ctx = case.build_context();
                 let result = matcher.evaluate(&ctx);
-                let passed = result == case.expect;
-                let detail = if passed {
-                    format!("got {result:?}")
-                } else {
-                    format!("expected {:?}, got {:?}", case.expect, result)
-                };
-                results.push((fixture.name.clone(), case.name.clone(), passed, detail));
+                let passed = expectation_matches(&case.expect, &result);
+                record.push_case(FixtureCaseRecord {
+                    case: case.name.clone(),
+                    passed,
+                    phase: "eval".into(),
+                    expected: case.expect.clone(),
+                    got: result,
+                });
             }
+            report.push_fixture(record);
         }
 
-        Ok(results)
+        Ok(report)
     }
 
     #[allow(clippy::unused_self)]
@@ -194,9 +371,532 @@ impl TestMatcher {
     }
 }
 
+impl TestMatcher {
+    /// Reject `context` if it is missing a required key or supplies one the
+    /// matcher never consults.
+    fn check_strict(&self, context: &HashMap<String, String>) -> PyResult<()> {
+        let supplied: std::collections::BTreeSet<&str> =
+            context.keys().map(String::as_str).collect();
+        let required: std::collections::BTreeSet<&str> =
+            self.required_keys.iter().map(String::as_str).collect();
+
+        let missing: Vec<&str> = required.difference(&supplied).copied().collect();
+        let unknown: Vec<&str> = supplied.difference(&required).copied().collect();
+
+        if missing.is_empty() && unknown.is_empty() {
+            return Ok(());
+        }
+
+        Err(PyValueError::new_err(format!(
+            "strict evaluation failed: missing keys {missing:?}, unknown keys {unknown:?}"
+        )))
+    }
+
+    /// Evaluate `context` and build its full trace, including the aggregate
+    /// timing/step counts `trace()` and `match_batch()` both report. Shared
+    /// so the two pymethods can't drift apart on what a trace contains.
+    fn trace_one(&self, context: &HashMap<String, String>) -> super::matcher::PyTraceResult {
+        let ctx = build_context(context.clone());
+        let started = Instant::now();
+        let trace = self.inner.evaluate_with_trace(&ctx);
+        let elapsed_ns = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+
+        let steps: Vec<super::matcher::PyTraceStep> = trace
+            .steps
+            .iter()
+            .map(|step| super::matcher::PyTraceStep {
+                index: step.index,
+                matched: step.matched,
+                predicate: format!("{:?}", step.predicate_trace),
+            })
+            .collect();
+        let steps_evaluated = steps.len();
+
+        super::matcher::PyTraceResult {
+            result: trace.result,
+            steps,
+            used_fallback: trace.used_fallback,
+            elapsed_ns,
+            steps_evaluated,
+            steps_skipped: self.total_matchers.saturating_sub(steps_evaluated),
+        }
+    }
+}
+
+/// Build a rayon pool for [`TestMatcher::match_batch`], capped at
+/// `max_threads` workers (rayon's default — one per logical core — when
+/// `None`).
+fn build_thread_pool(max_threads: Option<usize>) -> PyResult<rayon::ThreadPool> {
+    let mut builder = rayon::ThreadPoolBuilder::new();
+    if let Some(n) = max_threads {
+        if n == 0 {
+            return Err(PyValueError::new_err("max_threads must be at least 1"));
+        }
+        builder = builder.num_threads(n);
+    }
+    builder
+        .build()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Walk a `MatcherConfig<String>` and collect every context key a
+/// [`TransformInput`] or `xuma.test.v1.StringInput` predicate reads.
+fn collect_required_keys(config: &rumi::MatcherConfig<String>) -> Vec<String> {
+    let mut keys = std::collections::BTreeSet::new();
+    collect_from_matcher(config, &mut keys);
+    keys.into_iter().collect()
+}
+
+fn collect_from_matcher(
+    config: &rumi::MatcherConfig<String>,
+    keys: &mut std::collections::BTreeSet<String>,
+) {
+    for field_matcher in &config.matchers {
+        collect_from_predicate(&field_matcher.predicate, keys);
+        collect_from_on_match(&field_matcher.on_match, keys);
+    }
+    if let Some(on_no_match) = &config.on_no_match {
+        collect_from_on_match(on_no_match, keys);
+    }
+}
+
+fn collect_from_predicate(
+    predicate: &rumi::PredicateConfig,
+    keys: &mut std::collections::BTreeSet<String>,
+) {
+    match predicate {
+        rumi::PredicateConfig::Single(single) => {
+            if matches!(
+                single.input.type_url.as_str(),
+                "xuma.test.v1.StringInput" | "xuma.test.v1.TransformInput"
+            ) {
+                if let Some(key) = single.input.config.get("key").and_then(|v| v.as_str()) {
+                    keys.insert(key.to_string());
+                }
+            }
+        }
+        rumi::PredicateConfig::And { predicates } | rumi::PredicateConfig::Or { predicates } => {
+            for predicate in predicates {
+                collect_from_predicate(predicate, keys);
+            }
+        }
+        rumi::PredicateConfig::Not { predicate } => collect_from_predicate(predicate, keys),
+    }
+}
+
+fn collect_from_on_match(
+    on_match: &rumi::OnMatchConfig<String>,
+    keys: &mut std::collections::BTreeSet<String>,
+) {
+    if let rumi::OnMatchConfig::Matcher { matcher, .. } = on_match {
+        collect_from_matcher(matcher, keys);
+    }
+}
+
 /// Build the test registry for `TestContext`.
 fn build_test_registry() -> rumi::Registry<TestContext> {
-    rumi_test::register(rumi::RegistryBuilder::new()).build()
+    rumi_test::register(rumi::RegistryBuilder::new())
+        .input::<TransformInput>("xuma.test.v1.TransformInput")
+        .build()
+}
+
+/// Parse a `MatcherConfig<String>` document in JSON, YAML, TOML, or RON.
+///
+/// When `format` is `None`, the format is detected by sniffing the first
+/// non-whitespace byte of `input`: `{` implies JSON, `(` implies RON, `[`
+/// implies a TOML array-of-tables header, and anything else falls back to
+/// YAML (which is the superset format the conformance fixtures already use).
+fn parse_matcher_config(
+    input: &str,
+    format: Option<&str>,
+) -> Result<rumi::MatcherConfig<String>, String> {
+    let format = format.map_or_else(|| sniff_config_format(input), str::to_string);
+
+    match format.as_str() {
+        "json" => serde_json::from_str(input).map_err(|e| format!("invalid JSON config: {e}")),
+        "yaml" => serde_yaml::from_str(input).map_err(|e| format!("invalid YAML config: {e}")),
+        "toml" => toml::from_str(input).map_err(|e| format!("invalid TOML config: {e}")),
+        "ron" => ron::from_str(input).map_err(|e| format!("invalid RON config: {e}")),
+        other => Err(format!(
+            "unknown config format {other:?}: expected one of json, yaml, toml, ron"
+        )),
+    }
+}
+
+/// Sniff the config format from the first non-whitespace byte.
+fn sniff_config_format(input: &str) -> String {
+    match input.trim_start().as_bytes().first() {
+        Some(b'{') => "json",
+        Some(b'(') => "ron",
+        Some(b'[') => "toml",
+        _ => "yaml",
+    }
+    .to_string()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Context-value transforms (feature: `xuma.test.v1.TransformInput`)
+// Applies a pipeline of string operations to a context value before the
+// downstream predicate sees it, so messy inputs can be normalized in config
+// rather than in host code.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Extracts a context value by key, then runs it through a transform pipeline.
+///
+/// Registered as `xuma.test.v1.TransformInput`. `get` returns [`MatchingData::None`]
+/// if the key is absent, mirroring the other context-key lookup inputs.
+#[derive(Debug, Clone)]
+pub struct TransformInput {
+    key: String,
+    pipeline: Vec<ValueTransform>,
+}
+
+impl DataInput<TestContext> for TransformInput {
+    fn get(&self, ctx: &TestContext) -> MatchingData {
+        match ctx.get(&self.key) {
+            None => MatchingData::None,
+            Some(value) => {
+                let mut value = value.to_string();
+                for transform in &self.pipeline {
+                    value = transform.apply(&value);
+                }
+                MatchingData::String(value)
+            }
+        }
+    }
+}
+
+/// Configuration for [`TransformInput`].
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TransformInputConfig {
+    /// The context key to read before applying `transforms`.
+    pub key: String,
+    /// Ordered pipeline of operations applied to the looked-up value.
+    #[serde(default)]
+    pub transforms: Vec<ValueTransform>,
+}
+
+impl rumi::IntoDataInput<TestContext> for TransformInput {
+    type Config = TransformInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<TestContext>>, rumi::MatcherError> {
+        Ok(Box::new(TransformInput {
+            key: config.key,
+            pipeline: config.transforms,
+        }))
+    }
+}
+
+/// A single value-transform operation in a [`TransformInputConfig`] pipeline.
+///
+/// Deserialized from `{ "op": "...", ... }`. `regex_replace` precompiles and
+/// validates its pattern at deserialize time, so a malformed regex fails at
+/// `from_config` rather than at evaluation time.
+#[derive(Debug, Clone)]
+pub enum ValueTransform {
+    /// Replace all matches of `pattern` with `replacement` (supports `$1`-style captures).
+    RegexReplace {
+        regex: regex::Regex,
+        replacement: String,
+    },
+    /// Lowercase the value.
+    Lowercase,
+    /// Uppercase the value.
+    Uppercase,
+    /// Trim leading and trailing whitespace.
+    Trim,
+    /// Keep only the part of the value before the first occurrence of `delimiter`
+    /// (the value is left unchanged if `delimiter` is absent).
+    SubstringBefore(String),
+    /// Keep only the part of the value after the first occurrence of `delimiter`
+    /// (the value is left unchanged if `delimiter` is absent).
+    SubstringAfter(String),
+}
+
+impl ValueTransform {
+    fn apply(&self, value: &str) -> String {
+        match self {
+            Self::RegexReplace { regex, replacement } => {
+                regex.replace_all(value, replacement.as_str()).into_owned()
+            }
+            Self::Lowercase => value.to_lowercase(),
+            Self::Uppercase => value.to_uppercase(),
+            Self::Trim => value.trim().to_string(),
+            Self::SubstringBefore(delimiter) => value
+                .split_once(delimiter.as_str())
+                .map_or_else(|| value.to_string(), |(before, _)| before.to_string()),
+            Self::SubstringAfter(delimiter) => value
+                .split_once(delimiter.as_str())
+                .map_or_else(|| value.to_string(), |(_, after)| after.to_string()),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValueTransform {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(tag = "op", rename_all = "snake_case")]
+        enum Raw {
+            RegexReplace { pattern: String, replacement: String },
+            Lowercase,
+            Uppercase,
+            Trim,
+            SubstringBefore { delimiter: String },
+            SubstringAfter { delimiter: String },
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::RegexReplace { pattern, replacement } => {
+                let regex = regex::Regex::new(&pattern).map_err(|e| {
+                    serde::de::Error::custom(format!(
+                        "invalid regex in regex_replace transform: {e}"
+                    ))
+                })?;
+                ValueTransform::RegexReplace { regex, replacement }
+            }
+            Raw::Lowercase => ValueTransform::Lowercase,
+            Raw::Uppercase => ValueTransform::Uppercase,
+            Raw::Trim => ValueTransform::Trim,
+            Raw::SubstringBefore { delimiter } => ValueTransform::SubstringBefore(delimiter),
+            Raw::SubstringAfter { delimiter } => ValueTransform::SubstringAfter(delimiter),
+        })
+    }
+}
+
+/// Deep-merge `incoming` into `base` at `path`, recording which `layer`
+/// introduced each leaf value into `provenance` (keyed by the same
+/// dotted/bracketed path syntax `serde_path_to_error` produces).
+///
+/// Objects merge key-by-key; any other pairing (including array vs. array)
+/// replaces `base` wholesale with `incoming`.
+fn deep_merge(
+    base: &mut serde_json::Value,
+    incoming: serde_json::Value,
+    path: &str,
+    layer: usize,
+    provenance: &mut HashMap<String, usize>,
+) {
+    if let (serde_json::Value::Object(base_map), serde_json::Value::Object(incoming_map)) =
+        (&mut *base, &incoming)
+    {
+        for (key, value) in incoming_map {
+            let child_path = join_path(path, key);
+            match base_map.get_mut(key) {
+                Some(existing) => deep_merge(existing, value.clone(), &child_path, layer, provenance),
+                None => {
+                    mark_provenance(value, &child_path, layer, provenance);
+                    base_map.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        return;
+    }
+
+    mark_provenance(&incoming, path, layer, provenance);
+    *base = incoming;
+}
+
+/// Record `layer` as the provenance for `path` and every path beneath it.
+fn mark_provenance(
+    value: &serde_json::Value,
+    path: &str,
+    layer: usize,
+    provenance: &mut HashMap<String, usize>,
+) {
+    provenance.insert(path.to_string(), layer);
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                mark_provenance(child, &join_path(path, key), layer, provenance);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                mark_provenance(child, &format!("{path}[{index}]"), layer, provenance);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Join a dotted config path with the next object key.
+fn join_path(base: &str, key: &str) -> String {
+    if base.is_empty() {
+        key.to_string()
+    } else {
+        format!("{base}.{key}")
+    }
+}
+
+/// Whether a matcher's `actual` result satisfies a fixture case's `expect` value.
+///
+/// `expect` supports three forms, tried in order:
+/// - `"glob:<pattern>"` — shell-style wildcard match (`*` and `?`).
+/// - `"regex:<pattern>"` — unanchored search with a `regex` crate pattern
+///   (add `^`/`$` to require a full-string match).
+/// - anything else — exact string equality (the original behavior).
+///
+/// `None` only matches when the matcher itself produced no action.
+fn expectation_matches(expect: &Option<String>, actual: &Option<String>) -> bool {
+    match (expect, actual) {
+        (None, None) => true,
+        (None, Some(_)) | (Some(_), None) => false,
+        (Some(pattern), Some(actual)) => {
+            if let Some(glob) = pattern.strip_prefix("glob:") {
+                glob_match(glob, actual)
+            } else if let Some(re) = pattern.strip_prefix("regex:") {
+                regex::Regex::new(re).is_ok_and(|re| re.is_match(actual))
+            } else {
+                pattern == actual
+            }
+        }
+    }
+}
+
+/// Minimal shell-style glob matcher supporting `*` (any run of characters)
+/// and `?` (exactly one character).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn helper(p: &[char], t: &[char]) -> bool {
+        match (p.first(), t.first()) {
+            (None, None) => true,
+            (Some('*'), _) => helper(&p[1..], t) || (!t.is_empty() && helper(p, &t[1..])),
+            (Some('?'), Some(_)) => helper(&p[1..], &t[1..]),
+            (Some(pc), Some(tc)) if pc == tc => helper(&p[1..], &t[1..]),
+            _ => false,
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    helper(&pattern, &text)
+}
+
+/// Outcome of a single fixture case: `parse`/`load`/`eval` phase plus pass/fail.
+#[pyclass]
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct FixtureCaseRecord {
+    /// The case name (or a synthetic name like `parse_error` for error fixtures).
+    #[pyo3(get)]
+    case: String,
+    /// Whether this case behaved as the fixture expected.
+    #[pyo3(get)]
+    passed: bool,
+    /// The phase this case was decided at: `parse`, `load`, or `eval`.
+    #[pyo3(get)]
+    phase: String,
+    /// The expected action, if the case evaluated a matcher.
+    #[pyo3(get)]
+    expected: Option<String>,
+    /// The actual action (or error description) observed.
+    #[pyo3(get)]
+    got: Option<String>,
+}
+
+#[pymethods]
+impl FixtureCaseRecord {
+    fn __repr__(&self) -> String {
+        format!(
+            "FixtureCaseRecord(case={:?}, passed={}, phase={:?})",
+            self.case, self.passed, self.phase
+        )
+    }
+}
+
+/// Results for a single fixture: all of its cases plus a rolled-up `passed`.
+#[pyclass]
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct FixtureRecord {
+    /// The fixture name.
+    #[pyo3(get)]
+    fixture: String,
+    /// `true` iff every case in this fixture passed.
+    #[pyo3(get)]
+    passed: bool,
+    /// Per-case records, in evaluation order.
+    #[pyo3(get)]
+    cases: Vec<FixtureCaseRecord>,
+}
+
+impl FixtureRecord {
+    fn push_case(&mut self, case: FixtureCaseRecord) {
+        self.cases.push(case);
+        self.passed = self.cases.iter().all(|c| c.passed);
+    }
+}
+
+#[pymethods]
+impl FixtureRecord {
+    fn __repr__(&self) -> String {
+        format!(
+            "FixtureRecord(fixture={:?}, passed={}, cases={})",
+            self.fixture,
+            self.passed,
+            self.cases.len()
+        )
+    }
+}
+
+/// Structured, machine-readable report for a `run_fixtures()` run.
+///
+/// Replaces the opaque `Vec<(String, String, bool, String)>` tuples with a
+/// type that preserves expected/got separately and carries aggregate counts,
+/// so CI can diff results across runs instead of scraping formatted strings.
+#[pyclass]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FixtureReport {
+    /// Per-fixture records, in fixture-file order.
+    #[pyo3(get)]
+    fixtures: Vec<FixtureRecord>,
+    /// Total number of cases across all fixtures.
+    #[pyo3(get)]
+    total: usize,
+    /// Number of cases that passed.
+    #[pyo3(get)]
+    passed: usize,
+    /// Number of cases that failed.
+    #[pyo3(get)]
+    failed: usize,
+    /// `true` iff every case in every fixture passed.
+    #[pyo3(get)]
+    ok: bool,
+}
+
+impl Default for FixtureReport {
+    fn default() -> Self {
+        Self {
+            fixtures: Vec::new(),
+            total: 0,
+            passed: 0,
+            failed: 0,
+            ok: true,
+        }
+    }
+}
+
+impl FixtureReport {
+    fn push_fixture(&mut self, record: FixtureRecord) {
+        self.total += record.cases.len();
+        self.passed += record.cases.iter().filter(|c| c.passed).count();
+        self.failed += record.cases.iter().filter(|c| !c.passed).count();
+        self.ok = self.failed == 0;
+        self.fixtures.push(record);
+    }
+}
+
+#[pymethods]
+impl FixtureReport {
+    /// Serialize the report to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "FixtureReport(total={}, passed={}, failed={}, ok={})",
+            self.total, self.passed, self.failed, self.ok
+        )
+    }
 }
 
 /// Build a `TestContext` from a Python dict.
@@ -207,3 +907,153 @@ fn build_context(values: HashMap<String, String>) -> TestContext {
     }
     ctx
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_expectation_is_unchanged() {
+        assert!(expectation_matches(&Some("hit".into()), &Some("hit".into())));
+        assert!(!expectation_matches(&Some("hit".into()), &Some("miss".into())));
+        assert!(expectation_matches(&None, &None));
+        assert!(!expectation_matches(&None, &Some("hit".into())));
+    }
+
+    #[test]
+    fn glob_expectation() {
+        assert!(expectation_matches(
+            &Some("glob:api_*".into()),
+            &Some("api_backend".into())
+        ));
+        assert!(!expectation_matches(
+            &Some("glob:api_*".into()),
+            &Some("web_backend".into())
+        ));
+        assert!(expectation_matches(
+            &Some("glob:a?c".into()),
+            &Some("abc".into())
+        ));
+    }
+
+    #[test]
+    fn transform_pipeline_applies_in_order() {
+        let transforms: Vec<ValueTransform> = serde_json::from_value(serde_json::json!([
+            { "op": "trim" },
+            { "op": "lowercase" },
+            { "op": "regex_replace", "pattern": "^/api/", "replacement": "" }
+        ]))
+        .unwrap();
+
+        let mut value = "  /API/Users  ".to_string();
+        for transform in &transforms {
+            value = transform.apply(&value);
+        }
+        assert_eq!(value, "users");
+    }
+
+    #[test]
+    fn transform_substring_before_after() {
+        let before: ValueTransform =
+            serde_json::from_value(serde_json::json!({ "op": "substring_before", "delimiter": "@" }))
+                .unwrap();
+        let after: ValueTransform =
+            serde_json::from_value(serde_json::json!({ "op": "substring_after", "delimiter": "@" }))
+                .unwrap();
+        assert_eq!(before.apply("user@example.com"), "user");
+        assert_eq!(after.apply("user@example.com"), "example.com");
+        assert_eq!(before.apply("no-delimiter"), "no-delimiter");
+    }
+
+    #[test]
+    fn invalid_regex_replace_rejected_at_deserialize() {
+        let result: Result<ValueTransform, _> = serde_json::from_value(serde_json::json!({
+            "op": "regex_replace",
+            "pattern": "(unclosed",
+            "replacement": ""
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn collect_required_keys_walks_nested_matchers() {
+        let config: rumi::MatcherConfig<String> = serde_json::from_value(serde_json::json!({
+            "matchers": [{
+                "predicate": {
+                    "type": "and",
+                    "predicates": [
+                        {
+                            "type": "single",
+                            "input": { "type_url": "xuma.test.v1.StringInput", "config": { "key": "role" } },
+                            "value_match": { "Exact": "admin" }
+                        },
+                        {
+                            "type": "single",
+                            "input": { "type_url": "xuma.test.v1.TransformInput", "config": { "key": "path" } },
+                            "value_match": { "Prefix": "/api" }
+                        }
+                    ]
+                },
+                "on_match": {
+                    "type": "matcher",
+                    "matcher": {
+                        "matchers": [{
+                            "predicate": {
+                                "type": "single",
+                                "input": { "type_url": "xuma.test.v1.StringInput", "config": { "key": "org" } },
+                                "value_match": { "Exact": "acme" }
+                            },
+                            "on_match": { "type": "action", "action": "nested" }
+                        }]
+                    }
+                }
+            }]
+        }))
+        .unwrap();
+
+        let keys = collect_required_keys(&config);
+        assert_eq!(keys, vec!["org".to_string(), "path".to_string(), "role".to_string()]);
+    }
+
+    #[test]
+    fn deep_merge_overrides_object_keys() {
+        let mut base = serde_json::json!({ "a": 1, "b": { "c": 2 } });
+        let mut provenance = HashMap::new();
+        deep_merge(
+            &mut base,
+            serde_json::json!({ "b": { "c": 3, "d": 4 } }),
+            "",
+            1,
+            &mut provenance,
+        );
+        assert_eq!(base, serde_json::json!({ "a": 1, "b": { "c": 3, "d": 4 } }));
+        assert_eq!(provenance.get("b.c"), Some(&1));
+        assert_eq!(provenance.get("b.d"), Some(&1));
+    }
+
+    #[test]
+    fn deep_merge_replaces_arrays_wholesale() {
+        let mut base = serde_json::json!({ "matchers": [1, 2, 3] });
+        let mut provenance = HashMap::new();
+        deep_merge(
+            &mut base,
+            serde_json::json!({ "matchers": [9] }),
+            "",
+            0,
+            &mut provenance,
+        );
+        assert_eq!(base, serde_json::json!({ "matchers": [9] }));
+    }
+
+    #[test]
+    fn regex_expectation() {
+        assert!(expectation_matches(
+            &Some(r"regex:^api_\d+$".into()),
+            &Some("api_42".into())
+        ));
+        assert!(!expectation_matches(
+            &Some(r"regex:^api_\d+$".into()),
+            &Some("api_x".into())
+        ));
+    }
+}
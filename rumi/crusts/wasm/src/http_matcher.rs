@@ -16,6 +16,11 @@ use crate::matcher::{TraceResultSerde, TraceStepSerde};
 #[wasm_bindgen]
 pub struct HttpMatcher {
     inner: Matcher<HttpRequest, String>,
+    /// Kept alongside `inner` so `pathParams()` can re-walk the original
+    /// config for `xuma.http.v1.PathTemplateInput` predicates — see
+    /// `rumi_http::path_params`, since the compiled `inner` tree no longer
+    /// carries which type URL produced each predicate.
+    config: rumi::MatcherConfig<String>,
 }
 
 #[wasm_bindgen]
@@ -29,8 +34,18 @@ impl HttpMatcher {
     ///
     /// - `xuma.http.v1.PathInput` — request path (config: `{}`)
     /// - `xuma.http.v1.MethodInput` — HTTP method (config: `{}`)
-    /// - `xuma.http.v1.HeaderInput` — header value (config: `{"name": "..."}`)
-    /// - `xuma.http.v1.QueryParamInput` — query parameter (config: `{"name": "..."}`)
+    /// - `xuma.http.v1.HeaderInput` — header value (config: `{"name": "..."}`, or
+    ///   `{"name": "...", "mode": "any"|"all", "value_match": {...}}` to match every
+    ///   value of a repeated header)
+    /// - `xuma.http.v1.QueryParamInput` — query parameter (same config shape)
+    /// - `xuma.http.v1.PathTemplateInput` — path template (config: `{"template": "/users/{id}"}`)
+    /// - `xuma.http.v1.BodyInput` — request body (config: `{}` for the raw body, or
+    ///   `{"pointer": "/event/type", "max_bytes": 65536}` for a JSON pointer into it)
+    ///
+    /// An action string may reference `${name}` placeholders bound by a
+    /// predicate's `capture` name (see `rumi::capture`); `validate()` rejects
+    /// the config if a template references a name no predicate in its own
+    /// `FieldMatcher` declares.
     #[wasm_bindgen(js_name = "fromConfig")]
     pub fn from_config(json_config: &str) -> Result<HttpMatcher, JsValue> {
         let config: rumi::MatcherConfig<String> = serde_json::from_str(json_config)
@@ -38,14 +53,17 @@ impl HttpMatcher {
 
         let registry = build_http_registry();
         let matcher = registry
-            .load_matcher(config)
+            .load_matcher(config.clone())
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
         matcher
             .validate()
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
-        Ok(Self { inner: matcher })
+        Ok(Self {
+            inner: matcher,
+            config,
+        })
     }
 
     /// Evaluate an HTTP request against compiled matcher rules.
@@ -55,21 +73,46 @@ impl HttpMatcher {
     /// matcher.evaluate({
     ///   method: "GET",
     ///   path: "/api/users",
-    ///   headers: { "content-type": "application/json" },
-    ///   queryParams: { "page": "1" },
+    ///   headers: { "content-type": "application/json", "accept": ["text/html", "application/json"] },
+    ///   queryParams: { "page": "1", "tag": ["a", "b"] },
+    ///   body: "{\"event\":{\"type\":\"created\"}}",
     /// })
     /// ```
+    /// A header/query-param value may be a single string or a `string[]` —
+    /// use an array to model a repeated header or query param. `body` is
+    /// optional and read as a UTF-8 string.
     ///
-    /// Returns the action string if the request matched, or `undefined`.
+    /// Returns the action string if the request matched, or `undefined`. Any
+    /// `${name}` placeholders a predicate's `capture` name would bind are
+    /// *not* substituted — threading a live `Captures` environment through
+    /// `Matcher::evaluate` and interpolating the resolved action is the
+    /// runtime engine's job (see `rumi::capture`), and that engine isn't
+    /// wired up here, so the action comes back with any `${name}` references
+    /// still literal. Does not report `PathTemplateInput` params — call
+    /// `pathParams()` on the same context to get those alongside the action.
     pub fn evaluate(&self, context: JsValue) -> Result<Option<String>, JsValue> {
         let req = build_request_from_js(&context)?;
         Ok(self.inner.evaluate(&req))
     }
 
-    /// Trace evaluation for debugging.
+    /// Trace evaluation for debugging, one step per top-level matcher
+    /// evaluated before the first match. Does not report `PathTemplateInput` params — call
+    /// `pathParams()` on the same context to get those alongside the trace.
+    ///
+    /// `elapsedNs`/`stepsEvaluated`/`stepsSkipped` profile which configs
+    /// dominate evaluation cost: `elapsedNs` is wall-clock for this whole
+    /// call (via `Date.now()`, millisecond-resolution), `stepsEvaluated` is
+    /// `steps.length`, and `stepsSkipped` is how many of the configured
+    /// top-level matchers the first-match-wins short-circuit never reached.
+    /// Per-step timing and sub-match counts aren't available here —
+    /// recording those needs a monotonic clock inside
+    /// `Matcher::evaluate_with_trace` itself, which isn't part of this
+    /// crate's sources.
     pub fn trace(&self, context: JsValue) -> Result<JsValue, JsValue> {
         let req = build_request_from_js(&context)?;
+        let started_ms = js_sys::Date::now();
         let trace = self.inner.evaluate_with_trace(&req);
+        let elapsed_ns = ((js_sys::Date::now() - started_ms) * 1_000_000.0).max(0.0) as u64;
 
         let steps: Vec<TraceStepSerde> = trace
             .steps
@@ -80,15 +123,98 @@ impl HttpMatcher {
                 predicate: format!("{:?}", step.predicate_trace),
             })
             .collect();
+        let steps_evaluated = steps.len();
 
         let result = TraceResultSerde {
             result: trace.result,
             steps,
             used_fallback: trace.used_fallback,
+            elapsed_ns,
+            steps_evaluated,
+            steps_skipped: self.config.matchers.len().saturating_sub(steps_evaluated),
         };
 
         serde_wasm_bindgen::to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
+
+    /// The path-template params captured for this request, e.g. `{ id: "42" }`
+    /// for a `xuma.http.v1.PathTemplateInput` predicate compiled from
+    /// `/users/{id}`. Merges every matching template across the config; see
+    /// `rumi_http::path_params`.
+    #[wasm_bindgen(js_name = "pathParams")]
+    pub fn path_params(&self, context: JsValue) -> Result<JsValue, JsValue> {
+        let req = build_request_from_js(&context)?;
+        let params = rumi_http::path_params(&self.config, req.path());
+        serde_wasm_bindgen::to_value(&params).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Evaluate a batch of requests against this matcher in one boundary
+    /// crossing.
+    ///
+    /// Accepts a JS array of the same plain objects `evaluate()` takes, and
+    /// returns a same-length array of the action string (or `undefined`)
+    /// `evaluate()` would have returned for each one — built once and handed
+    /// back as a single typed array, rather than one `evaluate()` call per
+    /// request from TypeScript.
+    #[wasm_bindgen(js_name = "evaluateBatch")]
+    pub fn evaluate_batch(&self, contexts: JsValue) -> Result<JsValue, JsValue> {
+        let results = js_sys::Array::from(&contexts)
+            .iter()
+            .map(|context| Ok(self.inner.evaluate(&build_request_from_js(&context)?)))
+            .collect::<Result<Vec<Option<String>>, JsValue>>()?;
+
+        serde_wasm_bindgen::to_value(&results).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Compile a JS array of JSON config strings into an [`HttpMatcherSet`]
+    /// — an ordered, first-match-wins route table.
+    ///
+    /// Each config is loaded the same way as `fromConfig()`.
+    #[wasm_bindgen(js_name = "compileMany")]
+    pub fn compile_many(configs: Vec<String>) -> Result<HttpMatcherSet, JsValue> {
+        let matchers = configs
+            .iter()
+            .map(|json_config| HttpMatcher::from_config(json_config))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(HttpMatcherSet { matchers })
+    }
+}
+
+/// An ordered set of compiled [`HttpMatcher`]s, evaluated first-match-wins —
+/// like a route table with several `HttpRouteMatch` entries.
+///
+/// Created via `HttpMatcher.compileMany()`.
+#[wasm_bindgen]
+pub struct HttpMatcherSet {
+    matchers: Vec<HttpMatcher>,
+}
+
+#[wasm_bindgen]
+impl HttpMatcherSet {
+    /// Evaluate a request against every matcher in order, returning the
+    /// first one's action that fires, or `undefined` if none match.
+    pub fn evaluate(&self, context: JsValue) -> Result<Option<String>, JsValue> {
+        for matcher in &self.matchers {
+            let result = matcher.evaluate(context.clone())?;
+            if result.is_some() {
+                return Ok(result);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluate a batch of requests against this set in one boundary
+    /// crossing; each result is the first matcher's action that fires for
+    /// that request, per `evaluate()`.
+    #[wasm_bindgen(js_name = "evaluateBatch")]
+    pub fn evaluate_batch(&self, contexts: JsValue) -> Result<JsValue, JsValue> {
+        let results = js_sys::Array::from(&contexts)
+            .iter()
+            .map(|context| self.evaluate(context))
+            .collect::<Result<Vec<Option<String>>, JsValue>>()?;
+
+        serde_wasm_bindgen::to_value(&results).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
 }
 
 /// Build the HTTP registry for `HttpRequest`.
@@ -111,7 +237,7 @@ fn build_request_from_js(val: &JsValue) -> Result<HttpRequest, JsValue> {
 
     let mut builder = HttpRequest::builder().method(method).path(path);
 
-    // Headers (optional, Record<string, string>)
+    // Headers (optional, Record<string, string | string[]>)
     let headers_val = get("headers");
     if !headers_val.is_undefined() && !headers_val.is_null() {
         let entries = js_sys::Object::entries(&js_sys::Object::from(headers_val));
@@ -121,15 +247,13 @@ fn build_request_from_js(val: &JsValue) -> Result<HttpRequest, JsValue> {
                 .get(0)
                 .as_string()
                 .ok_or_else(|| JsValue::from_str("header key must be a string"))?;
-            let value = pair
-                .get(1)
-                .as_string()
-                .ok_or_else(|| JsValue::from_str("header value must be a string"))?;
-            builder = builder.header(key, value);
+            for value in js_value_to_strings(&pair.get(1), "header")? {
+                builder = builder.header(key.clone(), value);
+            }
         }
     }
 
-    // Query params (optional, Record<string, string>)
+    // Query params (optional, Record<string, string | string[]>)
     let params_val = get("queryParams");
     if !params_val.is_undefined() && !params_val.is_null() {
         let entries = js_sys::Object::entries(&js_sys::Object::from(params_val));
@@ -139,13 +263,38 @@ fn build_request_from_js(val: &JsValue) -> Result<HttpRequest, JsValue> {
                 .get(0)
                 .as_string()
                 .ok_or_else(|| JsValue::from_str("query param key must be a string"))?;
-            let value = pair
-                .get(1)
-                .as_string()
-                .ok_or_else(|| JsValue::from_str("query param value must be a string"))?;
-            builder = builder.query_param(key, value);
+            for value in js_value_to_strings(&pair.get(1), "query param")? {
+                builder = builder.query_param(key.clone(), value);
+            }
         }
     }
 
+    // Body (optional, string)
+    let body_val = get("body");
+    if let Some(body) = body_val.as_string() {
+        builder = builder.body(body.into_bytes());
+    }
+
     Ok(builder.build())
 }
+
+/// Read a header/query-param value that's either a single string or a
+/// `js_sys::Array` of strings — the latter models a repeated header
+/// (multiple `Accept` values) or query param (`?tag=a&tag=b`).
+fn js_value_to_strings(value: &JsValue, kind: &str) -> Result<Vec<String>, JsValue> {
+    if js_sys::Array::is_array(value) {
+        js_sys::Array::from(value)
+            .iter()
+            .map(|element| {
+                element
+                    .as_string()
+                    .ok_or_else(|| JsValue::from_str(&format!("{kind} array values must be strings")))
+            })
+            .collect()
+    } else {
+        value
+            .as_string()
+            .map(|s| vec![s])
+            .ok_or_else(|| JsValue::from_str(&format!("{kind} value must be a string or string[]")))
+    }
+}
@@ -16,6 +16,14 @@ use crate::matcher::{TraceResultSerde, TraceStepSerde};
 #[wasm_bindgen]
 pub struct TestMatcher {
     inner: Matcher<TestContext, String>,
+    /// Kept alongside `inner` so `transform()` can look up the `rewrite`
+    /// template of whichever top-level `FieldMatcher` won, by index — see
+    /// its doc comment for why this can't just ask `inner`.
+    config: rumi::MatcherConfig<String>,
+    /// Candidate pre-filtering index over `config`'s top-level rules (see
+    /// `rumi::index`), kept so `checkIndexConsistency()` can cross-check it
+    /// against `inner`'s real linear-scan result for the same context.
+    index: rumi::index::CandidateIndex,
 }
 
 #[wasm_bindgen]
@@ -28,6 +36,16 @@ impl TestMatcher {
     /// # Supported input type URLs
     ///
     /// - `xuma.test.v1.StringInput` — string lookup by key (config: `{"key": "..."}`)
+    ///
+    /// An action string may reference `${name}` placeholders bound by a
+    /// predicate's `capture` name (see `rumi::capture`); `validate()` rejects
+    /// the config if a template references a name no predicate in its own
+    /// `FieldMatcher` declares.
+    ///
+    /// A predicate may also use the tagless combinator shorthand —
+    /// `{"anyOf": [...]}`, `{"allOf": [...]}`, `{"not": {...}}` — in place of
+    /// `{"type": "or"/"and"/"not", ...}`; both parse to the same
+    /// `PredicateConfig` (see `rumi::PredicateConfig`).
     #[wasm_bindgen(js_name = "fromConfig")]
     pub fn from_config(json_config: &str) -> Result<TestMatcher, JsValue> {
         let config: rumi::MatcherConfig<String> = serde_json::from_str(json_config)
@@ -35,14 +53,20 @@ impl TestMatcher {
 
         let registry = build_test_registry();
         let matcher = registry
-            .load_matcher(config)
+            .load_matcher(config.clone())
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
         matcher
             .validate()
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
-        Ok(Self { inner: matcher })
+        let index = rumi::index::build_index(&config);
+
+        Ok(Self {
+            inner: matcher,
+            config,
+            index,
+        })
     }
 
     /// Evaluate a key-value context against compiled matcher rules.
@@ -52,16 +76,40 @@ impl TestMatcher {
     /// matcher.evaluate({ role: "admin", org: "acme" })
     /// ```
     ///
-    /// Returns the action string if the context matched, or `undefined`.
+    /// Returns the action string if the context matched, or `undefined`. Any
+    /// `${name}` placeholders a predicate's `capture` name would bind are
+    /// *not* substituted — threading a live `Captures` environment through
+    /// `Matcher::evaluate` and interpolating the resolved action is the
+    /// runtime engine's job (see `rumi::capture`), and that engine isn't
+    /// wired up here, so the action comes back with any `${name}` references
+    /// still literal.
     pub fn evaluate(&self, context: JsValue) -> Result<Option<String>, JsValue> {
         let ctx = build_context_from_js(&context)?;
         Ok(self.inner.evaluate(&ctx))
     }
 
-    /// Trace evaluation for debugging.
+    /// Trace evaluation for debugging, one step per top-level matcher
+    /// evaluated before the first match.
+    ///
+    /// Does not report `rewrite` ops applied by `transform()` — that needs
+    /// the runtime's trace step to carry its own audit trail, which isn't
+    /// part of this crate's sources; use `transform()`'s return value to see
+    /// the rewritten context instead.
+    ///
+    /// `elapsedNs`/`stepsEvaluated`/`stepsSkipped` profile which configs
+    /// dominate evaluation cost: `elapsedNs` is wall-clock for this whole
+    /// call (via `Date.now()`, millisecond-resolution), `stepsEvaluated` is
+    /// `steps.length`, and `stepsSkipped` is how many of the configured
+    /// top-level matchers the first-match-wins short-circuit never reached.
+    /// Per-step timing and sub-match counts aren't available here —
+    /// recording those needs a monotonic clock inside
+    /// `Matcher::evaluate_with_trace` itself, which isn't part of this
+    /// crate's sources.
     pub fn trace(&self, context: JsValue) -> Result<JsValue, JsValue> {
         let ctx = build_context_from_js(&context)?;
+        let started_ms = js_sys::Date::now();
         let trace = self.inner.evaluate_with_trace(&ctx);
+        let elapsed_ns = ((js_sys::Date::now() - started_ms) * 1_000_000.0).max(0.0) as u64;
 
         let steps: Vec<TraceStepSerde> = trace
             .steps
@@ -72,16 +120,89 @@ impl TestMatcher {
                 predicate: format!("{:?}", step.predicate_trace),
             })
             .collect();
+        let steps_evaluated = steps.len();
 
         let result = TraceResultSerde {
             result: trace.result,
             steps,
             used_fallback: trace.used_fallback,
+            elapsed_ns,
+            steps_evaluated,
+            steps_skipped: self.config.matchers.len().saturating_sub(steps_evaluated),
         };
 
         serde_wasm_bindgen::to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Evaluate a context and, if a rule matched, apply its `rewrite`
+    /// template (see `rumi::rewrite`) to a plain copy of the context.
+    ///
+    /// Returns `{ context, appliedOps }` — the rewritten
+    /// `Record<string, string>` plus the ordered audit trail of ops that
+    /// actually ran (see `rumi::rewrite::AppliedOp`) — or `undefined` if no
+    /// rule matched or the matched rule carries no `rewrite`.
+    ///
+    /// # Why this only sees top-level matches
+    ///
+    /// `rewrite` is looked up by the index of whichever top-level
+    /// `FieldMatcher` `evaluate_with_trace` reports as matched; a match
+    /// inside a nested `OnMatchConfig::Matcher` isn't resolved to its own
+    /// `rewrite` here, since that requires the runtime to report the full
+    /// matched path, not just an index — see `Matcher::evaluate_with_trace`
+    /// (not part of this crate's sources).
+    pub fn transform(&self, context: JsValue) -> Result<JsValue, JsValue> {
+        let (ctx, mut out) = build_context_and_map_from_js(&context)?;
+        let trace = self.inner.evaluate_with_trace(&ctx);
+
+        let Some(step) = trace.steps.iter().rev().find(|s| s.matched) else {
+            return Ok(JsValue::UNDEFINED);
+        };
+        let Some(field_matcher) = self.config.matchers.get(step.index) else {
+            return Ok(JsValue::UNDEFINED);
+        };
+        let rumi::OnMatchConfig::Action {
+            rewrite: Some(template),
+            ..
+        } = &field_matcher.on_match
+        else {
+            return Ok(JsValue::UNDEFINED);
+        };
+
+        let applied_ops = rumi::rewrite::apply(template, &mut out, &step.bindings);
+        let result = TransformResultSerde {
+            context: out,
+            applied_ops,
+        };
+        serde_wasm_bindgen::to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Cross-check the candidate pre-filtering index (see `rumi::index`)
+    /// against the real linear-scan result for one context.
+    ///
+    /// Returns `true` if the invariant holds: whichever top-level rule
+    /// `evaluate_with_trace` reports as matched (if any) is a member of the
+    /// index's candidate set for this context. A `false` result would mean
+    /// the index is unsound — it would wrongly let `evaluate` skip a rule
+    /// that should have fired.
+    ///
+    /// Exposed per-context rather than as a `runFixtures` case: a YAML
+    /// fixture case's raw key-value context isn't exposed by
+    /// `rumi_test::config_fixture` (only `case.build_context()`, an opaque
+    /// `TestContext`), so there's nothing here to build an index lookup
+    /// from without the runtime exposing it — that's the same "isn't part
+    /// of this crate's sources" gap `transform()` already documents.
+    #[wasm_bindgen(js_name = "checkIndexConsistency")]
+    pub fn check_index_consistency(&self, context: JsValue) -> Result<bool, JsValue> {
+        let (ctx, map) = build_context_and_map_from_js(&context)?;
+        let trace = self.inner.evaluate_with_trace(&ctx);
+        let candidate_set = rumi::index::candidates(&self.index, &map);
+
+        Ok(match trace.steps.iter().rev().find(|s| s.matched) {
+            Some(step) => candidate_set.contains(&step.index),
+            None => true,
+        })
+    }
+
     /// Load and run conformance fixtures from a YAML string.
     ///
     /// Returns an array of `{ fixture, caseName, passed, detail }` objects.
@@ -158,7 +279,7 @@ impl TestMatcher {
             for case in &fixture.cases {
                 let ctx = case.build_context();
                 let result = matcher.evaluate(&ctx);
-                let passed = result == case.expect;
+                let passed = expectation_matches(&case.expect, &result);
                 let detail = if passed {
                     format!("got {result:?}")
                 } else {
@@ -184,8 +305,19 @@ fn build_test_registry() -> rumi::Registry<TestContext> {
 
 /// Build a `TestContext` from a JS plain object (Record<string, string>).
 fn build_context_from_js(val: &JsValue) -> Result<TestContext, JsValue> {
+    build_context_and_map_from_js(val).map(|(ctx, _)| ctx)
+}
+
+/// Build a `TestContext` from a JS plain object, alongside a plain
+/// `HashMap<String, String>` mirror of the same entries — the latter is
+/// what `transform()` rewrites and hands back, since `TestContext` itself
+/// doesn't expose its entries for mutation.
+fn build_context_and_map_from_js(
+    val: &JsValue,
+) -> Result<(TestContext, std::collections::HashMap<String, String>), JsValue> {
     let entries = js_sys::Object::entries(&js_sys::Object::from(val.clone()));
     let mut ctx = TestContext::new();
+    let mut map = std::collections::HashMap::new();
     for entry in entries.iter() {
         let pair = js_sys::Array::from(&entry);
         let key = pair
@@ -196,9 +328,61 @@ fn build_context_from_js(val: &JsValue) -> Result<TestContext, JsValue> {
             .get(1)
             .as_string()
             .ok_or_else(|| JsValue::from_str("context value must be a string"))?;
+        map.insert(key.clone(), value.clone());
         ctx = ctx.with(key, value);
     }
-    Ok(ctx)
+    Ok((ctx, map))
+}
+
+/// Whether a matcher's `actual` result satisfies a fixture case's `expect` value.
+///
+/// `expect` supports three forms, tried in order:
+/// - `"glob:<pattern>"` — shell-style wildcard match (`*` and `?`).
+/// - `"regex:<pattern>"` — unanchored search with a `regex` crate pattern
+///   (add `^`/`$` to require a full-string match).
+/// - anything else — exact string equality (the original behavior).
+///
+/// `None` only matches when the matcher itself produced no action.
+fn expectation_matches(expect: &Option<String>, actual: &Option<String>) -> bool {
+    match (expect, actual) {
+        (None, None) => true,
+        (None, Some(_)) | (Some(_), None) => false,
+        (Some(pattern), Some(actual)) => {
+            if let Some(glob) = pattern.strip_prefix("glob:") {
+                glob_match(glob, actual)
+            } else if let Some(re) = pattern.strip_prefix("regex:") {
+                regex::Regex::new(re).is_ok_and(|re| re.is_match(actual))
+            } else {
+                pattern == actual
+            }
+        }
+    }
+}
+
+/// Minimal shell-style glob matcher supporting `*` (any run of characters)
+/// and `?` (exactly one character).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn helper(p: &[char], t: &[char]) -> bool {
+        match (p.first(), t.first()) {
+            (None, None) => true,
+            (Some('*'), _) => helper(&p[1..], t) || (!t.is_empty() && helper(p, &t[1..])),
+            (Some('?'), Some(_)) => helper(&p[1..], &t[1..]),
+            (Some(pc), Some(tc)) if pc == tc => helper(&p[1..], &t[1..]),
+            _ => false,
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    helper(&pattern, &text)
+}
+
+/// `transform()`'s result for serde-wasm-bindgen serialization: the
+/// rewritten context alongside the audit trail of ops actually applied.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransformResultSerde {
+    context: std::collections::HashMap<String, String>,
+    applied_ops: Vec<rumi::rewrite::AppliedOp>,
 }
 
 /// Fixture result for serde-wasm-bindgen serialization.
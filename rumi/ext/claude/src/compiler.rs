@@ -0,0 +1,1185 @@
+//! Domain compiler: a declarative [`HookMatch`]/[`ArgumentMatch`] builder
+//! that lowers to the core `Predicate`/`Matcher` tree (see the crate-level
+//! docs for the canonical "block dangerous Bash commands" example), so a
+//! hook rule can be authored as plain data instead of hand-assembled
+//! `SinglePredicate`s.
+//!
+//! [`HookContext`]/[`HookEvent`] are defined in `context.rs`, not part of
+//! this crate's sources — this module only consumes the accessors
+//! (`ctx.event()`, `ctx.tool_name()`, `ctx.argument(name)`, …) the inputs in
+//! [`crate::inputs`] already rely on.
+
+use crate::context::{HookContext, HookEvent};
+use crate::inputs::{
+    ArgumentInput, CwdInput, EventInput, GitBranchInput, SessionIdInput, ToolNameInput,
+};
+use rumi::prelude::*;
+
+/// A string-matching strategy a compiled predicate tests a [`DataInput`]'s
+/// value against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringMatch {
+    /// The value equals this string exactly.
+    Exact(String),
+    /// The value contains this substring.
+    Contains(String),
+    /// The value contains a match for this regex anywhere in the string —
+    /// the pattern-based analogue of [`Contains`](StringMatch::Contains).
+    Regex(String),
+    /// The value matches this regex across its entire length (implicitly
+    /// anchored `^(?:...)$`) — the pattern-based analogue of
+    /// [`Exact`](StringMatch::Exact).
+    RegexFull(String),
+}
+
+impl StringMatch {
+    /// Compile to a boxed [`InputMatcher`], pre-compiling any
+    /// [`Regex`](StringMatch::Regex)/[`RegexFull`](StringMatch::RegexFull)
+    /// pattern so evaluation itself never allocates a new automaton.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if a `Regex`/`RegexFull` pattern fails to
+    /// compile.
+    pub fn compile(&self) -> Result<Box<dyn InputMatcher>, String> {
+        match self {
+            StringMatch::Exact(s) => Ok(Box::new(ExactMatcher::new(s.clone()))),
+            StringMatch::Contains(s) => Ok(Box::new(ContainsMatcher::new(s.clone()))),
+            StringMatch::Regex(pattern) => Ok(Box::new(RegexMatcher::unanchored(pattern)?)),
+            StringMatch::RegexFull(pattern) => Ok(Box::new(RegexMatcher::anchored(pattern)?)),
+        }
+    }
+}
+
+impl std::fmt::Display for StringMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StringMatch::Exact(s) => write!(f, "== {s:?}"),
+            StringMatch::Contains(s) => write!(f, "contains {s:?}"),
+            StringMatch::Regex(pattern) => write!(f, "=~ /{pattern}/"),
+            StringMatch::RegexFull(pattern) => write!(f, "=~ /^{pattern}$/"),
+        }
+    }
+}
+
+/// A pre-compiled regex [`InputMatcher`], in unanchored (substring search,
+/// like `grep`) or anchored (whole-string, like [`ExactMatcher`] but
+/// pattern-based) mode.
+///
+/// Compiled once at [`StringMatch::compile`] time, not per evaluation — a
+/// `RegexMatcher::matches` call is just `Regex::is_match`.
+#[derive(Debug, Clone)]
+pub struct RegexMatcher {
+    regex: regex::Regex,
+}
+
+impl RegexMatcher {
+    /// Compile `pattern` for an unanchored (substring) search.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `pattern` fails to compile.
+    pub fn unanchored(pattern: &str) -> Result<Self, String> {
+        let regex =
+            regex::Regex::new(pattern).map_err(|e| format!("invalid regex {pattern:?}: {e}"))?;
+        Ok(Self { regex })
+    }
+
+    /// Compile `pattern` anchored across the whole string (`^(?:pattern)$`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `pattern` fails to compile.
+    pub fn anchored(pattern: &str) -> Result<Self, String> {
+        let regex = regex::Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|e| format!("invalid regex {pattern:?}: {e}"))?;
+        Ok(Self { regex })
+    }
+}
+
+impl InputMatcher for RegexMatcher {
+    fn matches(&self, data: &MatchingData) -> bool {
+        match data {
+            MatchingData::String(s) => self.regex.is_match(s),
+            _ => false,
+        }
+    }
+}
+
+/// One `{name, value}` argument constraint within a [`HookMatch`], e.g.
+/// `ArgumentMatch { name: "command".into(), value: StringMatch::Contains("rm -rf".into()) }`
+/// to test a `Bash` tool call's `command` argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentMatch {
+    /// The argument name, as passed to [`ArgumentInput::new`].
+    pub name: String,
+    /// How the argument's value must match.
+    pub value: StringMatch,
+}
+
+/// A declarative hook rule: every populated field is AND-ed together (a
+/// `HookMatch` with every field `None` matches unconditionally), as the
+/// crate-level docs' "block dangerous Bash commands" example shows. Compile
+/// with [`HookMatchExt::compile`] (a single rule's action) or
+/// [`compile_hook_matches`] (a priority-ordered list of rules sharing a
+/// fallback action).
+#[derive(Debug, Clone, Default)]
+pub struct HookMatch {
+    /// The hook event (`PreToolUse`, `PostToolUse`, …) this rule applies to.
+    pub event: Option<HookEvent>,
+    /// The tool name (`"Bash"`, `"Write"`, …).
+    pub tool_name: Option<StringMatch>,
+    /// Per-argument constraints, all of which must hold.
+    pub arguments: Option<Vec<ArgumentMatch>>,
+    /// The working directory the hook ran in.
+    pub cwd: Option<StringMatch>,
+    /// The current git branch (see [`crate::inputs::GitBranchInput`]).
+    pub git_branch: Option<StringMatch>,
+    /// The Claude Code session id.
+    pub session_id: Option<StringMatch>,
+}
+
+impl HookMatch {
+    /// Lower every populated field into a conjunction of [`Predicate::Single`]
+    /// leaves, AND-ed together. An all-`None` rule lowers to `Predicate::And(vec![])`
+    /// — a vacuous conjunction that matches unconditionally.
+    fn to_predicate(&self) -> Result<Predicate<HookContext>, String> {
+        let mut parts: Vec<Predicate<HookContext>> = Vec::new();
+
+        if let Some(event) = &self.event {
+            parts.push(Predicate::Single(SinglePredicate::new(
+                Box::new(EventInput),
+                Box::new(ExactMatcher::new(event.as_str().to_string())),
+            )));
+        }
+        if let Some(tool_name) = &self.tool_name {
+            parts.push(Predicate::Single(SinglePredicate::new(
+                Box::new(ToolNameInput),
+                tool_name.compile()?,
+            )));
+        }
+        if let Some(cwd) = &self.cwd {
+            parts.push(Predicate::Single(SinglePredicate::new(
+                Box::new(CwdInput),
+                cwd.compile()?,
+            )));
+        }
+        if let Some(git_branch) = &self.git_branch {
+            parts.push(Predicate::Single(SinglePredicate::new(
+                Box::new(GitBranchInput),
+                git_branch.compile()?,
+            )));
+        }
+        if let Some(session_id) = &self.session_id {
+            parts.push(Predicate::Single(SinglePredicate::new(
+                Box::new(SessionIdInput),
+                session_id.compile()?,
+            )));
+        }
+        if let Some(arguments) = &self.arguments {
+            for arg in arguments {
+                parts.push(Predicate::Single(SinglePredicate::new(
+                    Box::new(ArgumentInput::new(arg.name.clone())),
+                    arg.value.compile()?,
+                )));
+            }
+        }
+
+        Ok(Predicate::And(parts))
+    }
+}
+
+/// One step of a [`HookMatchTrace`]: the field checked, what it expected,
+/// what the context actually reported, and whether they matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// The [`HookMatch`] field this step checked (`"event"`, `"tool_name"`,
+    /// `"arg:<name>"`, …).
+    pub field: String,
+    /// A human-readable description of the expected value.
+    pub expected: String,
+    /// The actual value the context reported (`"<none>"` if the input
+    /// yielded [`MatchingData::None`]).
+    pub actual: String,
+    /// Whether `actual` satisfied `expected`.
+    pub matched: bool,
+}
+
+/// Every [`TraceStep`] produced by evaluating a [`HookMatch`] against one
+/// [`HookContext`], in field-check order, for debugging why a rule did or
+/// didn't match.
+#[derive(Debug, Clone, Default)]
+pub struct HookMatchTrace {
+    /// The steps, in the order the rule's fields were checked.
+    pub steps: Vec<TraceStep>,
+}
+
+impl HookMatchTrace {
+    /// Whether every step matched — the same verdict
+    /// [`HookMatchExt::compile`]'s predicate would report for this context.
+    #[must_use]
+    pub fn matched(&self) -> bool {
+        self.steps.iter().all(|step| step.matched)
+    }
+
+    /// Serialize to JSON, redacting each step's `actual` value with
+    /// [`TraceRedactor::default`]'s built-in patterns — the in-memory
+    /// `steps` are never mutated, only the emitted copy.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        self.to_json_with(&TraceRedactor::default())
+    }
+
+    /// Serialize to JSON, redacting each step's `actual` value with
+    /// `redactor` instead of the default rule set.
+    #[must_use]
+    pub fn to_json_with(&self, redactor: &TraceRedactor) -> serde_json::Value {
+        serde_json::json!({
+            "matched": self.matched(),
+            "steps": self.steps.iter().map(|step| serde_json::json!({
+                "field": step.field,
+                "expected": step.expected,
+                "actual": redactor.redact(&step.actual),
+                "matched": step.matched,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Applies regex-substitution rules to a [`HookMatchTrace`] step's `actual`
+/// value before it's serialized or displayed, so a secret embedded in a
+/// tool argument (a token in a `Bash` command, say) never reaches a log
+/// sink or UI even though the in-memory trace keeps the real value for test
+/// assertions.
+///
+/// [`TraceRedactor::default`] ships a handful of common secret shapes;
+/// [`with_pattern`](Self::with_pattern) layers additional caller-supplied
+/// patterns on top — rules run in registration order, each over the
+/// previous rule's output.
+pub struct TraceRedactor {
+    rules: Vec<regex::Regex>,
+}
+
+impl TraceRedactor {
+    /// A redactor with no rules — `redact` returns its input unchanged.
+    /// Build up from here with [`with_pattern`](Self::with_pattern), or use
+    /// [`TraceRedactor::default`] for the built-in rule set.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Register an additional regex rule: every match is replaced with
+    /// `[REDACTED]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `pattern` fails to compile.
+    pub fn with_pattern(mut self, pattern: &str) -> Result<Self, String> {
+        let regex =
+            regex::Regex::new(pattern).map_err(|e| format!("invalid regex {pattern:?}: {e}"))?;
+        self.rules.push(regex);
+        Ok(self)
+    }
+
+    /// Apply every rule to `value` in registration order, replacing each
+    /// match with `[REDACTED]`.
+    fn redact(&self, value: &str) -> String {
+        let mut redacted = value.to_string();
+        for rule in &self.rules {
+            redacted = rule.replace_all(&redacted, "[REDACTED]").into_owned();
+        }
+        redacted
+    }
+}
+
+impl Default for TraceRedactor {
+    /// Covers a handful of common secret shapes: AWS access key IDs, GitHub
+    /// personal access tokens, and `Bearer <token>` HTTP auth headers.
+    fn default() -> Self {
+        Self::empty()
+            .with_pattern(r"AKIA[0-9A-Z]{16}")
+            .and_then(|r| r.with_pattern(r"ghp_[A-Za-z0-9]{36}"))
+            .and_then(|r| r.with_pattern(r"(?i)bearer\s+[A-Za-z0-9\-._~+/]+=*"))
+            .expect("built-in redaction patterns are valid regexes")
+    }
+}
+
+fn trace_step(
+    field: &str,
+    expected: &StringMatch,
+    actual: MatchingData,
+    matcher: &dyn InputMatcher,
+) -> TraceStep {
+    let matched = matcher.matches(&actual);
+    let actual_str = match actual {
+        MatchingData::String(s) => s,
+        MatchingData::None => "<none>".to_string(),
+        other => format!("{other:?}"),
+    };
+    TraceStep {
+        field: field.to_string(),
+        expected: expected.to_string(),
+        actual: actual_str,
+        matched,
+    }
+}
+
+/// Extension trait compiling a [`HookMatch`] into a runnable [`Matcher`], or
+/// tracing it against a context for debugging.
+pub trait HookMatchExt<A> {
+    /// Compile this rule into a single-action [`Matcher`]: `action` is
+    /// returned when every field matches, `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if any field's [`StringMatch::Regex`]/
+    /// [`StringMatch::RegexFull`] pattern fails to compile.
+    fn compile(self, action: A) -> Result<Matcher<HookContext, A>, String>;
+
+    /// Evaluate every populated field against `ctx`, reporting a
+    /// [`HookMatchTrace`] instead of just a pass/fail `Option<A>` — see the
+    /// crate-level docs' trace example.
+    fn trace(&self, ctx: &HookContext) -> HookMatchTrace;
+
+    /// Parse `expr` as a [`HookExpr`] (see the module's grammar doc) and
+    /// compile it into a single-action [`Matcher`], the `cfg(...)`-style
+    /// counterpart to [`compile`](Self::compile) for rules that need `any`/
+    /// `not` rather than just an implicit `all`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] for unbalanced parens, an unknown key, or an
+    /// invalid regex pattern, with the byte span of the offending token.
+    fn compile_expr(expr: &str, action: A) -> Result<Matcher<HookContext, A>, ParseError>
+    where
+        Self: Sized,
+    {
+        let parsed = parse_hook_expr(expr)?;
+        Ok(Matcher::new(
+            vec![FieldMatcher::new(
+                parsed.to_predicate(),
+                OnMatch::Action(action),
+            )],
+            None,
+        ))
+    }
+}
+
+impl<A> HookMatchExt<A> for HookMatch {
+    fn compile(self, action: A) -> Result<Matcher<HookContext, A>, String> {
+        let predicate = self.to_predicate()?;
+        Ok(Matcher::new(
+            vec![FieldMatcher::new(predicate, OnMatch::Action(action))],
+            None,
+        ))
+    }
+
+    fn trace(&self, ctx: &HookContext) -> HookMatchTrace {
+        let mut steps = Vec::new();
+
+        if let Some(event) = &self.event {
+            let expected = StringMatch::Exact(event.as_str().to_string());
+            let matcher = ExactMatcher::new(event.as_str().to_string());
+            steps.push(trace_step(
+                "event",
+                &expected,
+                EventInput.get(ctx),
+                &matcher,
+            ));
+        }
+        if let Some(tool_name) = &self.tool_name {
+            if let Ok(matcher) = tool_name.compile() {
+                steps.push(trace_step(
+                    "tool_name",
+                    tool_name,
+                    ToolNameInput.get(ctx),
+                    matcher.as_ref(),
+                ));
+            }
+        }
+        if let Some(cwd) = &self.cwd {
+            if let Ok(matcher) = cwd.compile() {
+                steps.push(trace_step("cwd", cwd, CwdInput.get(ctx), matcher.as_ref()));
+            }
+        }
+        if let Some(git_branch) = &self.git_branch {
+            if let Ok(matcher) = git_branch.compile() {
+                steps.push(trace_step(
+                    "git_branch",
+                    git_branch,
+                    GitBranchInput.get(ctx),
+                    matcher.as_ref(),
+                ));
+            }
+        }
+        if let Some(session_id) = &self.session_id {
+            if let Ok(matcher) = session_id.compile() {
+                steps.push(trace_step(
+                    "session_id",
+                    session_id,
+                    SessionIdInput.get(ctx),
+                    matcher.as_ref(),
+                ));
+            }
+        }
+        if let Some(arguments) = &self.arguments {
+            for arg in arguments {
+                if let Ok(matcher) = arg.value.compile() {
+                    steps.push(trace_step(
+                        &format!("arg:{}", arg.name),
+                        &arg.value,
+                        ArgumentInput::new(arg.name.clone()).get(ctx),
+                        matcher.as_ref(),
+                    ));
+                }
+            }
+        }
+
+        HookMatchTrace { steps }
+    }
+}
+
+/// Compile a priority-ordered list of `(rule, action)` pairs into a single
+/// first-match-wins [`Matcher`], with an optional `default` action for when
+/// no rule's predicate matches — the list-of-rules counterpart to
+/// [`HookMatchExt::compile`]'s single rule.
+///
+/// # Errors
+///
+/// Returns an error string if any rule's [`StringMatch::Regex`]/
+/// [`StringMatch::RegexFull`] pattern fails to compile.
+pub fn compile_hook_matches<A>(
+    rules: Vec<(HookMatch, A)>,
+    default: Option<A>,
+) -> Result<Matcher<HookContext, A>, String> {
+    let matchers = rules
+        .into_iter()
+        .map(|(rule, action)| {
+            Ok(FieldMatcher::new(
+                rule.to_predicate()?,
+                OnMatch::Action(action),
+            ))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(Matcher::new(matchers, default.map(OnMatch::Action)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// HookExpr: a `cfg(...)`-style boolean expression language
+//
+// # Grammar
+//
+// ```text
+// expr      := "all" "(" expr ("," expr)* ")"
+//            | "any" "(" expr ("," expr)* ")"
+//            | "not" "(" expr ")"
+//            | leaf
+// leaf      := KEY ("=" STRING | "~" STRING)?
+// ```
+//
+// A bare `leaf` (no `=`/`~`) matches whenever the key's input yields
+// anything other than `MatchingData::None` — the `cfg`-flag analogue of
+// `unix`, with no value to compare. `key = "value"` is an exact match
+// (`StringMatch::Exact`); `key ~ "pattern"` is an unanchored regex match
+// (`StringMatch::Regex`). `key` must be one of `event`, `tool_name`, `cwd`,
+// `git_branch`, `session_id`, or `arg:<name>` for an `ArgumentInput`.
+//
+// ```ignore
+// let matcher = HookMatch::compile_expr(
+//     r#"any(tool_name = "Bash", tool_name = "Write")"#,
+//     "flag",
+// )?;
+// ```
+// ═══════════════════════════════════════════════════════════════════════
+
+/// A byte-offset span into [`HookExpr`] source, for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the span's first byte.
+    pub start: usize,
+    /// Byte offset one past the span's last byte.
+    pub end: usize,
+}
+
+/// A [`HookExpr`] parse error with the source span it occurred at —
+/// an unbalanced paren, an unknown key, or an invalid regex pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// A human-readable description of the error.
+    pub message: String,
+    /// The source span the error occurred at.
+    pub span: Span,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at byte {}..{})",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    All,
+    Any,
+    Not,
+    Eq,
+    Tilde,
+    Comma,
+    LParen,
+    RParen,
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+fn lex_hook_expr(src: &str) -> Result<Vec<Token>, ParseError> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0usize;
+
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        match c {
+            '(' => {
+                tokens.push(Token {
+                    kind: TokenKind::LParen,
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token {
+                    kind: TokenKind::RParen,
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token {
+                    kind: TokenKind::Comma,
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token {
+                    kind: TokenKind::Eq,
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+                i += 1;
+            }
+            '~' => {
+                tokens.push(Token {
+                    kind: TokenKind::Tilde,
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+                i += 1;
+            }
+            '"' => {
+                let mut j = i + 1;
+                let mut value = String::new();
+                loop {
+                    match bytes.get(j) {
+                        None => {
+                            return Err(ParseError {
+                                message: "unterminated string literal".into(),
+                                span: Span { start, end: j },
+                            });
+                        }
+                        Some(b'"') => {
+                            j += 1;
+                            break;
+                        }
+                        Some(_) => {
+                            value.push(bytes[j] as char);
+                            j += 1;
+                        }
+                    }
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Str(value),
+                    span: Span { start, end: j },
+                });
+                i = j;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut j = i;
+                while j < bytes.len() {
+                    let c = bytes[j] as char;
+                    if c.is_alphanumeric() || c == '_' || c == ':' {
+                        j += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let word = &src[i..j];
+                let kind = match word {
+                    "all" => TokenKind::All,
+                    "any" => TokenKind::Any,
+                    "not" => TokenKind::Not,
+                    _ => TokenKind::Ident(word.to_string()),
+                };
+                tokens.push(Token {
+                    kind,
+                    span: Span { start, end: j },
+                });
+                i = j;
+            }
+            other => {
+                return Err(ParseError {
+                    message: format!("unexpected character '{other}'"),
+                    span: Span {
+                        start,
+                        end: start + 1,
+                    },
+                });
+            }
+        }
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span {
+            start: bytes.len(),
+            end: bytes.len(),
+        },
+    });
+    Ok(tokens)
+}
+
+fn is_known_key(key: &str) -> bool {
+    matches!(
+        key,
+        "event" | "tool_name" | "cwd" | "git_branch" | "session_id"
+    ) || key
+        .strip_prefix("arg:")
+        .is_some_and(|rest| !rest.is_empty())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LeafOp {
+    Present,
+    Exact(String),
+    Regex(String),
+}
+
+/// A parsed `cfg(...)`-style boolean expression over [`HookContext`] inputs
+/// (see the module grammar doc). Parse with [`parse_hook_expr`]; compile
+/// with [`HookMatchExt::compile_expr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookExpr {
+    /// Every child must match (`all(...)`).
+    All(Vec<HookExpr>),
+    /// Any child must match (`any(...)`).
+    Any(Vec<HookExpr>),
+    /// Inverts the inner expression (`not(...)`).
+    Not(Box<HookExpr>),
+    /// A single `key`/value check.
+    Leaf { key: String, op: LeafOp },
+}
+
+impl HookExpr {
+    /// Lower this expression into the core `Predicate` tree.
+    ///
+    /// Panics only on a logic error in this module: [`parse_hook_expr`]
+    /// already rejects unknown keys and invalid regex patterns, so by the
+    /// time a `HookExpr` exists, `key` resolves to a known input and any
+    /// `LeafOp::Regex` pattern is known to compile.
+    fn to_predicate(&self) -> Predicate<HookContext> {
+        match self {
+            HookExpr::All(parts) => {
+                Predicate::And(parts.iter().map(HookExpr::to_predicate).collect())
+            }
+            HookExpr::Any(parts) => {
+                Predicate::Or(parts.iter().map(HookExpr::to_predicate).collect())
+            }
+            HookExpr::Not(inner) => Predicate::Not(Box::new(inner.to_predicate())),
+            HookExpr::Leaf { key, op } => {
+                let input = resolve_input(key).expect("key validated at parse time");
+                let matcher: Box<dyn InputMatcher> = match op {
+                    LeafOp::Present => Box::new(PresentMatcher),
+                    LeafOp::Exact(value) => StringMatch::Exact(value.clone())
+                        .compile()
+                        .expect("Exact never fails to compile"),
+                    LeafOp::Regex(pattern) => StringMatch::Regex(pattern.clone())
+                        .compile()
+                        .expect("regex pattern validated at parse time"),
+                };
+                Predicate::Single(SinglePredicate::new(input, matcher))
+            }
+        }
+    }
+}
+
+fn resolve_input(key: &str) -> Option<Box<dyn DataInput<HookContext>>> {
+    match key {
+        "event" => Some(Box::new(EventInput)),
+        "tool_name" => Some(Box::new(ToolNameInput)),
+        "cwd" => Some(Box::new(CwdInput)),
+        "git_branch" => Some(Box::new(GitBranchInput)),
+        "session_id" => Some(Box::new(SessionIdInput)),
+        key => key
+            .strip_prefix("arg:")
+            .map(|name| -> Box<dyn DataInput<HookContext>> {
+                Box::new(ArgumentInput::new(name.to_string()))
+            }),
+    }
+}
+
+/// Matches any value other than [`MatchingData::None`] — the `HookExpr`
+/// bare-identifier leaf ("does this input report anything at all"),
+/// analogous to a valueless `cfg` flag like `unix`.
+#[derive(Debug, Clone, Copy, Default)]
+struct PresentMatcher;
+
+impl InputMatcher for PresentMatcher {
+    fn matches(&self, data: &MatchingData) -> bool {
+        !matches!(data, MatchingData::None)
+    }
+}
+
+struct ExprParser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> ExprParser<'a> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn advance(&mut self) -> &Token {
+        let tok = &self.tokens[self.pos];
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn expect(&mut self, kind: &TokenKind, what: &str) -> Result<Span, ParseError> {
+        let tok = self.peek();
+        if &tok.kind == kind {
+            let span = tok.span;
+            self.advance();
+            Ok(span)
+        } else {
+            Err(ParseError {
+                message: format!("expected {what}, found {:?}", tok.kind),
+                span: tok.span,
+            })
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<HookExpr, ParseError> {
+        match &self.peek().kind {
+            TokenKind::All => {
+                self.advance();
+                Ok(HookExpr::All(self.parse_arg_list()?))
+            }
+            TokenKind::Any => {
+                self.advance();
+                Ok(HookExpr::Any(self.parse_arg_list()?))
+            }
+            TokenKind::Not => {
+                self.advance();
+                self.expect(&TokenKind::LParen, "'('")?;
+                let inner = self.parse_expr()?;
+                self.expect(&TokenKind::RParen, "')'")?;
+                Ok(HookExpr::Not(Box::new(inner)))
+            }
+            TokenKind::Ident(_) => self.parse_leaf(),
+            other => Err(ParseError {
+                message: format!("expected an expression, found {other:?}"),
+                span: self.peek().span,
+            }),
+        }
+    }
+
+    fn parse_arg_list(&mut self) -> Result<Vec<HookExpr>, ParseError> {
+        self.expect(&TokenKind::LParen, "'('")?;
+        let mut items = vec![self.parse_expr()?];
+        while self.peek().kind == TokenKind::Comma {
+            self.advance();
+            items.push(self.parse_expr()?);
+        }
+        self.expect(&TokenKind::RParen, "')'")?;
+        Ok(items)
+    }
+
+    fn parse_leaf(&mut self) -> Result<HookExpr, ParseError> {
+        let (key, key_span) = match &self.peek().kind {
+            TokenKind::Ident(name) => (name.clone(), self.peek().span),
+            other => {
+                return Err(ParseError {
+                    message: format!("expected a key, found {other:?}"),
+                    span: self.peek().span,
+                })
+            }
+        };
+        self.advance();
+        if !is_known_key(&key) {
+            return Err(ParseError {
+                message: format!("unknown key {key:?}"),
+                span: key_span,
+            });
+        }
+
+        let op = match &self.peek().kind {
+            TokenKind::Eq => {
+                self.advance();
+                LeafOp::Exact(self.parse_string()?)
+            }
+            TokenKind::Tilde => {
+                self.advance();
+                let (pattern, span) = self.parse_string_spanned()?;
+                if let Err(e) = regex::Regex::new(&pattern) {
+                    return Err(ParseError {
+                        message: format!("invalid regex {pattern:?}: {e}"),
+                        span,
+                    });
+                }
+                LeafOp::Regex(pattern)
+            }
+            _ => LeafOp::Present,
+        };
+        Ok(HookExpr::Leaf { key, op })
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.parse_string_spanned().map(|(s, _)| s)
+    }
+
+    fn parse_string_spanned(&mut self) -> Result<(String, Span), ParseError> {
+        match &self.peek().kind {
+            TokenKind::Str(s) => {
+                let s = s.clone();
+                let span = self.peek().span;
+                self.advance();
+                Ok((s, span))
+            }
+            other => Err(ParseError {
+                message: format!("expected a string literal, found {other:?}"),
+                span: self.peek().span,
+            }),
+        }
+    }
+}
+
+/// Parse `src` as a [`HookExpr`] (see the module grammar doc).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] for unbalanced parens, an unknown key, an
+/// invalid regex pattern, or trailing input after a complete expression.
+pub fn parse_hook_expr(src: &str) -> Result<HookExpr, ParseError> {
+    let tokens = lex_hook_expr(src)?;
+    let mut parser = ExprParser {
+        tokens: &tokens,
+        pos: 0,
+    };
+    let expr = parser.parse_expr()?;
+    match parser.peek().kind {
+        TokenKind::Eof => Ok(expr),
+        ref other => Err(ParseError {
+            message: format!("unexpected trailing token {other:?}"),
+            span: parser.peek().span,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_and_matches_the_dangerous_bash_example() {
+        let rule = HookMatch {
+            event: Some(HookEvent::PreToolUse),
+            tool_name: Some(StringMatch::Exact("Bash".into())),
+            arguments: Some(vec![ArgumentMatch {
+                name: "command".into(),
+                value: StringMatch::Contains("rm -rf".into()),
+            }]),
+            ..Default::default()
+        };
+        let matcher = rule.compile("block").unwrap();
+
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "rm -rf /important");
+        assert_eq!(matcher.evaluate(&ctx), Some("block"));
+
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "ls -la");
+        assert_eq!(matcher.evaluate(&ctx), None);
+    }
+
+    #[test]
+    fn regex_honours_explicit_anchors_in_the_pattern() {
+        let rule = HookMatch {
+            tool_name: Some(StringMatch::Regex(r"^(Bash|Write)$".into())),
+            ..Default::default()
+        };
+        let matcher = rule.compile("hit").unwrap();
+
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Bash")),
+            Some("hit")
+        );
+        assert_eq!(matcher.evaluate(&HookContext::pre_tool_use("Read")), None);
+    }
+
+    #[test]
+    fn regex_full_requires_the_whole_value_to_match() {
+        let rule = HookMatch {
+            tool_name: Some(StringMatch::RegexFull(r"Ba.*".into())),
+            ..Default::default()
+        };
+        let matcher = rule.compile("hit").unwrap();
+
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Bash")),
+            Some("hit")
+        );
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("NotBashToo")),
+            None
+        );
+
+        let contains_rule = HookMatch {
+            tool_name: Some(StringMatch::Regex(r"Ba.*".into())),
+            ..Default::default()
+        };
+        let contains_matcher = contains_rule.compile("hit").unwrap();
+        assert_eq!(
+            contains_matcher.evaluate(&HookContext::pre_tool_use("NotBashToo")),
+            Some("hit")
+        );
+    }
+
+    #[test]
+    fn compile_reports_an_invalid_regex_instead_of_panicking() {
+        let rule = HookMatch {
+            tool_name: Some(StringMatch::Regex("(unclosed".into())),
+            ..Default::default()
+        };
+
+        assert!(rule.compile("hit").is_err());
+    }
+
+    #[test]
+    fn trace_reports_each_field_checked_and_whether_it_matched() {
+        let rule = HookMatch {
+            tool_name: Some(StringMatch::Exact("Bash".into())),
+            arguments: Some(vec![ArgumentMatch {
+                name: "command".into(),
+                value: StringMatch::Contains("rm -rf".into()),
+            }]),
+            ..Default::default()
+        };
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "ls -la");
+
+        let trace = rule.trace(&ctx);
+
+        assert!(!trace.matched());
+        assert_eq!(trace.steps.len(), 2);
+        assert!(trace.steps[0].matched);
+        assert!(!trace.steps[1].matched);
+        assert_eq!(trace.steps[1].actual, "ls -la");
+    }
+
+    #[test]
+    fn compile_hook_matches_falls_back_to_default_action() {
+        let matcher = compile_hook_matches(
+            vec![(
+                HookMatch {
+                    tool_name: Some(StringMatch::Exact("Bash".into())),
+                    ..Default::default()
+                },
+                "block",
+            )],
+            Some("allow"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Bash")),
+            Some("block")
+        );
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Read")),
+            Some("allow")
+        );
+    }
+
+    #[test]
+    fn compile_expr_matches_any_of_two_tool_names() {
+        let matcher =
+            HookMatch::compile_expr(r#"any(tool_name = "Bash", tool_name = "Write")"#, "flag")
+                .unwrap();
+
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Bash")),
+            Some("flag")
+        );
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Write")),
+            Some("flag")
+        );
+        assert_eq!(matcher.evaluate(&HookContext::pre_tool_use("Read")), None);
+    }
+
+    #[test]
+    fn compile_expr_supports_all_and_not_and_regex() {
+        let matcher =
+            HookMatch::compile_expr(r#"all(tool_name = "Bash", not(cwd ~ "^/tmp"))"#, "flag")
+                .unwrap();
+
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/repo");
+        assert_eq!(matcher.evaluate(&ctx), Some("flag"));
+
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/tmp/scratch");
+        assert_eq!(matcher.evaluate(&ctx), None);
+    }
+
+    #[test]
+    fn compile_expr_bare_identifier_checks_presence() {
+        let matcher = HookMatch::compile_expr("tool_name", "flag").unwrap();
+
+        assert_eq!(
+            matcher.evaluate(&HookContext::pre_tool_use("Bash")),
+            Some("flag")
+        );
+    }
+
+    #[test]
+    fn compile_expr_reports_span_on_unknown_key() {
+        let err = HookMatch::compile_expr(r#"bogus = "x""#, "flag").unwrap_err();
+        assert_eq!(err.span, Span { start: 0, end: 5 });
+        assert!(err.message.contains("bogus"));
+    }
+
+    #[test]
+    fn compile_expr_reports_span_on_unbalanced_parens() {
+        let err = HookMatch::compile_expr(r#"all(tool_name = "Bash""#, "flag").unwrap_err();
+        assert!(err.message.contains("')'"));
+    }
+
+    #[test]
+    fn compile_expr_reports_invalid_regex() {
+        let err = HookMatch::compile_expr(r#"cwd ~ "(unclosed""#, "flag").unwrap_err();
+        assert!(err.message.contains("invalid regex"));
+    }
+
+    #[test]
+    fn compile_expr_supports_argument_keys() {
+        let matcher = HookMatch::compile_expr(r#"arg:command ~ "rm -rf""#, "block").unwrap();
+
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "rm -rf /");
+        assert_eq!(matcher.evaluate(&ctx), Some("block"));
+    }
+
+    #[test]
+    fn trace_to_json_redacts_a_bearer_token_by_default() {
+        let rule = HookMatch {
+            arguments: Some(vec![ArgumentMatch {
+                name: "command".into(),
+                value: StringMatch::Contains("curl".into()),
+            }]),
+            ..Default::default()
+        };
+        let ctx = HookContext::pre_tool_use("Bash").with_arg(
+            "command",
+            "curl -H 'Authorization: Bearer sk-abc123def456' example.com",
+        );
+
+        let trace = rule.trace(&ctx);
+        let json = trace.to_json();
+
+        let actual = json["steps"][0]["actual"].as_str().unwrap();
+        assert!(!actual.contains("sk-abc123def456"), "{actual}");
+        assert!(actual.contains("[REDACTED]"), "{actual}");
+    }
+
+    #[test]
+    fn trace_to_json_leaves_the_in_memory_trace_unredacted() {
+        let rule = HookMatch {
+            arguments: Some(vec![ArgumentMatch {
+                name: "command".into(),
+                value: StringMatch::Contains("curl".into()),
+            }]),
+            ..Default::default()
+        };
+        let ctx = HookContext::pre_tool_use("Bash").with_arg(
+            "command",
+            "curl -H 'Authorization: Bearer sk-abc123def456' example.com",
+        );
+
+        let trace = rule.trace(&ctx);
+        let _ = trace.to_json();
+
+        assert!(trace.steps[0].actual.contains("sk-abc123def456"));
+    }
+
+    #[test]
+    fn trace_redactor_applies_a_caller_supplied_pattern() {
+        let redactor = TraceRedactor::empty().with_pattern(r"tenant-\d+").unwrap();
+        let rule = HookMatch {
+            arguments: Some(vec![ArgumentMatch {
+                name: "command".into(),
+                value: StringMatch::Contains("echo".into()),
+            }]),
+            ..Default::default()
+        };
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "echo tenant-42");
+
+        let trace = rule.trace(&ctx);
+        let json = trace.to_json_with(&redactor);
+
+        assert_eq!(json["steps"][0]["actual"], "echo [REDACTED]");
+    }
+
+    #[test]
+    fn trace_to_json_reports_the_overall_matched_verdict() {
+        let rule = HookMatch {
+            tool_name: Some(StringMatch::Exact("Bash".into())),
+            ..Default::default()
+        };
+        let ctx = HookContext::pre_tool_use("Write");
+
+        let json = rule.trace(&ctx).to_json();
+
+        assert_eq!(json["matched"], false);
+    }
+}
@@ -23,23 +23,260 @@ impl DataInput<HookContext> for ToolNameInput {
     }
 }
 
-/// Extracts a tool argument by name.
+/// Extracts a tool argument by name, optionally descending into a nested
+/// JSON value via a path relative to that argument — `/`-separated
+/// (`0/file_path`) or `.`-separated (`0.file_path`).
+///
+/// With no path, this is exactly the original behavior: the raw string value
+/// of `ctx.argument(name)`. With a path, `name` is still looked up the same
+/// way, but its value is parsed as JSON and the path descends through object
+/// keys and array indices to an addressed scalar — so `name: "edits", path:
+/// "0/file_path"` addresses a multi-edit tool's `edits[0].file_path`.
+/// `HookContext` only exposes arguments as flat strings (see `context.rs`),
+/// so this only reaches a nested field
+/// if the tool payload that built the context stored it as a JSON-encoded
+/// string under its top-level argument name.
 #[derive(Debug, Clone)]
 pub struct ArgumentInput {
     name: String,
+    path: Option<Vec<String>>,
 }
 
 impl ArgumentInput {
-    /// Create a new argument input extractor.
+    /// Create a new argument input extractor for the flat, top-level
+    /// argument `name`.
     pub fn new(name: impl Into<String>) -> Self {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            path: None,
+        }
+    }
+
+    /// Descend into `name`'s value (parsed as JSON) via a `/`- or
+    /// `.`-separated `path` relative to it, e.g. `name: "edits", path:
+    /// "0/file_path"`.
+    #[must_use]
+    pub fn with_path(name: impl Into<String>, path: impl AsRef<str>) -> Self {
+        Self {
+            name: name.into(),
+            path: Some(split_argument_path(path.as_ref())),
+        }
     }
 }
 
 impl DataInput<HookContext> for ArgumentInput {
     fn get(&self, ctx: &HookContext) -> MatchingData {
-        ctx.argument(&self.name)
-            .map_or(MatchingData::None, |s| MatchingData::String(s.to_string()))
+        let Some(raw) = ctx.argument(&self.name) else {
+            return MatchingData::None;
+        };
+
+        let Some(path) = &self.path else {
+            return MatchingData::String(raw.to_string());
+        };
+
+        let Ok(mut value) = serde_json::from_str::<serde_json::Value>(raw) else {
+            return MatchingData::None;
+        };
+        for segment in path {
+            let Some(next) = descend_json_path(&value, segment) else {
+                return MatchingData::None;
+            };
+            value = next.clone();
+        }
+
+        match value {
+            serde_json::Value::String(s) => MatchingData::String(s),
+            serde_json::Value::Number(n) => MatchingData::String(n.to_string()),
+            serde_json::Value::Bool(b) => MatchingData::String(b.to_string()),
+            _ => MatchingData::None,
+        }
+    }
+}
+
+/// Split a `/`- or `.`-separated argument path into segments: on `/` if the
+/// path contains one, on `.` otherwise.
+fn split_argument_path(path: &str) -> Vec<String> {
+    let sep = if path.contains('/') { '/' } else { '.' };
+    path.split(sep)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Descend one path segment into a JSON value: an object key or an array
+/// index. Shared by [`ArgumentInput`]'s nested-path mode and the typed
+/// extractors below.
+fn descend_json_path<'a>(
+    value: &'a serde_json::Value,
+    segment: &str,
+) -> Option<&'a serde_json::Value> {
+    match value {
+        serde_json::Value::Object(map) => map.get(segment),
+        serde_json::Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    }
+}
+
+/// Look up `name`'s raw argument value, parse it as JSON, and descend
+/// `path` (if any) into it — the JSON-value counterpart of
+/// [`ArgumentInput::get`]'s string extraction, reused by the typed
+/// extractors below so `name: "timeout_ms"` addresses the same argument
+/// whether it's read as a string, an integer, or a bare JSON scalar.
+fn argument_json_value(
+    ctx: &HookContext,
+    name: &str,
+    path: &Option<Vec<String>>,
+) -> Option<serde_json::Value> {
+    let raw = ctx.argument(name)?;
+    let mut value: serde_json::Value = serde_json::from_str(raw).ok()?;
+    if let Some(path) = path {
+        for segment in path {
+            value = descend_json_path(&value, segment)?.clone();
+        }
+    }
+    Some(value)
+}
+
+/// Extracts a named (or nested) tool argument as a typed
+/// [`MatchingData::Integer`], for ordering/threshold matches (e.g.
+/// `timeout_ms > 5000`) that a string comparison can't express.
+///
+/// Values that overflow `i64` are parsed as `i128`/`u128` so no precision is
+/// lost, then reported as a canonical base-10 [`MatchingData::String`]
+/// instead of being silently truncated — see [`integer_matching_data`].
+#[derive(Debug, Clone)]
+pub struct IntegerInput {
+    name: String,
+    path: Option<Vec<String>>,
+}
+
+impl IntegerInput {
+    /// Create a new integer input extractor for the flat, top-level
+    /// argument `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            path: None,
+        }
+    }
+
+    /// Descend into `name`'s value (parsed as JSON) via a `/`- or
+    /// `.`-separated `path` relative to it, the same convention as
+    /// [`ArgumentInput::with_path`].
+    #[must_use]
+    pub fn with_path(name: impl Into<String>, path: impl AsRef<str>) -> Self {
+        Self {
+            name: name.into(),
+            path: Some(split_argument_path(path.as_ref())),
+        }
+    }
+}
+
+impl DataInput<HookContext> for IntegerInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        match argument_json_value(ctx, &self.name, &self.path) {
+            Some(serde_json::Value::Number(n)) => integer_matching_data(&n),
+            _ => MatchingData::None,
+        }
+    }
+}
+
+/// Converts a JSON number into [`MatchingData::Integer`] when it fits an
+/// `i64`. Otherwise the number's exact decimal text is reparsed as `i128`
+/// (or, failing that, `u128`) and reported as a canonical
+/// [`MatchingData::String`] — lossless, at the cost of falling back to
+/// string comparison for out-of-range values.
+fn integer_matching_data(n: &serde_json::Number) -> MatchingData {
+    if let Some(i) = n.as_i64() {
+        return MatchingData::Integer(i);
+    }
+    let text = n.to_string();
+    if let Ok(big) = text.parse::<i128>() {
+        return MatchingData::String(big.to_string());
+    }
+    match text.parse::<u128>() {
+        Ok(big) => MatchingData::String(big.to_string()),
+        Err(_) => MatchingData::None,
+    }
+}
+
+/// Extracts a named (or nested) tool argument as a typed
+/// [`MatchingData::Float`].
+#[derive(Debug, Clone)]
+pub struct FloatInput {
+    name: String,
+    path: Option<Vec<String>>,
+}
+
+impl FloatInput {
+    /// Create a new float input extractor for the flat, top-level argument
+    /// `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            path: None,
+        }
+    }
+
+    /// Descend into `name`'s value (parsed as JSON) via a `/`- or
+    /// `.`-separated `path` relative to it, the same convention as
+    /// [`ArgumentInput::with_path`].
+    #[must_use]
+    pub fn with_path(name: impl Into<String>, path: impl AsRef<str>) -> Self {
+        Self {
+            name: name.into(),
+            path: Some(split_argument_path(path.as_ref())),
+        }
+    }
+}
+
+impl DataInput<HookContext> for FloatInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        match argument_json_value(ctx, &self.name, &self.path) {
+            Some(serde_json::Value::Number(n)) => {
+                n.as_f64().map_or(MatchingData::None, MatchingData::Float)
+            }
+            _ => MatchingData::None,
+        }
+    }
+}
+
+/// Extracts a named (or nested) tool argument as a typed
+/// [`MatchingData::Bool`].
+#[derive(Debug, Clone)]
+pub struct BoolInput {
+    name: String,
+    path: Option<Vec<String>>,
+}
+
+impl BoolInput {
+    /// Create a new boolean input extractor for the flat, top-level
+    /// argument `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            path: None,
+        }
+    }
+
+    /// Descend into `name`'s value (parsed as JSON) via a `/`- or
+    /// `.`-separated `path` relative to it, the same convention as
+    /// [`ArgumentInput::with_path`].
+    #[must_use]
+    pub fn with_path(name: impl Into<String>, path: impl AsRef<str>) -> Self {
+        Self {
+            name: name.into(),
+            path: Some(split_argument_path(path.as_ref())),
+        }
+    }
+}
+
+impl DataInput<HookContext> for BoolInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        match argument_json_value(ctx, &self.name, &self.path) {
+            Some(serde_json::Value::Bool(b)) => MatchingData::Bool(b),
+            _ => MatchingData::None,
+        }
     }
 }
 
@@ -74,17 +311,267 @@ impl DataInput<HookContext> for GitBranchInput {
     }
 }
 
+/// Whether the working tree containing `ctx.cwd()` has uncommitted or staged
+/// changes: `MatchingData::String("dirty")` or `MatchingData::String("clean")`,
+/// or `MatchingData::None` if `cwd` is not inside a git repository.
+#[derive(Debug, Clone)]
+pub struct GitDirtyInput;
+
+impl DataInput<HookContext> for GitDirtyInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        git_status(ctx.cwd()).map_or(MatchingData::None, |status| {
+            MatchingData::String(if status.dirty { "dirty" } else { "clean" }.to_string())
+        })
+    }
+}
+
+/// Extracts the `origin` remote's fetch URL, or `None` if `cwd` is not
+/// inside a git repository or the repository has no `origin` remote.
+#[derive(Debug, Clone)]
+pub struct GitRemoteUrlInput;
+
+impl DataInput<HookContext> for GitRemoteUrlInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        git_status(ctx.cwd())
+            .and_then(|status| status.remote_url)
+            .map_or(MatchingData::None, MatchingData::String)
+    }
+}
+
+/// Extracts the paths a status walk reports as changed (untracked, modified,
+/// or staged), newline-joined, or `None` if `cwd` is not inside a git
+/// repository. `MatchingData` only carries a single scalar, so there's no
+/// list variant to report these as — joining on `\n` lets a `Contains`/`Regex`
+/// value match still gate on "did `src/` change" without this crate growing
+/// a new `MatchingData` variant.
+#[derive(Debug, Clone)]
+pub struct GitChangedFilesInput;
+
+impl DataInput<HookContext> for GitChangedFilesInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        git_status(ctx.cwd()).map_or(MatchingData::None, |status| {
+            MatchingData::String(status.changed_files.join("\n"))
+        })
+    }
+}
+
+/// Extracts the paths staged in the index (a subset of
+/// [`GitChangedFilesInput`]'s changed paths — untracked/unstaged working-tree
+/// edits don't count), newline-joined like `GitChangedFilesInput`, or `None`
+/// if `cwd` is not inside a git repository. Backed by the same
+/// [`STATUS_CACHE_TTL`]-bounded memoization as the other `Git*Input`s, so a
+/// long-running process still sees the index change within roughly that long
+/// rather than reporting whatever was staged on its first lookup forever.
+#[derive(Debug, Clone)]
+pub struct GitStagedPathsInput;
+
+impl DataInput<HookContext> for GitStagedPathsInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        git_status(ctx.cwd()).map_or(MatchingData::None, |status| {
+            MatchingData::String(status.staged_files.join("\n"))
+        })
+    }
+}
+
+/// One git2 status walk's result, shared by [`GitDirtyInput`],
+/// [`GitRemoteUrlInput`], [`GitChangedFilesInput`], and
+/// [`GitStagedPathsInput`] so evaluating all four for the same request only
+/// walks the working tree once.
+#[derive(Debug, Clone, Default)]
+struct GitStatus {
+    dirty: bool,
+    changed_files: Vec<String>,
+    staged_files: Vec<String>,
+    remote_url: Option<String>,
+}
+
+/// How long a memoized [`GitStatus`] stays valid for reuse. Long enough to
+/// cover a handful of these inputs being evaluated back-to-back for the same
+/// request (the reason this cache exists at all); short enough that a
+/// long-running hook-matcher process notices the working tree going
+/// dirty→clean (or back) within roughly this long, instead of serving the
+/// first lookup's snapshot for the rest of the process's life.
+const STATUS_CACHE_TTL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Snapshots memoized by canonicalized repository workdir — `Repository::discover`
+/// plus a status walk are real filesystem work and these inputs are evaluated
+/// per-match, so repeated lookups against the same repo (from any of the four
+/// inputs above, for any context sharing that `cwd`) reuse one computation
+/// instead of re-walking the tree each time. Entries expire after
+/// [`STATUS_CACHE_TTL`] so a process that lives across many requests still
+/// sees the tree change.
+fn status_cache(
+) -> &'static std::sync::Mutex<std::collections::HashMap<std::path::PathBuf, (GitStatus, std::time::Instant)>>
+{
+    static CACHE: std::sync::OnceLock<
+        std::sync::Mutex<
+            std::collections::HashMap<std::path::PathBuf, (GitStatus, std::time::Instant)>,
+        >,
+    > = std::sync::OnceLock::new();
+    CACHE.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()))
+}
+
+/// Discover the repository containing `cwd` and return its memoized status
+/// snapshot, or `None` if `cwd` is not inside a git repository. A cached
+/// snapshot older than [`STATUS_CACHE_TTL`] is treated as a miss and
+/// recomputed.
+fn git_status(cwd: &str) -> Option<GitStatus> {
+    let repo = git2::Repository::discover(cwd).ok()?;
+    let workdir = repo.workdir()?.canonicalize().ok()?;
+
+    if let Some((status, computed_at)) = status_cache().lock().unwrap().get(&workdir) {
+        if computed_at.elapsed() < STATUS_CACHE_TTL {
+            return Some(status.clone());
+        }
+    }
+
+    let status = compute_git_status(&repo);
+    status_cache()
+        .lock()
+        .unwrap()
+        .insert(workdir, (status.clone(), std::time::Instant::now()));
+    Some(status)
+}
+
+/// Walk `repo`'s working-tree status once, producing the snapshot
+/// [`git_status`] memoizes.
+fn compute_git_status(repo: &git2::Repository) -> GitStatus {
+    let staged = git2::Status::INDEX_NEW
+        | git2::Status::INDEX_MODIFIED
+        | git2::Status::INDEX_DELETED
+        | git2::Status::INDEX_RENAMED
+        | git2::Status::INDEX_TYPECHANGE;
+
+    let statuses = repo.statuses(None).ok();
+
+    let mut changed_files: Vec<String> = statuses
+        .as_ref()
+        .map(|statuses| {
+            statuses
+                .iter()
+                .filter(|entry| entry.status() != git2::Status::CURRENT)
+                .filter_map(|entry| entry.path().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    changed_files.sort();
+
+    let mut staged_files: Vec<String> = statuses
+        .as_ref()
+        .map(|statuses| {
+            statuses
+                .iter()
+                .filter(|entry| entry.status().intersects(staged))
+                .filter_map(|entry| entry.path().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    staged_files.sort();
+
+    let remote_url = repo
+        .find_remote("origin")
+        .ok()
+        .and_then(|remote| remote.url().map(str::to_string));
+
+    GitStatus {
+        dirty: !changed_files.is_empty(),
+        changed_files,
+        staged_files,
+        remote_url,
+    }
+}
+
+/// Maps `ctx.cwd()` to a logical monorepo group label via longest-prefix
+/// matching over a directory trie, for Bazel/monorepo-style per-subtree hook
+/// policies (e.g. "apply this rule only inside `packages/payments/`").
+///
+/// `cwd` and every configured prefix are treated as already-absolute; a
+/// trailing `/` on either is ignored, and a prefix equal to `cwd` itself is a
+/// valid (and the longest possible) match.
+#[derive(Debug, Clone)]
+pub struct ProjectGroupInput {
+    root: ProjectGroupNode,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ProjectGroupNode {
+    label: Option<String>,
+    children: std::collections::HashMap<String, ProjectGroupNode>,
+}
+
+impl ProjectGroupInput {
+    /// Build a trie from `(prefix_path, label)` entries, keyed by path
+    /// component. If two entries share the same prefix, the later one wins.
+    #[must_use]
+    pub fn new(entries: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut root = ProjectGroupNode::default();
+        for (prefix, label) in entries {
+            let mut node = &mut root;
+            for segment in split_path_segments(&prefix) {
+                node = node.children.entry(segment.to_string()).or_default();
+            }
+            node.label = Some(label);
+        }
+        Self { root }
+    }
+}
+
+impl DataInput<HookContext> for ProjectGroupInput {
+    fn get(&self, ctx: &HookContext) -> MatchingData {
+        let mut node = &self.root;
+        let mut longest = node.label.clone();
+        for segment in split_path_segments(ctx.cwd()) {
+            let Some(child) = node.children.get(segment) else {
+                break;
+            };
+            node = child;
+            if node.label.is_some() {
+                longest = node.label.clone();
+            }
+        }
+        longest.map_or(MatchingData::None, MatchingData::String)
+    }
+}
+
+/// Split an absolute path into its non-empty components, so a trailing (or
+/// leading, or doubled) `/` doesn't affect trie matching.
+fn split_path_segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Registry support (feature = "registry")
 // Hand-written config types — used when proto feature is not enabled.
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// One `(prefix, label)` entry of a [`ProjectGroupInputConfig`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize)]
+pub struct ProjectGroupEntryConfig {
+    /// The directory prefix this entry matches, e.g. `/repo/packages/payments`.
+    pub prefix: String,
+    /// The label to report when this is the longest matching prefix.
+    pub label: String,
+}
+
+/// Configuration for [`ProjectGroupInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize)]
+pub struct ProjectGroupInputConfig {
+    /// The `(prefix, label)` entries to build the trie from.
+    pub groups: Vec<ProjectGroupEntryConfig>,
+}
+
 /// Configuration for [`ArgumentInput`].
 #[cfg(all(feature = "registry", not(feature = "proto")))]
 #[derive(serde::Deserialize)]
 pub struct ArgumentInputConfig {
     /// The argument name to extract.
     pub name: String,
+    /// Optional `/`- or `.`-separated path into `name`'s value (parsed as
+    /// JSON), relative to that value — e.g. `"0/file_path"` for `edits[0].
+    /// file_path`. Omit for the original flat-string behavior.
+    pub path: Option<String>,
 }
 
 #[cfg(all(feature = "registry", not(feature = "proto")))]
@@ -116,7 +603,89 @@ impl rumi::IntoDataInput<HookContext> for ArgumentInput {
     fn from_config(
         config: Self::Config,
     ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
-        Ok(Box::new(ArgumentInput::new(config.name)))
+        let input = match config.path {
+            Some(path) => ArgumentInput::with_path(config.name, path),
+            None => ArgumentInput::new(config.name),
+        };
+        Ok(Box::new(input))
+    }
+}
+
+/// Configuration for [`IntegerInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize)]
+pub struct IntegerInputConfig {
+    /// The argument name to extract.
+    pub name: String,
+    /// Optional `/`- or `.`-separated path into `name`'s value, the same
+    /// convention as [`ArgumentInputConfig::path`].
+    pub path: Option<String>,
+}
+
+/// Configuration for [`FloatInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize)]
+pub struct FloatInputConfig {
+    /// The argument name to extract.
+    pub name: String,
+    /// Optional `/`- or `.`-separated path into `name`'s value, the same
+    /// convention as [`ArgumentInputConfig::path`].
+    pub path: Option<String>,
+}
+
+/// Configuration for [`BoolInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize)]
+pub struct BoolInputConfig {
+    /// The argument name to extract.
+    pub name: String,
+    /// Optional `/`- or `.`-separated path into `name`'s value, the same
+    /// convention as [`ArgumentInputConfig::path`].
+    pub path: Option<String>,
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for IntegerInput {
+    type Config = IntegerInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        let input = match config.path {
+            Some(path) => IntegerInput::with_path(config.name, path),
+            None => IntegerInput::new(config.name),
+        };
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for FloatInput {
+    type Config = FloatInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        let input = match config.path {
+            Some(path) => FloatInput::with_path(config.name, path),
+            None => FloatInput::new(config.name),
+        };
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for BoolInput {
+    type Config = BoolInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        let input = match config.path {
+            Some(path) => BoolInput::with_path(config.name, path),
+            None => BoolInput::new(config.name),
+        };
+        Ok(Box::new(input))
     }
 }
 
@@ -153,6 +722,62 @@ impl rumi::IntoDataInput<HookContext> for GitBranchInput {
     }
 }
 
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for GitDirtyInput {
+    type Config = rumi::UnitConfig;
+
+    fn from_config(
+        _: rumi::UnitConfig,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        Ok(Box::new(GitDirtyInput))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for GitRemoteUrlInput {
+    type Config = rumi::UnitConfig;
+
+    fn from_config(
+        _: rumi::UnitConfig,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        Ok(Box::new(GitRemoteUrlInput))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for GitChangedFilesInput {
+    type Config = rumi::UnitConfig;
+
+    fn from_config(
+        _: rumi::UnitConfig,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        Ok(Box::new(GitChangedFilesInput))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for GitStagedPathsInput {
+    type Config = rumi::UnitConfig;
+
+    fn from_config(
+        _: rumi::UnitConfig,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        Ok(Box::new(GitStagedPathsInput))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HookContext> for ProjectGroupInput {
+    type Config = ProjectGroupInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+        let entries = config.groups.into_iter().map(|e| (e.prefix, e.label));
+        Ok(Box::new(ProjectGroupInput::new(entries)))
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Proto config types (feature = "proto")
 // Uses proto-generated types as Config, enabling xDS control plane integration.
@@ -186,10 +811,71 @@ mod proto_configs {
     impl rumi::IntoDataInput<HookContext> for ArgumentInput {
         type Config = proto::ToolArgInput;
 
+        // `path` is a new field on `ToolArgInput` — proto3 string fields
+        // default to `""`, the same "absent" convention `name` already uses
+        // in the generated (de)serializer, so an empty `path` means the
+        // original flat-string lookup. Adding the field itself to the
+        // `.proto` schema and regenerating is outside this crate's sources.
+        fn from_config(
+            config: proto::ToolArgInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            let input = if config.path.is_empty() {
+                ArgumentInput::new(config.name)
+            } else {
+                ArgumentInput::with_path(config.name, config.path)
+            };
+            Ok(Box::new(input))
+        }
+    }
+
+    // `IntegerInput`/`FloatInput`/`BoolInput` share `ToolArgInput`'s `name`
+    // + `path` shape, so each reuses that message as its config rather than
+    // the `.proto` schema growing three near-identical messages — adding
+    // dedicated `IntegerArgInput`/`FloatArgInput`/`BoolArgInput` messages
+    // (if that's preferred instead) is outside this crate's sources.
+
+    impl rumi::IntoDataInput<HookContext> for IntegerInput {
+        type Config = proto::ToolArgInput;
+
+        fn from_config(
+            config: proto::ToolArgInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            let input = if config.path.is_empty() {
+                IntegerInput::new(config.name)
+            } else {
+                IntegerInput::with_path(config.name, config.path)
+            };
+            Ok(Box::new(input))
+        }
+    }
+
+    impl rumi::IntoDataInput<HookContext> for FloatInput {
+        type Config = proto::ToolArgInput;
+
+        fn from_config(
+            config: proto::ToolArgInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            let input = if config.path.is_empty() {
+                FloatInput::new(config.name)
+            } else {
+                FloatInput::with_path(config.name, config.path)
+            };
+            Ok(Box::new(input))
+        }
+    }
+
+    impl rumi::IntoDataInput<HookContext> for BoolInput {
+        type Config = proto::ToolArgInput;
+
         fn from_config(
             config: proto::ToolArgInput,
         ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
-            Ok(Box::new(ArgumentInput::new(config.name)))
+            let input = if config.path.is_empty() {
+                BoolInput::new(config.name)
+            } else {
+                BoolInput::with_path(config.name, config.path)
+            };
+            Ok(Box::new(input))
         }
     }
 
@@ -222,6 +908,52 @@ mod proto_configs {
             Ok(Box::new(GitBranchInput))
         }
     }
+
+    // `GitDirtyInput`/`GitRemoteUrlInput`/`GitChangedFilesInput` are empty
+    // messages, the same shape as `GitBranchInput` above — see the `.proto`
+    // schema these are generated from, which isn't part of this crate's
+    // sources.
+
+    impl rumi::IntoDataInput<HookContext> for GitDirtyInput {
+        type Config = proto::GitDirtyInput;
+
+        fn from_config(
+            _: proto::GitDirtyInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            Ok(Box::new(GitDirtyInput))
+        }
+    }
+
+    impl rumi::IntoDataInput<HookContext> for GitRemoteUrlInput {
+        type Config = proto::GitRemoteUrlInput;
+
+        fn from_config(
+            _: proto::GitRemoteUrlInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            Ok(Box::new(GitRemoteUrlInput))
+        }
+    }
+
+    impl rumi::IntoDataInput<HookContext> for GitChangedFilesInput {
+        type Config = proto::GitChangedFilesInput;
+
+        fn from_config(
+            _: proto::GitChangedFilesInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            Ok(Box::new(GitChangedFilesInput))
+        }
+    }
+
+    impl rumi::IntoDataInput<HookContext> for ProjectGroupInput {
+        type Config = proto::ProjectGroupInput;
+
+        fn from_config(
+            config: proto::ProjectGroupInput,
+        ) -> Result<Box<dyn rumi::DataInput<HookContext>>, rumi::MatcherError> {
+            let entries = config.groups.into_iter().map(|e| (e.prefix, e.label));
+            Ok(Box::new(ProjectGroupInput::new(entries)))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -287,6 +1019,126 @@ mod tests {
         assert_eq!(ArgumentInput::new("command").get(&ctx), MatchingData::None);
     }
 
+    #[test]
+    fn argument_input_with_path_descends_via_slash_segments() {
+        let ctx = HookContext::pre_tool_use("MultiEdit")
+            .with_arg("edits", r#"[{"file_path":"a.rs"},{"file_path":"b.rs"}]"#);
+        assert_eq!(
+            ArgumentInput::with_path("edits", "1/file_path").get(&ctx),
+            MatchingData::String("b.rs".into())
+        );
+    }
+
+    #[test]
+    fn argument_input_with_path_descends_via_dotted_segments() {
+        let ctx =
+            HookContext::pre_tool_use("MultiEdit").with_arg("edits", r#"[{"file_path":"a.rs"}]"#);
+        assert_eq!(
+            ArgumentInput::with_path("edits", "0.file_path").get(&ctx),
+            MatchingData::String("a.rs".into())
+        );
+    }
+
+    #[test]
+    fn argument_input_with_path_is_none_for_a_missing_segment() {
+        let ctx = HookContext::pre_tool_use("MultiEdit").with_arg("edits", r#"[{}]"#);
+        assert_eq!(
+            ArgumentInput::with_path("edits", "0/file_path").get(&ctx),
+            MatchingData::None
+        );
+    }
+
+    #[test]
+    fn argument_input_with_path_is_none_for_non_json_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "not json");
+        assert_eq!(
+            ArgumentInput::with_path("command", "0").get(&ctx),
+            MatchingData::None
+        );
+    }
+
+    #[test]
+    fn integer_input_returns_value() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("timeout_ms", "5000");
+        assert_eq!(
+            IntegerInput::new("timeout_ms").get(&ctx),
+            MatchingData::Integer(5000)
+        );
+    }
+
+    #[test]
+    fn integer_input_with_path_descends_into_a_nested_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("options", r#"{"timeout_ms": 1500}"#);
+        assert_eq!(
+            IntegerInput::with_path("options", "timeout_ms").get(&ctx),
+            MatchingData::Integer(1500)
+        );
+    }
+
+    #[test]
+    fn integer_input_is_none_for_missing_argument() {
+        let ctx = HookContext::pre_tool_use("Bash");
+        assert_eq!(
+            IntegerInput::new("timeout_ms").get(&ctx),
+            MatchingData::None
+        );
+    }
+
+    #[test]
+    fn integer_input_is_none_for_a_non_numeric_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("command", "ls");
+        assert_eq!(IntegerInput::new("command").get(&ctx), MatchingData::None);
+    }
+
+    #[test]
+    fn integer_input_falls_back_to_a_canonical_string_beyond_i64_range() {
+        let ctx = HookContext::pre_tool_use("Bash")
+            .with_arg("big", "99999999999999999999999999999999999999");
+        assert_eq!(
+            IntegerInput::new("big").get(&ctx),
+            MatchingData::String("99999999999999999999999999999999999999".into())
+        );
+    }
+
+    #[test]
+    fn float_input_returns_value() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("threshold", "0.5");
+        assert_eq!(
+            FloatInput::new("threshold").get(&ctx),
+            MatchingData::Float(0.5)
+        );
+    }
+
+    #[test]
+    fn float_input_is_none_for_a_non_numeric_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("threshold", "not a number");
+        assert_eq!(FloatInput::new("threshold").get(&ctx), MatchingData::None);
+    }
+
+    #[test]
+    fn bool_input_returns_value() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("dry_run", "true");
+        assert_eq!(
+            BoolInput::new("dry_run").get(&ctx),
+            MatchingData::Bool(true)
+        );
+    }
+
+    #[test]
+    fn bool_input_with_path_descends_into_a_nested_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("options", r#"{"dry_run": false}"#);
+        assert_eq!(
+            BoolInput::with_path("options", "dry_run").get(&ctx),
+            MatchingData::Bool(false)
+        );
+    }
+
+    #[test]
+    fn bool_input_is_none_for_a_non_boolean_argument() {
+        let ctx = HookContext::pre_tool_use("Bash").with_arg("dry_run", "\"yes\"");
+        assert_eq!(BoolInput::new("dry_run").get(&ctx), MatchingData::None);
+    }
+
     #[test]
     fn session_id_input() {
         let ctx = HookContext::pre_tool_use("Bash").with_session_id("abc-123");
@@ -319,4 +1171,185 @@ mod tests {
         let ctx = HookContext::pre_tool_use("Bash");
         assert_eq!(GitBranchInput.get(&ctx), MatchingData::None);
     }
+
+    /// A fresh temp directory, unique per call so parallel tests (and the
+    /// global status-cache memoization) don't collide on the same workdir.
+    fn unique_temp_dir() -> std::path::PathBuf {
+        static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!(
+            "rumi-claude-git-input-test-{}-{n}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn git_dirty_input_is_none_outside_a_repository() {
+        let dir = unique_temp_dir();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(GitDirtyInput.get(&ctx), MatchingData::None);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_dirty_input_reports_clean_for_an_empty_repo() {
+        let dir = unique_temp_dir();
+        git2::Repository::init(&dir).unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(
+            GitDirtyInput.get(&ctx),
+            MatchingData::String("clean".into())
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_dirty_input_reports_dirty_with_an_untracked_file() {
+        let dir = unique_temp_dir();
+        git2::Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("new_file.txt"), "hi").unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(
+            GitDirtyInput.get(&ctx),
+            MatchingData::String("dirty".into())
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_changed_files_input_lists_an_untracked_file() {
+        let dir = unique_temp_dir();
+        git2::Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("new_file.txt"), "hi").unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        let MatchingData::String(files) = GitChangedFilesInput.get(&ctx) else {
+            panic!("expected a list of changed files");
+        };
+        assert!(files.contains("new_file.txt"), "{files}");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_staged_paths_input_excludes_untracked_files() {
+        let dir = unique_temp_dir();
+        git2::Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("untracked.txt"), "hi").unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(
+            GitStagedPathsInput.get(&ctx),
+            MatchingData::String(String::new())
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_staged_paths_input_lists_a_staged_file() {
+        let dir = unique_temp_dir();
+        let repo = git2::Repository::init(&dir).unwrap();
+        std::fs::write(dir.join("staged.txt"), "hi").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("staged.txt")).unwrap();
+        index.write().unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        let MatchingData::String(files) = GitStagedPathsInput.get(&ctx) else {
+            panic!("expected a list of staged files");
+        };
+        assert!(files.contains("staged.txt"), "{files}");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_staged_paths_input_is_none_outside_a_repository() {
+        let dir = unique_temp_dir();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(GitStagedPathsInput.get(&ctx), MatchingData::None);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_remote_url_input_reports_the_origin_url() {
+        let dir = unique_temp_dir();
+        let repo = git2::Repository::init(&dir).unwrap();
+        repo.remote("origin", "https://example.com/repo.git")
+            .unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(
+            GitRemoteUrlInput.get(&ctx),
+            MatchingData::String("https://example.com/repo.git".into())
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn git_remote_url_input_is_none_without_an_origin_remote() {
+        let dir = unique_temp_dir();
+        git2::Repository::init(&dir).unwrap();
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd(dir.to_str().unwrap());
+
+        assert_eq!(GitRemoteUrlInput.get(&ctx), MatchingData::None);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    fn project_groups() -> ProjectGroupInput {
+        ProjectGroupInput::new([
+            ("/repo".to_string(), "default".to_string()),
+            ("/repo/packages".to_string(), "packages".to_string()),
+            (
+                "/repo/packages/payments".to_string(),
+                "payments".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn project_group_input_returns_the_longest_matching_prefix() {
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/repo/packages/payments/src");
+        assert_eq!(
+            project_groups().get(&ctx),
+            MatchingData::String("payments".into())
+        );
+    }
+
+    #[test]
+    fn project_group_input_falls_back_to_a_shorter_prefix() {
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/repo/packages/billing");
+        assert_eq!(
+            project_groups().get(&ctx),
+            MatchingData::String("packages".into())
+        );
+    }
+
+    #[test]
+    fn project_group_input_matches_a_prefix_equal_to_cwd() {
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/repo/packages/payments");
+        assert_eq!(
+            project_groups().get(&ctx),
+            MatchingData::String("payments".into())
+        );
+    }
+
+    #[test]
+    fn project_group_input_ignores_a_trailing_slash() {
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/repo/packages/payments/");
+        assert_eq!(
+            project_groups().get(&ctx),
+            MatchingData::String("payments".into())
+        );
+    }
+
+    #[test]
+    fn project_group_input_is_none_outside_every_prefix() {
+        let ctx = HookContext::pre_tool_use("Bash").with_cwd("/elsewhere");
+        assert_eq!(project_groups().get(&ctx), MatchingData::None);
+    }
 }
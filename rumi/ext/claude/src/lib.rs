@@ -58,6 +58,11 @@ pub use inputs::ArgumentInputConfig;
 /// - `xuma.claude.v1.SessionIdInput` → [`SessionIdInput`]
 /// - `xuma.claude.v1.CwdInput` → [`CwdInput`]
 /// - `xuma.claude.v1.GitBranchInput` → [`GitBranchInput`]
+/// - `xuma.claude.v1.GitDirtyInput` → [`GitDirtyInput`]
+/// - `xuma.claude.v1.GitRemoteUrlInput` → [`GitRemoteUrlInput`]
+/// - `xuma.claude.v1.GitChangedFilesInput` → [`GitChangedFilesInput`]
+/// - `xuma.claude.v1.GitStagedPathsInput` → [`GitStagedPathsInput`]
+/// - `xuma.claude.v1.ProjectGroupInput` → [`ProjectGroupInput`]
 #[cfg(feature = "registry")]
 #[must_use]
 pub fn register(builder: rumi::RegistryBuilder<HookContext>) -> rumi::RegistryBuilder<HookContext> {
@@ -68,14 +73,21 @@ pub fn register(builder: rumi::RegistryBuilder<HookContext>) -> rumi::RegistryBu
         .input::<SessionIdInput>("xuma.claude.v1.SessionIdInput")
         .input::<CwdInput>("xuma.claude.v1.CwdInput")
         .input::<GitBranchInput>("xuma.claude.v1.GitBranchInput")
+        .input::<GitDirtyInput>("xuma.claude.v1.GitDirtyInput")
+        .input::<GitRemoteUrlInput>("xuma.claude.v1.GitRemoteUrlInput")
+        .input::<GitChangedFilesInput>("xuma.claude.v1.GitChangedFilesInput")
+        .input::<GitStagedPathsInput>("xuma.claude.v1.GitStagedPathsInput")
+        .input::<ProjectGroupInput>("xuma.claude.v1.ProjectGroupInput")
 }
 
 /// Prelude for convenient imports.
 pub mod prelude {
     pub use super::{
-        compile_hook_matches, ArgumentInput, ArgumentMatch, CwdInput, EventInput, GitBranchInput,
-        HookContext, HookEvent, HookMatch, HookMatchExt, HookMatchTrace, SessionIdInput,
-        StringMatch, ToolNameInput, TraceStep,
+        compile_hook_matches, parse_hook_expr, ArgumentInput, ArgumentMatch, CwdInput,
+        EventInput, GitBranchInput, GitChangedFilesInput, GitDirtyInput, GitRemoteUrlInput,
+        GitStagedPathsInput, HookContext, HookEvent, HookExpr, HookMatch, HookMatchExt,
+        HookMatchTrace, ParseError, ProjectGroupInput, SessionIdInput, StringMatch,
+        ToolNameInput, TraceRedactor, TraceStep,
     };
     pub use rumi::prelude::*;
 }
@@ -166,6 +178,11 @@ mod proto_tests {
         assert!(registry.contains_input("xuma.claude.v1.SessionIdInput"));
         assert!(registry.contains_input("xuma.claude.v1.CwdInput"));
         assert!(registry.contains_input("xuma.claude.v1.GitBranchInput"));
+        assert!(registry.contains_input("xuma.claude.v1.GitDirtyInput"));
+        assert!(registry.contains_input("xuma.claude.v1.GitRemoteUrlInput"));
+        assert!(registry.contains_input("xuma.claude.v1.GitChangedFilesInput"));
+        assert!(registry.contains_input("xuma.claude.v1.GitStagedPathsInput"));
+        assert!(registry.contains_input("xuma.claude.v1.ProjectGroupInput"));
         assert!(registry.contains_matcher("xuma.core.v1.StringMatcher"));
     }
 
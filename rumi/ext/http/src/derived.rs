@@ -0,0 +1,68 @@
+//! Example inputs wired up via `#[derive(DataInput)]` (see `rumi_macros`)
+//! instead of a hand-written `IntoDataInput` impl plus a `register_simple`
+//! entry.
+//!
+//! [`HttpRequest`] is the `Ctx` every derived input here targets, so its
+//! `inventory` collection is declared once, in this module.
+
+use rumi::prelude::*;
+
+use crate::simple::HttpRequest;
+
+rumi_macros::inventory::collect!(rumi_macros::DataInputEntry<HttpRequest>);
+
+/// Reports whether the request's `Content-Type` header contains `json`.
+///
+/// No config, so the derive emits the [`rumi::UnitConfig`] case: this needs
+/// `Default` and nothing else to be registry-loadable under
+/// `xuma.http.v1.IsJsonRequestInput`, via [`register_derived`] rather than a
+/// `.input::<T>()` call in [`crate::simple::register_simple`].
+#[derive(Debug, Clone, Copy, Default, rumi_macros::DataInput)]
+#[data_input(ctx = HttpRequest, type_url = "xuma.http.v1.IsJsonRequestInput")]
+pub struct SimpleIsJsonRequestInput;
+
+impl DataInput<HttpRequest> for SimpleIsJsonRequestInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        MatchingData::Bool(
+            ctx.header("content-type")
+                .is_some_and(|value| value.contains("json")),
+        )
+    }
+}
+
+/// Register every `#[derive(DataInput)]` type declared for [`HttpRequest`]
+/// (currently just [`SimpleIsJsonRequestInput`]), alongside
+/// [`crate::simple::register_simple`]'s hand-wired inputs.
+#[must_use]
+pub fn register_derived(
+    builder: rumi::RegistryBuilder<HttpRequest>,
+) -> rumi::RegistryBuilder<HttpRequest> {
+    rumi_macros::register_derived(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_json_request_input_true_for_json_content_type() {
+        let req = HttpRequest::builder()
+            .header("Content-Type", "application/json")
+            .build();
+
+        assert_eq!(
+            SimpleIsJsonRequestInput.get(&req),
+            MatchingData::Bool(true)
+        );
+    }
+
+    #[test]
+    fn is_json_request_input_false_when_missing() {
+        let req = HttpRequest::builder().build();
+
+        assert_eq!(
+            SimpleIsJsonRequestInput.get(&req),
+            MatchingData::Bool(false)
+        );
+    }
+}
@@ -25,8 +25,13 @@
 //! ```
 
 // Modules always available
+mod path_template;
 mod simple;
 
+// Example `#[derive(DataInput)]` wiring (see `rumi_macros`)
+#[cfg(all(feature = "derive", feature = "registry", not(feature = "proto")))]
+mod derived;
+
 // Modules requiring ext_proc heavy deps
 #[cfg(feature = "ext-proc")]
 mod compiler;
@@ -38,15 +43,27 @@ mod inputs;
 mod message;
 
 // Simple types (always available)
+pub use path_template::PathTemplate;
 pub use simple::{
-    HttpRequest, HttpRequestBuilder, SimpleHeaderInput, SimpleMethodInput, SimplePathInput,
-    SimpleQueryParamInput,
+    BasicAuthField, HttpRequest, HttpRequestBuilder, MatchMode, SimpleBasicAuthInput,
+    SimpleBodyInput, SimpleCookieInput, SimpleHeaderAllInput, SimpleHeaderInput,
+    SimpleMethodInput, SimplePathInput, SimplePathTemplateInput, SimplePathTemplateParamInput,
+    SimpleQueryParamAllInput, SimpleQueryParamInput, SimpleRoutePatternInput,
 };
 
 // Registry for simple HttpRequest context (always available with registry)
 #[cfg(feature = "registry")]
 pub use simple::register_simple;
 
+// Derive-macro-wired inputs for simple HttpRequest context
+#[cfg(all(feature = "derive", feature = "registry", not(feature = "proto")))]
+pub use derived::{register_derived, SimpleIsJsonRequestInput};
+
+// Path-template param extraction over a loaded `MatcherConfig` (hand-written
+// config types only, same constraint as `HeaderInputConfig` below)
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+pub use simple::path_params;
+
 // ext_proc types (require ext-proc feature)
 #[cfg(feature = "ext-proc")]
 pub use compiler::*;
@@ -71,12 +88,22 @@ pub use k8s_gateway_api::{
 pub mod prelude {
     pub use super::{
         // Simple context + inputs (always available)
+        BasicAuthField,
         HttpRequest,
         HttpRequestBuilder,
+        MatchMode,
+        SimpleBasicAuthInput,
+        SimpleBodyInput,
+        SimpleCookieInput,
+        SimpleHeaderAllInput,
         SimpleHeaderInput,
         SimpleMethodInput,
         SimplePathInput,
+        SimplePathTemplateInput,
+        SimplePathTemplateParamInput,
+        SimpleQueryParamAllInput,
         SimpleQueryParamInput,
+        SimpleRoutePatternInput,
     };
 
     // ext_proc types (require ext-proc feature)
@@ -119,6 +146,10 @@ pub use inputs::{HeaderInputConfig, QueryParamInputConfig};
 /// - `xuma.http.v1.QueryParamInput` → [`QueryParamInput`]
 /// - `xuma.http.v1.SchemeInput` → [`SchemeInput`]
 /// - `xuma.http.v1.AuthorityInput` → [`AuthorityInput`]
+///
+/// There is no `BodyInput` here: nothing reads the buffered body out of the
+/// ext_proc `ProcessingRequest` body chunk into [`HttpMessage`]; see
+/// [`simple::SimpleBodyInput`] for the `HttpRequest` equivalent.
 #[cfg(all(feature = "ext-proc", feature = "registry"))]
 #[must_use]
 pub fn register(builder: rumi::RegistryBuilder<HttpMessage>) -> rumi::RegistryBuilder<HttpMessage> {
@@ -0,0 +1,234 @@
+//! Path-template compilation, the way `path-to-regex` compiles Express-style
+//! route templates: `/users/{id}/orders/{orderId}` becomes a single anchored
+//! [`regex::Regex`] with one named capture group per `{name}` token, plus the
+//! ordered list of names so callers can report "what `{id}` captured" rather
+//! than a bare numbered group.
+//!
+//! This module only compiles the template and matches a path against it —
+//! wiring the captured params into a live evaluation (the engine's `${name}`
+//! capture environment, see `rumi/core/src/capture.rs`)
+//! is the caller's job; [`SimplePathTemplateInput`](crate::simple::SimplePathTemplateInput)
+//! and [`crate::simple::path_params`] are that wiring for `HttpRequest`.
+
+use std::collections::BTreeMap;
+
+/// A compiled path template, e.g. `/users/{id}/orders/{orderId}`.
+///
+/// `{name}` matches a single path segment (`[^/]+`); `{name:pattern}` matches
+/// `pattern` instead, for cases like a greedy multi-segment capture
+/// (`{path:.*}`, as static-asset routes use). The compiled regex is anchored
+/// (`^...$`) and tolerant of a trailing `/` regardless of whether the
+/// template itself has one.
+#[derive(Debug, Clone)]
+pub struct PathTemplate {
+    regex: regex::Regex,
+    keys: Vec<String>,
+}
+
+impl PathTemplate {
+    /// Compile `template`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `template` declares an empty `{}` name, the
+    /// same name more than once (ambiguous which segment it should bind to),
+    /// or a `{name:pattern}` whose `pattern` isn't a valid regex fragment.
+    pub fn compile(template: &str) -> Result<Self, String> {
+        let mut pattern = String::from("^");
+        let mut keys: Vec<String> = Vec::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '{' {
+                pattern.push_str(&regex::escape(&c.to_string()));
+                continue;
+            }
+
+            let mut token = String::new();
+            let mut closed = false;
+            for c in chars.by_ref() {
+                if c == '}' {
+                    closed = true;
+                    break;
+                }
+                token.push(c);
+            }
+            if !closed {
+                return Err(format!(
+                    "path template {template:?} has an unclosed {{ placeholder"
+                ));
+            }
+
+            let (name, segment_pattern) = match token.split_once(':') {
+                Some((name, pat)) => (name, pat),
+                None => (token.as_str(), "[^/]+"),
+            };
+            if name.is_empty() {
+                return Err(format!(
+                    "path template {template:?} has an empty parameter name"
+                ));
+            }
+            if keys.iter().any(|k| k == name) {
+                return Err(format!(
+                    "path template {template:?} declares {name:?} more than once"
+                ));
+            }
+
+            regex::Regex::new(segment_pattern).map_err(|e| {
+                format!("path template {template:?}: invalid pattern for {name:?}: {e}")
+            })?;
+
+            keys.push(name.to_string());
+            pattern.push_str(&format!("(?P<{name}>{segment_pattern})"));
+        }
+
+        // Tolerate a trailing `/` on either side: drop one from the compiled
+        // pattern if the template ended with one, then make it optional.
+        if let Some(stripped) = pattern.strip_suffix('/') {
+            pattern = stripped.to_string();
+        }
+        pattern.push_str("/?$");
+
+        let regex = regex::Regex::new(&pattern).map_err(|e| {
+            format!("path template {template:?} compiled to invalid regex {pattern:?}: {e}")
+        })?;
+
+        Ok(Self { regex, keys })
+    }
+
+    /// Whether `path` matches this template.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path)
+    }
+
+    /// Match `path` against this template, returning the captured, percent-decoded
+    /// parameters keyed by name, or `None` if `path` doesn't match.
+    #[must_use]
+    pub fn params(&self, path: &str) -> Option<BTreeMap<String, String>> {
+        let caps = self.regex.captures(path)?;
+        Some(
+            self.keys
+                .iter()
+                .filter_map(|key| {
+                    caps.name(key)
+                        .map(|m| (key.clone(), percent_decode(m.as_str())))
+                })
+                .collect(),
+        )
+    }
+
+    /// The parameter names this template declares, in template order.
+    #[must_use]
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+/// Decode `%XX` escapes in a captured path segment. An escape that isn't
+/// valid hex is left verbatim — a malformed escape is a client bug, not a
+/// reason to fail a match that already succeeded.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 3 <= bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).expect("ASCII hex digits");
+            out.push(u8::from_str_radix(hex, 16).expect("validated hex digits"));
+            i += 3;
+            continue;
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_literal_template() {
+        let template = PathTemplate::compile("/health").unwrap();
+        assert!(template.matches("/health"));
+        assert!(!template.matches("/healthz"));
+    }
+
+    #[test]
+    fn captures_a_single_named_segment() {
+        let template = PathTemplate::compile("/users/{id}").unwrap();
+        let params = template.params("/users/42").unwrap();
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn captures_multiple_named_segments_in_order() {
+        let template = PathTemplate::compile("/users/{id}/orders/{orderId}").unwrap();
+        assert_eq!(template.keys(), &["id".to_string(), "orderId".to_string()]);
+
+        let params = template.params("/users/42/orders/99").unwrap();
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+        assert_eq!(params.get("orderId"), Some(&"99".to_string()));
+    }
+
+    #[test]
+    fn a_segment_does_not_cross_a_slash_by_default() {
+        let template = PathTemplate::compile("/users/{id}").unwrap();
+        assert!(!template.matches("/users/42/orders"));
+    }
+
+    #[test]
+    fn custom_pattern_allows_a_greedy_multi_segment_capture() {
+        let template = PathTemplate::compile("/static/{path:.*}").unwrap();
+        let params = template.params("/static/css/app.css").unwrap();
+        assert_eq!(params.get("path"), Some(&"css/app.css".to_string()));
+    }
+
+    #[test]
+    fn rejects_a_duplicate_parameter_name() {
+        let err = PathTemplate::compile("/users/{id}/teams/{id}").unwrap_err();
+        assert!(err.contains("more than once"), "{err}");
+    }
+
+    #[test]
+    fn rejects_an_empty_parameter_name() {
+        let err = PathTemplate::compile("/users/{}").unwrap_err();
+        assert!(err.contains("empty parameter name"), "{err}");
+    }
+
+    #[test]
+    fn rejects_an_invalid_custom_pattern() {
+        let err = PathTemplate::compile("/users/{id:(}").unwrap_err();
+        assert!(err.contains("invalid pattern"), "{err}");
+    }
+
+    #[test]
+    fn matches_with_and_without_a_trailing_slash() {
+        let template = PathTemplate::compile("/users/{id}/").unwrap();
+        assert!(template.matches("/users/42"));
+        assert!(template.matches("/users/42/"));
+
+        let template = PathTemplate::compile("/users/{id}").unwrap();
+        assert!(template.matches("/users/42"));
+        assert!(template.matches("/users/42/"));
+    }
+
+    #[test]
+    fn percent_decodes_captured_values() {
+        let template = PathTemplate::compile("/search/{query}").unwrap();
+        let params = template.params("/search/a%20b").unwrap();
+        assert_eq!(params.get("query"), Some(&"a b".to_string()));
+    }
+
+    #[test]
+    fn no_match_returns_none() {
+        let template = PathTemplate::compile("/users/{id}").unwrap();
+        assert!(template.params("/teams/42").is_none());
+    }
+}
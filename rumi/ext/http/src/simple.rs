@@ -2,19 +2,30 @@
 //!
 //! This is a lightweight context for when you don't need full `ext_proc`.
 
+use base64::Engine;
 use rumi::prelude::*;
 use std::collections::HashMap;
 
+use crate::path_template::PathTemplate;
+
 /// Simple HTTP request context for matching.
 ///
 /// Use this for testing or simple use cases. For production `ext_proc`
 /// integration, use [`HttpMessage`](crate::HttpMessage) instead.
+///
+/// Headers and query params are stored as `Vec<String>` per key — a request
+/// can repeat a header (`Accept: text/html`, `Accept: application/json`) or a
+/// query param (`?tag=a&tag=b`), and a single collapsed value would silently
+/// drop the rest. [`header`](HttpRequest::header)/[`query_param`](HttpRequest::query_param)
+/// return the first value for simple lookups; [`headers`](HttpRequest::headers)/
+/// [`query_params`](HttpRequest::query_params) return every value.
 #[derive(Debug, Clone, Default)]
 pub struct HttpRequest {
     method: String,
     path: String,
-    headers: HashMap<String, String>,
-    query_params: HashMap<String, String>,
+    headers: HashMap<String, Vec<String>>,
+    query_params: HashMap<String, Vec<String>>,
+    body: Vec<u8>,
 }
 
 impl HttpRequest {
@@ -36,16 +47,38 @@ impl HttpRequest {
         &self.path
     }
 
-    /// Get a header value by name (case-insensitive).
+    /// Get the first header value by name (case-insensitive).
     #[must_use]
     pub fn header(&self, name: &str) -> Option<&str> {
-        self.headers.get(&name.to_lowercase()).map(String::as_str)
+        self.headers(name).first().map(String::as_str)
+    }
+
+    /// Get every value of a header by name (case-insensitive), in the order
+    /// they were added. Empty if the header wasn't set.
+    #[must_use]
+    pub fn headers(&self, name: &str) -> &[String] {
+        self.headers
+            .get(&name.to_lowercase())
+            .map_or(&[], Vec::as_slice)
     }
 
-    /// Get a query parameter by name.
+    /// Get the first query parameter value by name.
     #[must_use]
     pub fn query_param(&self, name: &str) -> Option<&str> {
-        self.query_params.get(name).map(String::as_str)
+        self.query_params(name).first().map(String::as_str)
+    }
+
+    /// Get every value of a query parameter by name, in the order they were
+    /// added. Empty if the query parameter wasn't set.
+    #[must_use]
+    pub fn query_params(&self, name: &str) -> &[String] {
+        self.query_params.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the raw request body bytes. Empty if the body wasn't set.
+    #[must_use]
+    pub fn body(&self) -> &[u8] {
+        &self.body
     }
 }
 
@@ -70,19 +103,36 @@ impl HttpRequestBuilder {
         self
     }
 
-    /// Add a header (name is lowercased for case-insensitive lookup).
+    /// Add a header value (name is lowercased for case-insensitive lookup).
+    /// Repeated calls for the same name append rather than overwrite — use
+    /// this to model a repeated header like multiple `Accept` values.
     #[must_use]
     pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
         self.request
             .headers
-            .insert(name.into().to_lowercase(), value.into());
+            .entry(name.into().to_lowercase())
+            .or_default()
+            .push(value.into());
         self
     }
 
-    /// Add a query parameter.
+    /// Add a query parameter value. Repeated calls for the same name append
+    /// rather than overwrite — use this to model a repeated query param like
+    /// `?tag=a&tag=b`.
     #[must_use]
     pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
-        self.request.query_params.insert(name.into(), value.into());
+        self.request
+            .query_params
+            .entry(name.into())
+            .or_default()
+            .push(value.into());
+        self
+    }
+
+    /// Set the request body.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.request.body = body.into();
         self
     }
 
@@ -115,45 +165,599 @@ impl DataInput<HttpRequest> for SimplePathInput {
     }
 }
 
+/// Whether a multi-valued [`SimpleHeaderInput`]/[`SimpleQueryParamInput`]
+/// requires any or all of a name's values to satisfy the configured
+/// [`rumi::StringMatchSpec`]. Defaults to `Any`, matching the pre-existing
+/// single-value behavior (a name with one value matches iff that value does).
+///
+/// A [`DataInput`] reports exactly one [`MatchingData`] per evaluation, and
+/// the engine that would otherwise apply a predicate's `value_match` to that
+/// one value lives outside this crate's sources (see
+/// [`crate::path_template`] for the same constraint). So unlike a
+/// single-valued header/query-param match, where `value_match` is a sibling
+/// field on the predicate, here the input itself has to carry a copy of the
+/// spec and decide any/all internally — [`DataInput::get`] reports
+/// [`MatchingData::String`] of the header/query-param name when the mode is
+/// satisfied, [`MatchingData::None`] otherwise. Pair it with an always-true
+/// outer `value_match` (e.g. `{"Regex": ".*"}`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    all(feature = "registry", not(feature = "proto")),
+    derive(serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "registry", not(feature = "proto")),
+    serde(rename_all = "lowercase")
+)]
+pub enum MatchMode {
+    #[default]
+    Any,
+    All,
+}
+
+impl MatchMode {
+    /// Validate that `value_match`'s pattern compiles, turning a malformed
+    /// config into a load-time error instead of a silently-false match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `value_match` is a `Regex` with an invalid
+    /// pattern.
+    pub fn validate(self, value_match: &rumi::StringMatchSpec) -> Result<(), String> {
+        if let rumi::StringMatchSpec::Regex(pattern) = value_match {
+            regex::Regex::new(pattern).map_err(|e| format!("invalid regex {pattern:?}: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// Whether `values` satisfies this mode against `value_match` — `Any`
+    /// matches if at least one value does, `All` requires every value to
+    /// (and is `false` for no values, same as `Any`).
+    #[must_use]
+    fn matches(self, values: &[String], value_match: &rumi::StringMatchSpec) -> bool {
+        if values.is_empty() {
+            return false;
+        }
+        let mut satisfied = values
+            .iter()
+            .map(|v| string_match_spec_matches(value_match, v));
+        match self {
+            MatchMode::Any => satisfied.any(|ok| ok),
+            MatchMode::All => satisfied.all(|ok| ok),
+        }
+    }
+}
+
+/// Evaluate a [`rumi::StringMatchSpec`] against `value`, the way the engine's
+/// own (out-of-crate) value-match step would for a single-valued input.
+fn string_match_spec_matches(spec: &rumi::StringMatchSpec, value: &str) -> bool {
+    match spec {
+        rumi::StringMatchSpec::Exact(s) => value == s,
+        rumi::StringMatchSpec::Prefix(s) => value.starts_with(s.as_str()),
+        rumi::StringMatchSpec::Suffix(s) => value.ends_with(s.as_str()),
+        rumi::StringMatchSpec::Contains(s) => value.contains(s.as_str()),
+        rumi::StringMatchSpec::Regex(pattern) => regex::Regex::new(pattern)
+            .map(|re| re.is_match(value))
+            .unwrap_or(false),
+    }
+}
+
 /// Extracts a header from simple `HttpRequest`.
+///
+/// A name can repeat (multiple `Accept` headers); [`SimpleHeaderInput::get`]
+/// reports the first value, same as [`HttpRequest::header`]. To match against
+/// every value instead — "any `Accept` is JSON" vs "all `Accept` values are
+/// JSON" — build with [`SimpleHeaderInput::with_mode`] instead; see
+/// [`MatchMode`] for why the value match has to be baked into the input
+/// rather than applied by the engine per value.
 #[derive(Debug, Clone)]
 pub struct SimpleHeaderInput {
     name: String,
+    mode_match: Option<(MatchMode, rumi::StringMatchSpec)>,
 }
 
 impl SimpleHeaderInput {
-    /// Create a header input for the given name (case-insensitive).
+    /// Create a header input for the given name (case-insensitive), reporting
+    /// the first value only.
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into().to_lowercase(),
+            mode_match: None,
         }
     }
+
+    /// Create a header input that reports whether `mode` of the header's
+    /// values satisfy `value_match`.
+    ///
+    /// # Errors
+    ///
+    /// See [`MatchMode::validate`].
+    pub fn with_mode(
+        name: impl Into<String>,
+        mode: MatchMode,
+        value_match: rumi::StringMatchSpec,
+    ) -> Result<Self, String> {
+        mode.validate(&value_match)?;
+        Ok(Self {
+            name: name.into().to_lowercase(),
+            mode_match: Some((mode, value_match)),
+        })
+    }
 }
 
 impl DataInput<HttpRequest> for SimpleHeaderInput {
     fn get(&self, ctx: &HttpRequest) -> MatchingData {
-        ctx.header(&self.name)
-            .map_or(MatchingData::None, |s| MatchingData::String(s.to_string()))
+        match &self.mode_match {
+            None => ctx
+                .header(&self.name)
+                .map_or(MatchingData::None, |s| MatchingData::String(s.to_string())),
+            Some((mode, value_match)) => {
+                if mode.matches(ctx.headers(&self.name), value_match) {
+                    MatchingData::String(self.name.clone())
+                } else {
+                    MatchingData::None
+                }
+            }
+        }
     }
 }
 
 /// Extracts a query parameter from simple `HttpRequest`.
+///
+/// A name can repeat (`?tag=a&tag=b`); [`SimpleQueryParamInput::get`] reports
+/// the first value, same as [`HttpRequest::query_param`]. To match against
+/// every value instead, build with [`SimpleQueryParamInput::with_mode`]; see
+/// [`MatchMode`].
 #[derive(Debug, Clone)]
 pub struct SimpleQueryParamInput {
     name: String,
+    mode_match: Option<(MatchMode, rumi::StringMatchSpec)>,
 }
 
 impl SimpleQueryParamInput {
-    /// Create a query param input for the given name.
+    /// Create a query param input for the given name, reporting the first
+    /// value only.
     pub fn new(name: impl Into<String>) -> Self {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            mode_match: None,
+        }
+    }
+
+    /// Create a query param input that reports whether `mode` of the query
+    /// parameter's values satisfy `value_match`.
+    ///
+    /// # Errors
+    ///
+    /// See [`MatchMode::validate`].
+    pub fn with_mode(
+        name: impl Into<String>,
+        mode: MatchMode,
+        value_match: rumi::StringMatchSpec,
+    ) -> Result<Self, String> {
+        mode.validate(&value_match)?;
+        Ok(Self {
+            name: name.into(),
+            mode_match: Some((mode, value_match)),
+        })
     }
 }
 
 impl DataInput<HttpRequest> for SimpleQueryParamInput {
     fn get(&self, ctx: &HttpRequest) -> MatchingData {
-        ctx.query_param(&self.name)
-            .map_or(MatchingData::None, |s| MatchingData::String(s.to_string()))
+        match &self.mode_match {
+            None => ctx
+                .query_param(&self.name)
+                .map_or(MatchingData::None, |s| MatchingData::String(s.to_string())),
+            Some((mode, value_match)) => {
+                if mode.matches(ctx.query_params(&self.name), value_match) {
+                    MatchingData::String(self.name.clone())
+                } else {
+                    MatchingData::None
+                }
+            }
+        }
+    }
+}
+
+/// Joins multiple values with this separator for [`SimpleHeaderAllInput`] and
+/// [`SimpleQueryParamAllInput`], since neither value can legally contain it.
+const ALL_VALUES_SEPARATOR: char = '\n';
+
+/// Extracts every value of a header from simple `HttpRequest`, e.g. every
+/// `Set-Cookie` or repeated `Accept`.
+///
+/// [`MatchingData`] has no list variant, and the engine that would apply a
+/// per-value `value_match` lives outside this crate's sources (see
+/// [`SimpleHeaderInput::with_mode`] for the same constraint). Rather than
+/// pick one value, [`SimpleHeaderAllInput::get`] reports every value joined
+/// with `\n` as a single [`MatchingData::String`] — lossless as long as no
+/// value itself contains a newline, the same trade-off
+/// [`MatchingData::Integer`]'s big-number fallback makes for precision. A
+/// `Contains`/`Regex` `value_match` can then test across all values at once;
+/// use [`SimpleHeaderInput::with_mode`] instead if you need strict any/all
+/// semantics against a single pattern.
+#[derive(Debug, Clone)]
+pub struct SimpleHeaderAllInput {
+    name: String,
+}
+
+impl SimpleHeaderAllInput {
+    /// Create an all-values header input for the given name (case-insensitive).
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into().to_lowercase(),
+        }
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleHeaderAllInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        let values = ctx.headers(&self.name);
+        if values.is_empty() {
+            return MatchingData::None;
+        }
+        MatchingData::String(values.join(&ALL_VALUES_SEPARATOR.to_string()))
+    }
+}
+
+/// Extracts every value of a query parameter from simple `HttpRequest`, e.g.
+/// every `tag` in `?tag=a&tag=b`. See [`SimpleHeaderAllInput`] for the
+/// newline-joined representation and why it exists.
+#[derive(Debug, Clone)]
+pub struct SimpleQueryParamAllInput {
+    name: String,
+}
+
+impl SimpleQueryParamAllInput {
+    /// Create an all-values query param input for the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleQueryParamAllInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        let values = ctx.query_params(&self.name);
+        if values.is_empty() {
+            return MatchingData::None;
+        }
+        MatchingData::String(values.join(&ALL_VALUES_SEPARATOR.to_string()))
+    }
+}
+
+/// Extracts a single cookie's value from the `Cookie` request header.
+///
+/// Matching on one cookie via [`SimpleHeaderInput`] means regexing the whole
+/// `Cookie` header, which is fragile once more than one cookie is present.
+/// This parses the header into name/value pairs instead — splitting on `;`,
+/// trimming surrounding whitespace from each pair, then splitting each pair
+/// on its first `=` — and reports the value for the requested name.
+///
+/// A name repeated across pairs (a client sending the same cookie twice)
+/// reports the first occurrence, matching how most HTTP stacks resolve the
+/// ambiguity. Values are reported exactly as they appear on the wire — a
+/// cookie value is opaque, so this never percent-decodes it.
+#[derive(Debug, Clone)]
+pub struct SimpleCookieInput {
+    name: String,
+}
+
+impl SimpleCookieInput {
+    /// Create a cookie input for the given name (case-sensitive, matching
+    /// the `Cookie` header's own name comparison).
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleCookieInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        let Some(header) = ctx.header("cookie") else {
+            return MatchingData::None;
+        };
+        header
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == self.name)
+            .map_or(MatchingData::None, |(_, value)| {
+                MatchingData::String(value.to_string())
+            })
+    }
+}
+
+/// Which part of an HTTP Basic credential [`SimpleBasicAuthInput`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    all(feature = "registry", not(feature = "proto")),
+    derive(serde::Deserialize)
+)]
+#[cfg_attr(
+    all(feature = "registry", not(feature = "proto")),
+    serde(rename_all = "lowercase")
+)]
+pub enum BasicAuthField {
+    /// The decoded username, the part of `user:pass` before the first `:`.
+    Username,
+    /// The decoded password, the part of `user:pass` after the first `:`.
+    Password,
+    /// The full decoded `user:pass` credential, undivided.
+    Raw,
+}
+
+/// Extracts a username, password, or raw credential from an HTTP Basic
+/// `Authorization` header.
+///
+/// Reads the `Authorization` header, checks for the `Basic ` scheme prefix,
+/// base64-decodes the remainder, and splits the result on its first `:` into
+/// a username and password — same shape as [`SimpleCookieInput`] sparing
+/// callers from hand-rolling base64 decoding in a [`SimpleHeaderInput`]
+/// regex. A missing header, a non-`Basic` scheme, or invalid base64 all
+/// report [`MatchingData::None`] rather than an error — an absent or
+/// malformed credential and "no match" aren't distinguishable to a
+/// predicate tree.
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleBasicAuthInput {
+    field: BasicAuthField,
+}
+
+impl SimpleBasicAuthInput {
+    /// Create a Basic-auth input reporting `field` of the decoded credential.
+    #[must_use]
+    pub fn new(field: BasicAuthField) -> Self {
+        Self { field }
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleBasicAuthInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        let Some(header) = ctx.header("authorization") else {
+            return MatchingData::None;
+        };
+        let Some(encoded) = header.strip_prefix("Basic ") else {
+            return MatchingData::None;
+        };
+        let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(encoded.trim()) else {
+            return MatchingData::None;
+        };
+        let Ok(credential) = String::from_utf8(decoded) else {
+            return MatchingData::None;
+        };
+        match self.field {
+            BasicAuthField::Raw => MatchingData::String(credential),
+            BasicAuthField::Username => credential
+                .split_once(':')
+                .map_or(MatchingData::None, |(user, _)| {
+                    MatchingData::String(user.to_string())
+                }),
+            BasicAuthField::Password => credential
+                .split_once(':')
+                .map_or(MatchingData::None, |(_, pass)| {
+                    MatchingData::String(pass.to_string())
+                }),
+        }
+    }
+}
+
+/// Matches the request path against a template like `/users/{id}`.
+///
+/// `.get()` only reports whether `path` matched — a single [`DataInput`] has
+/// one value to report, and the engine's `${name}` capture binding is keyed
+/// off a predicate's own `capture` name, not off however many `{name}`
+/// segments a template declares. The captured segments themselves come from
+/// [`SimplePathTemplateInput::params`] (or [`path_params`], for a whole
+/// compiled matcher config), called separately against the same request path.
+#[derive(Debug, Clone)]
+pub struct SimplePathTemplateInput {
+    template: PathTemplate,
+}
+
+impl SimplePathTemplateInput {
+    /// Compile a path template input directly.
+    ///
+    /// # Errors
+    ///
+    /// See [`PathTemplate::compile`].
+    pub fn new(template: &str) -> Result<Self, String> {
+        Ok(Self {
+            template: PathTemplate::compile(template)?,
+        })
+    }
+
+    /// The parameters captured by matching `path` against this input's
+    /// template, or `None` if `path` doesn't match.
+    #[must_use]
+    pub fn params(&self, path: &str) -> Option<std::collections::BTreeMap<String, String>> {
+        self.template.params(path)
+    }
+}
+
+impl DataInput<HttpRequest> for SimplePathTemplateInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        if self.template.matches(&ctx.path) {
+            MatchingData::String(ctx.path.clone())
+        } else {
+            MatchingData::None
+        }
+    }
+}
+
+/// Extracts a single named capture from matching the request path against a
+/// template, e.g. report `"42"` for `param = "id"` against template
+/// `/users/{id}` and path `/users/42`.
+///
+/// [`SimplePathTemplateInput::get`] only reports whether the whole path
+/// matched, so the `{id}` segment itself could previously only be read back
+/// via [`SimplePathTemplateInput::params`]/[`path_params`], called separately
+/// alongside evaluation. This input puts a single captured segment directly
+/// into the predicate tree instead, so a sibling predicate's `value_match`
+/// can test it like any other string input.
+#[derive(Debug, Clone)]
+pub struct SimplePathTemplateParamInput {
+    template: PathTemplate,
+    param: String,
+}
+
+impl SimplePathTemplateParamInput {
+    /// Compile `template` and bind to extracting its `param` capture.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `template` fails to compile (see
+    /// [`PathTemplate::compile`]) or doesn't declare a `{param}` segment.
+    pub fn new(template: &str, param: impl Into<String>) -> Result<Self, String> {
+        let compiled = PathTemplate::compile(template)?;
+        let param = param.into();
+        if !compiled.keys().iter().any(|key| key == &param) {
+            return Err(format!(
+                "path template {template:?} does not declare parameter {param:?}"
+            ));
+        }
+        Ok(Self {
+            template: compiled,
+            param,
+        })
+    }
+}
+
+impl DataInput<HttpRequest> for SimplePathTemplateParamInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        self.template
+            .params(&ctx.path)
+            .and_then(|params| params.get(&self.param).cloned())
+            .map_or(MatchingData::None, MatchingData::String)
+    }
+}
+
+/// Matches the request path against a route template like
+/// `/users/{id}/files/{rest:.*}`, folding what [`SimplePathTemplateInput`] and
+/// [`SimplePathTemplateParamInput`] do separately into one type: with no
+/// capture bound it's a whole-path boolean predicate, and with one bound (via
+/// [`SimpleRoutePatternInput::with_capture`]) it reports that segment instead.
+/// A trailing `{name:.*}` greedily captures the remainder of the path,
+/// slashes included; see [`PathTemplate`] for the underlying compiler.
+#[derive(Debug, Clone)]
+pub struct SimpleRoutePatternInput {
+    template: PathTemplate,
+    capture: Option<String>,
+}
+
+impl SimpleRoutePatternInput {
+    /// Compile a route pattern that reports whether the whole path matched.
+    ///
+    /// # Errors
+    ///
+    /// See [`PathTemplate::compile`].
+    pub fn new(template: &str) -> Result<Self, String> {
+        Ok(Self {
+            template: PathTemplate::compile(template)?,
+            capture: None,
+        })
+    }
+
+    /// Compile a route pattern bound to reporting its `capture` segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if `template` fails to compile (see
+    /// [`PathTemplate::compile`]) or doesn't declare a `{capture}` segment.
+    pub fn with_capture(template: &str, capture: impl Into<String>) -> Result<Self, String> {
+        let compiled = PathTemplate::compile(template)?;
+        let capture = capture.into();
+        if !compiled.keys().iter().any(|key| key == &capture) {
+            return Err(format!(
+                "path template {template:?} does not declare parameter {capture:?}"
+            ));
+        }
+        Ok(Self {
+            template: compiled,
+            capture: Some(capture),
+        })
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleRoutePatternInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        match &self.capture {
+            None => {
+                if self.template.matches(&ctx.path) {
+                    MatchingData::String(ctx.path.clone())
+                } else {
+                    MatchingData::None
+                }
+            }
+            Some(name) => self
+                .template
+                .params(&ctx.path)
+                .and_then(|params| params.get(name).cloned())
+                .map_or(MatchingData::None, MatchingData::String),
+        }
+    }
+}
+
+/// Extracts a scalar from the request body via an RFC 6901 JSON pointer, e.g.
+/// `/event/type`.
+///
+/// With no pointer, reports the raw body decoded as UTF-8. With a pointer,
+/// parses the body as JSON and resolves it via [`serde_json::Value::pointer`];
+/// a missing/non-JSON body, a pointer into a missing key, or a pointer that
+/// resolves to a non-scalar (array/object/null) all report
+/// [`MatchingData::None`] — there's no JSON value to stringify. `max_bytes`
+/// bounds how much of the body this input is willing to parse, so a huge
+/// body can't be turned into unbounded JSON-parsing work.
+#[derive(Debug, Clone, Default)]
+pub struct SimpleBodyInput {
+    pointer: Option<String>,
+    max_bytes: Option<usize>,
+}
+
+impl SimpleBodyInput {
+    /// Extract the raw body as UTF-8, with no size cap.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Extract the scalar at `pointer` (RFC 6901, e.g. `/event/type`) from the
+    /// body parsed as JSON.
+    #[must_use]
+    pub fn with_pointer(pointer: impl Into<String>) -> Self {
+        Self {
+            pointer: Some(pointer.into()),
+            max_bytes: None,
+        }
+    }
+
+    /// Cap how many body bytes this input will read; bodies larger than
+    /// `max_bytes` report [`MatchingData::None`] instead of being parsed.
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+}
+
+impl DataInput<HttpRequest> for SimpleBodyInput {
+    fn get(&self, ctx: &HttpRequest) -> MatchingData {
+        let body = ctx.body();
+        if self.max_bytes.is_some_and(|max| body.len() > max) {
+            return MatchingData::None;
+        }
+
+        let Some(pointer) = &self.pointer else {
+            return std::str::from_utf8(body)
+                .map_or(MatchingData::None, |s| MatchingData::String(s.to_string()));
+        };
+
+        let Ok(value) = serde_json::from_slice::<serde_json::Value>(body) else {
+            return MatchingData::None;
+        };
+        match value.pointer(pointer) {
+            Some(serde_json::Value::String(s)) => MatchingData::String(s.clone()),
+            Some(serde_json::Value::Number(n)) => MatchingData::String(n.to_string()),
+            Some(serde_json::Value::Bool(b)) => MatchingData::String(b.to_string()),
+            _ => MatchingData::None,
+        }
     }
 }
 
@@ -163,19 +767,113 @@ impl DataInput<HttpRequest> for SimpleQueryParamInput {
 // ═══════════════════════════════════════════════════════════════════════════════
 
 /// Configuration for [`SimpleHeaderInput`].
+///
+/// `mode`/`value_match` are optional — omitting both keeps the pre-existing
+/// single-value behavior (`get()` reports the first value). Set both to
+/// match against every value instead; see [`MatchMode`].
 #[cfg(all(feature = "registry", not(feature = "proto")))]
 #[derive(serde::Deserialize)]
 pub struct SimpleHeaderInputConfig {
     /// The header name to extract (case-insensitive).
     pub name: String,
+    /// Whether `any` or `all` of the header's values must satisfy
+    /// `value_match`. Defaults to `any`.
+    #[serde(default)]
+    pub mode: MatchMode,
+    /// The value match every value is tested against when `mode` applies.
+    /// Required if `mode` is `all`; ignored (first-value lookup) if absent.
+    pub value_match: Option<rumi::StringMatchSpec>,
 }
 
 /// Configuration for [`SimpleQueryParamInput`].
+///
+/// `mode`/`value_match` are optional — see [`SimpleHeaderInputConfig`].
 #[cfg(all(feature = "registry", not(feature = "proto")))]
 #[derive(serde::Deserialize)]
 pub struct SimpleQueryParamInputConfig {
     /// The query parameter name to extract.
     pub name: String,
+    /// Whether `any` or `all` of the query parameter's values must satisfy
+    /// `value_match`. Defaults to `any`.
+    #[serde(default)]
+    pub mode: MatchMode,
+    /// The value match every value is tested against when `mode` applies.
+    pub value_match: Option<rumi::StringMatchSpec>,
+}
+
+/// Configuration for [`SimpleHeaderAllInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimpleHeaderAllInputConfig {
+    /// The header name (case-insensitive).
+    pub name: String,
+}
+
+/// Configuration for [`SimpleQueryParamAllInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimpleQueryParamAllInputConfig {
+    /// The query parameter name.
+    pub name: String,
+}
+
+/// Configuration for [`SimpleCookieInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimpleCookieInputConfig {
+    /// The cookie name to extract (case-sensitive).
+    pub name: String,
+}
+
+/// Configuration for [`SimpleBasicAuthInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimpleBasicAuthInputConfig {
+    /// Which part of the decoded credential to report.
+    pub field: BasicAuthField,
+}
+
+/// Configuration for [`SimplePathTemplateInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimplePathTemplateInputConfig {
+    /// The path template, e.g. `/users/{id}/orders/{orderId}`.
+    pub template: String,
+}
+
+/// Configuration for [`SimplePathTemplateParamInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimplePathTemplateParamInputConfig {
+    /// The path template, e.g. `/users/{id}/orders/{orderId}`.
+    pub template: String,
+    /// The name of the captured segment to extract, e.g. `id`.
+    pub param: String,
+}
+
+/// Configuration for [`SimpleRoutePatternInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Clone)]
+pub struct SimpleRoutePatternInputConfig {
+    /// The route template, e.g. `/users/{id}/files/{rest:.*}`.
+    pub template: String,
+    /// The name of the captured segment to report instead of a whole-path
+    /// boolean match, e.g. `id`. Omit to match the whole path.
+    pub capture: Option<String>,
+}
+
+/// Configuration for [`SimpleBodyInput`].
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[derive(serde::Deserialize, Default)]
+pub struct SimpleBodyInputConfig {
+    /// RFC 6901 JSON pointer into the body, e.g. `/event/type`. Omit for the
+    /// raw body decoded as UTF-8. Also accepts `json_path`, the name this
+    /// config key is more commonly known by elsewhere in the ecosystem.
+    #[serde(alias = "json_path")]
+    pub pointer: Option<String>,
+    /// Bodies larger than this many bytes report no match instead of being
+    /// parsed.
+    pub max_bytes: Option<usize>,
 }
 
 #[cfg(all(feature = "registry", not(feature = "proto")))]
@@ -207,7 +905,19 @@ impl rumi::IntoDataInput<HttpRequest> for SimpleHeaderInput {
     fn from_config(
         config: Self::Config,
     ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
-        Ok(Box::new(SimpleHeaderInput::new(config.name)))
+        let input = match config.value_match {
+            Some(value_match) => {
+                SimpleHeaderInput::with_mode(config.name, config.mode, value_match)
+                    .map_err(|source| rumi::MatcherError::InvalidConfig { source })?
+            }
+            None if config.mode == MatchMode::All => {
+                return Err(rumi::MatcherError::InvalidConfig {
+                    source: "header input with mode \"all\" requires a value_match".to_string(),
+                });
+            }
+            None => SimpleHeaderInput::new(config.name),
+        };
+        Ok(Box::new(input))
     }
 }
 
@@ -218,7 +928,118 @@ impl rumi::IntoDataInput<HttpRequest> for SimpleQueryParamInput {
     fn from_config(
         config: Self::Config,
     ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
-        Ok(Box::new(SimpleQueryParamInput::new(config.name)))
+        let input = match config.value_match {
+            Some(value_match) => {
+                SimpleQueryParamInput::with_mode(config.name, config.mode, value_match)
+                    .map_err(|source| rumi::MatcherError::InvalidConfig { source })?
+            }
+            None => SimpleQueryParamInput::new(config.name),
+        };
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleHeaderAllInput {
+    type Config = SimpleHeaderAllInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        Ok(Box::new(SimpleHeaderAllInput::new(config.name)))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleQueryParamAllInput {
+    type Config = SimpleQueryParamAllInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        Ok(Box::new(SimpleQueryParamAllInput::new(config.name)))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleCookieInput {
+    type Config = SimpleCookieInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        Ok(Box::new(SimpleCookieInput::new(config.name)))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleBasicAuthInput {
+    type Config = SimpleBasicAuthInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        Ok(Box::new(SimpleBasicAuthInput::new(config.field)))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimplePathTemplateInput {
+    type Config = SimplePathTemplateInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        let input = SimplePathTemplateInput::new(&config.template)
+            .map_err(|source| rumi::MatcherError::InvalidConfig { source })?;
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimplePathTemplateParamInput {
+    type Config = SimplePathTemplateParamInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        let input = SimplePathTemplateParamInput::new(&config.template, config.param)
+            .map_err(|source| rumi::MatcherError::InvalidConfig { source })?;
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleRoutePatternInput {
+    type Config = SimpleRoutePatternInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        let input = match config.capture {
+            Some(capture) => SimpleRoutePatternInput::with_capture(&config.template, capture),
+            None => SimpleRoutePatternInput::new(&config.template),
+        }
+        .map_err(|source| rumi::MatcherError::InvalidConfig { source })?;
+        Ok(Box::new(input))
+    }
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+impl rumi::IntoDataInput<HttpRequest> for SimpleBodyInput {
+    type Config = SimpleBodyInputConfig;
+
+    fn from_config(
+        config: Self::Config,
+    ) -> Result<Box<dyn rumi::DataInput<HttpRequest>>, rumi::MatcherError> {
+        let mut input = match config.pointer {
+            Some(pointer) => SimpleBodyInput::with_pointer(pointer),
+            None => SimpleBodyInput::new(),
+        };
+        if let Some(max_bytes) = config.max_bytes {
+            input = input.with_max_bytes(max_bytes);
+        }
+        Ok(Box::new(input))
     }
 }
 
@@ -230,8 +1051,24 @@ impl rumi::IntoDataInput<HttpRequest> for SimpleQueryParamInput {
 /// Registers:
 /// - `xuma.http.v1.PathInput` → [`SimplePathInput`]
 /// - `xuma.http.v1.MethodInput` → [`SimpleMethodInput`]
-/// - `xuma.http.v1.HeaderInput` → [`SimpleHeaderInput`]
-/// - `xuma.http.v1.QueryParamInput` → [`SimpleQueryParamInput`]
+/// - `xuma.http.v1.HeaderInput` → [`SimpleHeaderInput`] (config: `{"name": "..."}`, or
+///   `{"name": "...", "mode": "any"|"all", "value_match": {...}}` to match every value)
+/// - `xuma.http.v1.QueryParamInput` → [`SimpleQueryParamInput`] (same config shape)
+/// - `xuma.http.v1.HeaderAllInput` → [`SimpleHeaderAllInput`] (config: `{"name": "..."}`; all
+///   values joined with `\n`)
+/// - `xuma.http.v1.QueryParamAllInput` → [`SimpleQueryParamAllInput`] (same config shape)
+/// - `xuma.http.v1.PathTemplateInput` → [`SimplePathTemplateInput`] (config: `{"template": "..."}`)
+/// - `xuma.http.v1.PathTemplateParamInput` → [`SimplePathTemplateParamInput`] (config:
+///   `{"template": "...", "param": "id"}`)
+/// - `xuma.http.v1.BodyInput` → [`SimpleBodyInput`] (config: `{}`, or
+///   `{"pointer": "/event/type", "max_bytes": 65536}`; `json_path` is
+///   accepted as an alias for `pointer`)
+/// - `xuma.http.v1.CookieInput` → [`SimpleCookieInput`] (config: `{"name": "session"}`)
+/// - `xuma.http.v1.BasicAuthInput` → [`SimpleBasicAuthInput`] (config:
+///   `{"field": "username"|"password"|"raw"}`)
+/// - `xuma.http.v1.RoutePatternInput` → [`SimpleRoutePatternInput`] (config:
+///   `{"template": "/users/{id}/files/{rest:.*}"}`, or with `"capture": "id"` to
+///   report that segment instead of the whole-path boolean match)
 #[cfg(feature = "registry")]
 #[must_use]
 pub fn register_simple(
@@ -242,6 +1079,76 @@ pub fn register_simple(
         .input::<SimpleMethodInput>("xuma.http.v1.MethodInput")
         .input::<SimpleHeaderInput>("xuma.http.v1.HeaderInput")
         .input::<SimpleQueryParamInput>("xuma.http.v1.QueryParamInput")
+        .input::<SimpleHeaderAllInput>("xuma.http.v1.HeaderAllInput")
+        .input::<SimpleQueryParamAllInput>("xuma.http.v1.QueryParamAllInput")
+        .input::<SimplePathTemplateInput>("xuma.http.v1.PathTemplateInput")
+        .input::<SimplePathTemplateParamInput>("xuma.http.v1.PathTemplateParamInput")
+        .input::<SimpleBodyInput>("xuma.http.v1.BodyInput")
+        .input::<SimpleCookieInput>("xuma.http.v1.CookieInput")
+        .input::<SimpleBasicAuthInput>("xuma.http.v1.BasicAuthInput")
+        .input::<SimpleRoutePatternInput>("xuma.http.v1.RoutePatternInput")
+}
+
+/// Every path param a `xuma.http.v1.PathTemplateInput` predicate anywhere in
+/// `config` captures from `path`, merged across templates.
+///
+/// A compiled [`rumi::Matcher`] tree is opaque — it can tell you whether a
+/// request matched, not which params a `PathTemplateInput` along the way
+/// captured. This walks the `MatcherConfig` the matcher was loaded from
+/// instead (including nested `OnMatchConfig::Matcher` trees), re-compiling
+/// and re-running each `PathTemplateInput` it finds against `path`. Call it
+/// alongside `evaluate()`/`trace()` on the same request to get both the
+/// matched action and the extracted params.
+///
+/// If more than one template captures the same name (unusual, but not
+/// rejected), later templates in tree order win — the reverse of
+/// first-match-wins evaluation, where the earlier rule decides.
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+#[must_use]
+pub fn path_params<A>(
+    config: &rumi::MatcherConfig<A>,
+    path: &str,
+) -> std::collections::BTreeMap<String, String> {
+    let mut params = std::collections::BTreeMap::new();
+    for field in &config.matchers {
+        collect_path_params(&field.predicate, path, &mut params);
+        if let rumi::OnMatchConfig::Matcher { matcher, .. } = &field.on_match {
+            params.extend(path_params(matcher, path));
+        }
+    }
+    params
+}
+
+#[cfg(all(feature = "registry", not(feature = "proto")))]
+fn collect_path_params(
+    predicate: &rumi::PredicateConfig,
+    path: &str,
+    params: &mut std::collections::BTreeMap<String, String>,
+) {
+    match predicate {
+        rumi::PredicateConfig::Single(sp) => {
+            if sp.input.type_url != "xuma.http.v1.PathTemplateInput" {
+                return;
+            }
+            let Ok(config) =
+                serde_json::from_value::<SimplePathTemplateInputConfig>(sp.input.config.clone())
+            else {
+                return;
+            };
+            let Ok(template) = PathTemplate::compile(&config.template) else {
+                return;
+            };
+            if let Some(found) = template.params(path) {
+                params.extend(found);
+            }
+        }
+        rumi::PredicateConfig::And { predicates } | rumi::PredicateConfig::Or { predicates } => {
+            for p in predicates {
+                collect_path_params(p, path, params);
+            }
+        }
+        rumi::PredicateConfig::Not { predicate } => collect_path_params(predicate, path, params),
+    }
 }
 
 #[cfg(test)]
@@ -272,4 +1179,545 @@ mod tests {
         assert_eq!(req.header("x-custom-header"), Some("value"));
         assert_eq!(req.header("X-CUSTOM-HEADER"), Some("value"));
     }
+
+    #[test]
+    fn repeated_headers_and_query_params_keep_every_value() {
+        let req = HttpRequest::builder()
+            .header("Accept", "text/html")
+            .header("Accept", "application/json")
+            .query_param("tag", "a")
+            .query_param("tag", "b")
+            .build();
+
+        assert_eq!(req.header("accept"), Some("text/html"));
+        assert_eq!(
+            req.headers("accept"),
+            &["text/html".to_string(), "application/json".to_string()]
+        );
+        assert_eq!(req.query_param("tag"), Some("a"));
+        assert_eq!(
+            req.query_params("tag"),
+            &["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn header_input_any_mode_matches_if_one_value_satisfies() {
+        let input = SimpleHeaderInput::with_mode(
+            "accept",
+            MatchMode::Any,
+            rumi::StringMatchSpec::Exact("application/json".to_string()),
+        )
+        .unwrap();
+        let req = HttpRequest::builder()
+            .header("Accept", "text/html")
+            .header("Accept", "application/json")
+            .build();
+
+        assert_eq!(input.get(&req), MatchingData::String("accept".to_string()));
+    }
+
+    #[test]
+    fn header_input_all_mode_requires_every_value_to_satisfy() {
+        let input = SimpleHeaderInput::with_mode(
+            "accept",
+            MatchMode::All,
+            rumi::StringMatchSpec::Contains("json".to_string()),
+        )
+        .unwrap();
+
+        let req = HttpRequest::builder()
+            .header("Accept", "application/json")
+            .header("Accept", "text/html")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::None);
+
+        let req = HttpRequest::builder()
+            .header("Accept", "application/json")
+            .header("Accept", "application/ld+json")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("accept".to_string()));
+    }
+
+    #[test]
+    fn query_param_input_mode_is_none_when_missing() {
+        let input = SimpleQueryParamInput::with_mode(
+            "tag",
+            MatchMode::Any,
+            rumi::StringMatchSpec::Exact("admin".to_string()),
+        )
+        .unwrap();
+        let req = HttpRequest::builder().path("/api/users").build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn header_all_input_joins_every_value_with_newline() {
+        let input = SimpleHeaderAllInput::new("accept");
+        let req = HttpRequest::builder()
+            .header("Accept", "text/html")
+            .header("Accept", "application/json")
+            .build();
+
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("text/html\napplication/json".to_string())
+        );
+    }
+
+    #[test]
+    fn header_all_input_is_none_when_missing() {
+        let input = SimpleHeaderAllInput::new("accept");
+        let req = HttpRequest::builder().build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn query_param_all_input_joins_every_value_with_newline() {
+        let input = SimpleQueryParamAllInput::new("tag");
+        let req = HttpRequest::builder()
+            .query_param("tag", "a")
+            .query_param("tag", "b")
+            .build();
+
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("a\nb".to_string())
+        );
+    }
+
+    #[test]
+    fn path_template_input_matches_and_captures() {
+        let input = SimplePathTemplateInput::new("/users/{id}/orders/{orderId}").unwrap();
+        let req = HttpRequest::builder()
+            .path("/users/42/orders/99")
+            .build();
+
+        assert_eq!(input.get(&req), MatchingData::String(req.path().to_string()));
+        let params = input.params(req.path()).unwrap();
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+        assert_eq!(params.get("orderId"), Some(&"99".to_string()));
+    }
+
+    #[test]
+    fn path_template_input_is_none_when_path_does_not_match() {
+        let input = SimplePathTemplateInput::new("/users/{id}").unwrap();
+        let req = HttpRequest::builder().path("/teams/42").build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+        assert!(input.params(req.path()).is_none());
+    }
+
+    #[test]
+    fn path_template_param_input_extracts_one_capture() {
+        let input = SimplePathTemplateParamInput::new("/users/{id}/orders/{orderId}", "id")
+            .unwrap();
+        let req = HttpRequest::builder()
+            .path("/users/42/orders/99")
+            .build();
+
+        assert_eq!(input.get(&req), MatchingData::String("42".to_string()));
+    }
+
+    #[test]
+    fn path_template_param_input_is_none_when_path_does_not_match() {
+        let input = SimplePathTemplateParamInput::new("/users/{id}", "id").unwrap();
+        let req = HttpRequest::builder().path("/teams/42").build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn path_template_param_input_rejects_unknown_param() {
+        let err = SimplePathTemplateParamInput::new("/users/{id}", "userId").unwrap_err();
+        assert!(err.contains("userId"));
+    }
+
+    #[test]
+    fn route_pattern_input_with_no_capture_is_a_whole_path_predicate() {
+        let input = SimpleRoutePatternInput::new("/users/{id}/files/{rest:.*}").unwrap();
+        let req = HttpRequest::builder()
+            .path("/users/42/files/a/b.txt")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String(req.path().to_string()));
+
+        let req = HttpRequest::builder().path("/teams/42").build();
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn route_pattern_input_with_capture_reports_that_segment() {
+        let input =
+            SimpleRoutePatternInput::with_capture("/users/{id}/files/{rest:.*}", "rest").unwrap();
+        let req = HttpRequest::builder()
+            .path("/users/42/files/a/b.txt")
+            .build();
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("a/b.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn route_pattern_input_with_capture_is_none_when_path_does_not_match() {
+        let input = SimpleRoutePatternInput::with_capture("/users/{id}", "id").unwrap();
+        let req = HttpRequest::builder().path("/teams/42").build();
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn route_pattern_input_rejects_unknown_capture() {
+        let err = SimpleRoutePatternInput::with_capture("/users/{id}", "userId").unwrap_err();
+        assert!(err.contains("userId"));
+    }
+
+    #[test]
+    fn route_pattern_input_rejects_an_invalid_template() {
+        let err = SimpleRoutePatternInput::new("/users/{id:(}").unwrap_err();
+        assert!(err.contains("invalid pattern"), "{err}");
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn route_pattern_input_config_deserializes_template_and_capture() {
+        let json = serde_json::json!({
+            "template": "/users/{id}/files/{rest:.*}",
+            "capture": "id",
+        });
+        let config: SimpleRoutePatternInputConfig = serde_json::from_value(json).unwrap();
+        let input =
+            <SimpleRoutePatternInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+                .unwrap();
+
+        let req = HttpRequest::builder()
+            .path("/users/42/files/a/b.txt")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("42".to_string()));
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn path_params_walks_a_matcher_config_for_path_template_inputs() {
+        let json = serde_json::json!({
+            "matchers": [{
+                "predicate": {
+                    "type": "single",
+                    "input": {
+                        "type_url": "xuma.http.v1.PathTemplateInput",
+                        "config": { "template": "/users/{id}" }
+                    },
+                    "value_match": { "Regex": ".*" }
+                },
+                "on_match": { "type": "action", "action": "get_user" }
+            }]
+        });
+        let config: rumi::MatcherConfig<String> = serde_json::from_value(json).unwrap();
+
+        let params = path_params(&config, "/users/42");
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+
+        assert!(path_params(&config, "/teams/42").is_empty());
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn header_input_config_deserializes_any_mode() {
+        let json = serde_json::json!({
+            "name": "tag",
+            "mode": "any",
+            "value_match": { "Exact": "admin" }
+        });
+        let config: SimpleHeaderInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleHeaderInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder()
+            .header("tag", "viewer")
+            .header("tag", "admin")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("tag".to_string()));
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn regex_value_match_works_end_to_end_through_a_hand_written_config() {
+        // `StringMatchSpec::Regex` (and its load-time compile validation) is
+        // already part of `rumi`'s `value_match` — not something this crate
+        // defines — so this just pins down that a hand-written JSON
+        // `MatcherConfig` exercises it the same way proto-sourced configs do
+        // (see `rumi-proto`'s `regex_spec`/`convert_string_matcher`).
+        let registry = register_simple(rumi::RegistryBuilder::new()).build();
+
+        let json = serde_json::json!({
+            "matchers": [{
+                "predicate": {
+                    "type": "single",
+                    "input": { "type_url": "xuma.http.v1.PathInput", "config": {} },
+                    "value_match": { "Regex": "^/api/v[0-9]+/" }
+                },
+                "on_match": { "type": "action", "action": "versioned_api" }
+            }],
+            "on_no_match": { "type": "action", "action": "default" }
+        });
+        let config: rumi::MatcherConfig<String> = serde_json::from_value(json).unwrap();
+        let matcher = registry.load_matcher(config).unwrap();
+
+        let req = HttpRequest::builder().path("/api/v2/users").build();
+        assert_eq!(matcher.evaluate(&req), Some("versioned_api".to_string()));
+
+        let req = HttpRequest::builder().path("/api/users").build();
+        assert_eq!(matcher.evaluate(&req), Some("default".to_string()));
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn header_input_config_defaults_mode_to_any_without_value_match() {
+        let json = serde_json::json!({ "name": "tag" });
+        let config: SimpleHeaderInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleHeaderInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder().header("tag", "admin").build();
+        assert_eq!(input.get(&req), MatchingData::String("admin".to_string()));
+    }
+
+    #[test]
+    fn body_input_with_no_pointer_reports_the_raw_body_as_utf8() {
+        let input = SimpleBodyInput::new();
+        let req = HttpRequest::builder().body(*b"hello world").build();
+
+        assert_eq!(input.get(&req), MatchingData::String("hello world".to_string()));
+    }
+
+    #[test]
+    fn body_input_with_a_pointer_extracts_a_scalar_from_json() {
+        let req = HttpRequest::builder()
+            .body(br#"{"event":{"type":"created","count":3,"urgent":true}}"#.to_vec())
+            .build();
+
+        let input = SimpleBodyInput::with_pointer("/event/type");
+        assert_eq!(input.get(&req), MatchingData::String("created".to_string()));
+
+        let input = SimpleBodyInput::with_pointer("/event/count");
+        assert_eq!(input.get(&req), MatchingData::String("3".to_string()));
+
+        let input = SimpleBodyInput::with_pointer("/event/urgent");
+        assert_eq!(input.get(&req), MatchingData::String("true".to_string()));
+    }
+
+    #[test]
+    fn body_input_pointer_into_a_missing_key_is_none() {
+        let input = SimpleBodyInput::with_pointer("/event/type");
+        let req = HttpRequest::builder().body(br#"{"event":{}}"#.to_vec()).build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn body_input_pointer_against_non_json_body_is_none() {
+        let input = SimpleBodyInput::with_pointer("/event/type");
+        let req = HttpRequest::builder().body(*b"not json").build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn body_input_over_max_bytes_is_none() {
+        let input = SimpleBodyInput::new().with_max_bytes(4);
+        let req = HttpRequest::builder().body(*b"this is too long").build();
+
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn body_input_config_deserializes_pointer_and_max_bytes() {
+        let json = serde_json::json!({ "pointer": "/event/type", "max_bytes": 1024 });
+        let config: SimpleBodyInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleBodyInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder()
+            .body(br#"{"event":{"type":"created"}}"#.to_vec())
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("created".to_string()));
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn body_input_config_defaults_to_raw_body_without_a_pointer() {
+        let json = serde_json::json!({});
+        let config: SimpleBodyInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleBodyInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder().body(*b"raw").build();
+        assert_eq!(input.get(&req), MatchingData::String("raw".to_string()));
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn body_input_config_accepts_json_path_as_a_pointer_alias() {
+        let json = serde_json::json!({ "json_path": "/event/type" });
+        let config: SimpleBodyInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleBodyInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder()
+            .body(br#"{"event":{"type":"created"}}"#.to_vec())
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("created".to_string()));
+    }
+
+    #[test]
+    fn cookie_input_extracts_the_named_cookie() {
+        let req = HttpRequest::builder()
+            .header("Cookie", "session=abc123; theme=dark")
+            .build();
+
+        let input = SimpleCookieInput::new("session");
+        assert_eq!(input.get(&req), MatchingData::String("abc123".to_string()));
+
+        let input = SimpleCookieInput::new("theme");
+        assert_eq!(input.get(&req), MatchingData::String("dark".to_string()));
+    }
+
+    #[test]
+    fn cookie_input_is_none_without_a_cookie_header() {
+        let req = HttpRequest::builder().build();
+        let input = SimpleCookieInput::new("session");
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn cookie_input_is_none_for_a_missing_cookie_name() {
+        let req = HttpRequest::builder()
+            .header("Cookie", "theme=dark")
+            .build();
+        let input = SimpleCookieInput::new("session");
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn cookie_input_uses_the_first_occurrence_of_a_duplicated_name() {
+        let req = HttpRequest::builder()
+            .header("Cookie", "session=first; session=second")
+            .build();
+        let input = SimpleCookieInput::new("session");
+        assert_eq!(input.get(&req), MatchingData::String("first".to_string()));
+    }
+
+    #[test]
+    fn cookie_input_does_not_percent_decode_values() {
+        let req = HttpRequest::builder()
+            .header("Cookie", "session=abc%20123")
+            .build();
+        let input = SimpleCookieInput::new("session");
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("abc%20123".to_string())
+        );
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn cookie_input_config_deserializes_name() {
+        let json = serde_json::json!({ "name": "session" });
+        let config: SimpleCookieInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleCookieInput as rumi::IntoDataInput<HttpRequest>>::from_config(config)
+            .unwrap();
+
+        let req = HttpRequest::builder()
+            .header("Cookie", "session=abc123")
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("abc123".to_string()));
+    }
+
+    fn basic_auth_header(credential: &str) -> String {
+        format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(credential)
+        )
+    }
+
+    #[test]
+    fn basic_auth_input_extracts_username_password_and_raw() {
+        let req = HttpRequest::builder()
+            .header("Authorization", basic_auth_header("alice:hunter2"))
+            .build();
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Username);
+        assert_eq!(input.get(&req), MatchingData::String("alice".to_string()));
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Password);
+        assert_eq!(input.get(&req), MatchingData::String("hunter2".to_string()));
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Raw);
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("alice:hunter2".to_string())
+        );
+    }
+
+    #[test]
+    fn basic_auth_input_is_none_without_an_authorization_header() {
+        let req = HttpRequest::builder().build();
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Username);
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn basic_auth_input_is_none_for_a_non_basic_scheme() {
+        let req = HttpRequest::builder()
+            .header("Authorization", "Bearer abc123")
+            .build();
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Username);
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn basic_auth_input_is_none_for_invalid_base64() {
+        let req = HttpRequest::builder()
+            .header("Authorization", "Basic not-valid-base64!!")
+            .build();
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Username);
+        assert_eq!(input.get(&req), MatchingData::None);
+    }
+
+    #[test]
+    fn basic_auth_input_username_and_password_are_none_without_a_colon() {
+        let req = HttpRequest::builder()
+            .header("Authorization", basic_auth_header("notacredential"))
+            .build();
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Username);
+        assert_eq!(input.get(&req), MatchingData::None);
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Password);
+        assert_eq!(input.get(&req), MatchingData::None);
+
+        let input = SimpleBasicAuthInput::new(BasicAuthField::Raw);
+        assert_eq!(
+            input.get(&req),
+            MatchingData::String("notacredential".to_string())
+        );
+    }
+
+    #[cfg(all(feature = "registry", not(feature = "proto")))]
+    #[test]
+    fn basic_auth_input_config_deserializes_field() {
+        let json = serde_json::json!({ "field": "password" });
+        let config: SimpleBasicAuthInputConfig = serde_json::from_value(json).unwrap();
+        let input = <SimpleBasicAuthInput as rumi::IntoDataInput<HttpRequest>>::from_config(
+            config,
+        )
+        .unwrap();
+
+        let req = HttpRequest::builder()
+            .header("Authorization", basic_auth_header("alice:hunter2"))
+            .build();
+        assert_eq!(input.get(&req), MatchingData::String("hunter2".to_string()));
+    }
 }
@@ -0,0 +1,186 @@
+//! `#[derive(DataInput)]` — generates the `IntoDataInput` registry glue that
+//! every hand-written input (`SimplePathInput`, `SimpleHeaderInput`, …) in
+//! `rumi-http` and friends currently repeats by hand: a `from_config` that
+//! either builds the unit-config case or delegates to `TryFrom<Config>`, plus
+//! a `.input::<T>("type_url")` registration call kept in sync in some
+//! `register_*` function elsewhere.
+//!
+//! ```ignore
+//! #[derive(Default, DataInput)]
+//! #[data_input(ctx = HttpRequest, type_url = "xuma.http.v1.PathInput")]
+//! struct SimplePathInput;
+//!
+//! #[derive(DataInput)]
+//! #[data_input(
+//!     ctx = HttpRequest,
+//!     type_url = "xuma.http.v1.HeaderInput",
+//!     config = SimpleHeaderInputConfig,
+//! )]
+//! struct SimpleHeaderInput { /* ... */ }
+//!
+//! impl TryFrom<SimpleHeaderInputConfig> for SimpleHeaderInput {
+//!     type Error = String;
+//!     fn try_from(config: SimpleHeaderInputConfig) -> Result<Self, String> { /* ... */ }
+//! }
+//! ```
+//!
+//! Without `config`, the derive emits the [`rumi::UnitConfig`] case the
+//! existing unit-struct inputs use today, and the annotated type must
+//! implement `Default`. With `config`, the type must implement
+//! `TryFrom<Config, Error = String>` — the same shape every hand-written
+//! `::new`/`::with_mode` constructor already returns — so the macro only
+//! generates the glue, never the construction logic itself.
+//!
+//! Each derive also submits an [`inventory`] entry so callers don't need to
+//! list every derived type by hand: `register_derived(builder)` walks every
+//! submitted entry for `Ctx` and calls its registration closure, the derive
+//! equivalent of the `register_simple`/`register` functions each crate
+//! already hand-writes. A real Cargo workspace would split the `inventory`
+//! submission and the generic [`register_derived`] helper below into a small
+//! non-proc-macro "support" crate that both `rumi-macros` and its callers
+//! depend on (a `proc-macro = true` crate may only export macros); this
+//! crate keeps both in one place since that split isn't load-bearing for the
+//! design itself.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Ident, LitStr, Path};
+
+/// Re-exported so downstream crates can reach `rumi_macros::inventory::{iter,
+/// submit, collect}` — the derive emits `::rumi_macros::inventory::submit!`
+/// and callers write `rumi_macros::inventory::collect!`, neither of which
+/// resolves against a bare `inventory` dependency that only this crate has.
+pub use inventory;
+
+/// One type registered via `#[derive(DataInput)]` for context type `Ctx`.
+///
+/// `register` is generated per derived type; it calls
+/// `builder.input::<T>(type_url)` for the type the entry was submitted for.
+pub struct DataInputEntry<Ctx: 'static> {
+    pub type_url: &'static str,
+    pub register:
+        fn(rumi::RegistryBuilder<Ctx>) -> rumi::RegistryBuilder<Ctx>,
+}
+
+/// Register every `#[derive(DataInput)]` type submitted for `Ctx`, in
+/// submission order.
+///
+/// `inventory` collects submissions per concrete type, so each `Ctx` a crate
+/// derives `DataInput` for must declare its collection once, typically next
+/// to that `Ctx`'s own `register_simple`/`register`:
+///
+/// ```ignore
+/// rumi_macros::inventory::collect!(rumi_macros::DataInputEntry<HttpRequest>);
+/// ```
+///
+/// Then call this the way you'd call a hand-written `register_simple`/
+/// `register`, typically chained after `rumi::register_core_matchers`:
+///
+/// ```ignore
+/// let builder = register_derived(rumi::register_core_matchers(RegistryBuilder::new()));
+/// ```
+#[must_use]
+pub fn register_derived<Ctx: 'static>(
+    mut builder: rumi::RegistryBuilder<Ctx>,
+) -> rumi::RegistryBuilder<Ctx> {
+    for entry in inventory::iter::<DataInputEntry<Ctx>> {
+        builder = (entry.register)(builder);
+    }
+    builder
+}
+
+#[proc_macro_derive(DataInput, attributes(data_input))]
+pub fn derive_data_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let mut ctx: Option<Path> = None;
+    let mut type_url: Option<LitStr> = None;
+    let mut config: Option<Path> = None;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("data_input") {
+            continue;
+        }
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("ctx") {
+                ctx = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("type_url") {
+                type_url = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("config") {
+                config = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unknown `data_input` key, expected one of: ctx, type_url, config"));
+            }
+            Ok(())
+        });
+        if let Err(err) = result {
+            return err.to_compile_error().into();
+        }
+    }
+
+    let Some(ctx) = ctx else {
+        return syn::Error::new_spanned(ident, "`#[data_input(ctx = ...)]` is required")
+            .to_compile_error()
+            .into();
+    };
+    let Some(type_url) = type_url else {
+        return syn::Error::new_spanned(
+            ident,
+            "`#[data_input(type_url = \"...\")]` is required",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let into_data_input_impl = match config {
+        Some(config) => quote! {
+            impl rumi::IntoDataInput<#ctx> for #ident {
+                type Config = #config;
+
+                fn from_config(
+                    config: Self::Config,
+                ) -> Result<Box<dyn rumi::DataInput<#ctx>>, rumi::MatcherError> {
+                    let input = <#ident as ::std::convert::TryFrom<#config>>::try_from(config)
+                        .map_err(|source| rumi::MatcherError::InvalidConfig { source })?;
+                    Ok(Box::new(input))
+                }
+            }
+        },
+        None => quote! {
+            impl rumi::IntoDataInput<#ctx> for #ident {
+                type Config = rumi::UnitConfig;
+
+                fn from_config(
+                    _: rumi::UnitConfig,
+                ) -> Result<Box<dyn rumi::DataInput<#ctx>>, rumi::MatcherError> {
+                    Ok(Box::new(<#ident as ::std::default::Default>::default()))
+                }
+            }
+        },
+    };
+
+    let register_fn = Ident::new(&format!("__register_data_input_{ident}"), ident.span());
+
+    quote! {
+        #into_data_input_impl
+
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #register_fn(
+            builder: rumi::RegistryBuilder<#ctx>,
+        ) -> rumi::RegistryBuilder<#ctx> {
+            builder.input::<#ident>(#type_url)
+        }
+
+        ::rumi_macros::inventory::submit! {
+            ::rumi_macros::DataInputEntry::<#ctx> {
+                type_url: #type_url,
+                register: #register_fn,
+            }
+        }
+    }
+    .into()
+}
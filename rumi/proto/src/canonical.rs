@@ -0,0 +1,93 @@
+//! Deterministic canonical-JSON serialization for signing/hashing.
+//!
+//! [`ModifyAction`]'s generated `Serialize` impl (see
+//! `gen/xuma/claude/v1/xuma.claude.v1.serde.rs`) is suitable for talking to
+//! another protojson implementation, but `modified_args` is a `HashMap`, so
+//! that impl's map-entry order isn't stable across runs or processes — two
+//! semantically-equal values can serialize to different byte strings. That's
+//! fine for a wire message, but useless as the input to a signature or
+//! content hash over a tool-modification event, where two equal values must
+//! produce *identical* bytes.
+//!
+//! [`ModifyAction::to_canonical_json`] renders that same JSON shape
+//! (lowerCamelCase field names, omitting fields at proto3 default) but with
+//! every map's entries sorted lexicographically by key and no insignificant
+//! whitespace, so it's safe to sign or hash directly. It relies on
+//! `serde_json::Map` being backed by a `BTreeMap` (the default; sorted
+//! regardless of insertion order) rather than sorting by hand, the same way
+//! `serde_json::Value::Object` already guarantees canonical key order as
+//! long as the `preserve_order` feature isn't enabled.
+//!
+//! `modified_args`' values are plain strings here, so there's no float
+//! field to canonicalize — if one is ever added, it should go through
+//! [`crate::protojson::F64Canonical`] before being written into the
+//! canonical object, so `NaN`/`Infinity` render the same way they do over
+//! the wire instead of failing `serde_json` outright.
+
+use serde_json::{Map, Value};
+
+use crate::xuma::claude::v1::ModifyAction;
+
+impl ModifyAction {
+    /// Render `self` as canonical JSON: sorted map keys, no insignificant
+    /// whitespace, so two semantically-equal values always produce
+    /// byte-identical output.
+    pub fn to_canonical_json(&self) -> String {
+        let mut object = Map::new();
+        if !self.message.is_empty() {
+            object.insert("message".to_string(), Value::String(self.message.clone()));
+        }
+        if !self.modified_args.is_empty() {
+            let args: Map<String, Value> = self
+                .modified_args
+                .iter()
+                .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                .collect();
+            object.insert("modifiedArgs".to_string(), Value::Object(args));
+        }
+        serde_json::to_string(&Value::Object(object)).expect("a Map of strings always serializes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn modify_action(message: &str, args: &[(&str, &str)]) -> ModifyAction {
+        ModifyAction {
+            message: message.to_string(),
+            modified_args: args.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn sorts_modified_args_keys_regardless_of_insertion_order() {
+        let a = modify_action("redacted", &[("zeta", "1"), ("alpha", "2")]);
+        let b = modify_action("redacted", &[("alpha", "2"), ("zeta", "1")]);
+        assert_eq!(a.to_canonical_json(), b.to_canonical_json());
+        assert!(a.to_canonical_json().find("alpha").unwrap() < a.to_canonical_json().find("zeta").unwrap());
+    }
+
+    #[test]
+    fn omits_empty_fields() {
+        let action = modify_action("", &[]);
+        assert_eq!(action.to_canonical_json(), "{}");
+    }
+
+    #[test]
+    fn produces_no_insignificant_whitespace() {
+        let action = modify_action("hi", &[("path", "/safe")]);
+        let json = action.to_canonical_json();
+        assert!(!json.contains(' '));
+        assert!(!json.contains('\n'));
+    }
+
+    #[test]
+    fn is_stable_across_repeated_calls() {
+        let action = modify_action("hi", &[("a", "1"), ("b", "2"), ("c", "3")]);
+        let first = action.to_canonical_json();
+        let second = action.to_canonical_json();
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,337 @@
+//! CEL (Common Expression Language) evaluation for [`CelExpression`] /
+//! [`CelExtractString`](crate::xds::r#type::v3::CelExtractString), behind the
+//! `cel` feature.
+//!
+//! `CelExpression` can carry its expression three ways: the non-deprecated
+//! `cel_expr_parsed`/`cel_expr_checked` fields, their deprecated
+//! `expr_specifier` oneof equivalents, or a raw `cel_expr_string`. The first
+//! two hold a `google.api.expr.v1alpha1.Expr`/`CheckedExpr` AST, but this
+//! crate doesn't generate those message types (see `gen/xds/r#type/v3`,
+//! which only has pbjson serde impls, not the underlying prost structs), so
+//! [`CompiledExpression::compile`] can only lower the textual form —
+//! `cel_expr_parsed`/`cel_expr_checked`/`expr_specifier` being set instead
+//! returns [`CelError::UnsupportedAst`] rather than silently ignoring them.
+//!
+//! Compiling parses and type-checks `cel_expr_string` once via the
+//! `cel-interpreter` crate; the resulting [`CompiledExpression`] can then be
+//! [`evaluate`](CompiledExpression::evaluate)d against any number of
+//! activations without re-parsing. [`CelExtractString::extract`] builds on
+//! that: evaluate `expr_extract`, take the result if it's a string, and fall
+//! back to `default_value` (or `""`) on any compile/evaluate error or a
+//! non-string result, matching `xds.type.v3.CelExtractString`'s own
+//! fall-back-on-error semantics.
+//!
+//! This whole module is meant to be wired in behind the feature, as
+//! `#[cfg(feature = "cel")] mod cel;` — this crate's `Cargo.toml` declaring
+//! that feature and the `cel-interpreter` dependency it pulls in, and the
+//! crate root declaring the module, aren't part of this snapshot (this
+//! crate has no `lib.rs` here at all; see the sibling `dsl.rs`/`canonical.rs`/
+//! `protojson.rs`, which have the same gap), but every item below is
+//! already written as if both existed.
+
+use std::collections::HashMap;
+
+use cel_interpreter::{Context, Program, Value as InterpValue};
+
+use crate::xds::r#type::v3::{CelExpression, CelExtractString};
+
+/// A CEL-typed value, either passed in as part of an activation map or
+/// returned from [`CompiledExpression::evaluate`].
+///
+/// This is this crate's own copy of the handful of CEL value kinds rather
+/// than a re-export of `cel_interpreter::Value`, so a caller building an
+/// activation map doesn't need the interpreter crate's types in scope, and
+/// so an interpreter upgrade that adds or renames a `Value` variant doesn't
+/// ripple into every caller's match arms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CelValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    List(Vec<CelValue>),
+    Map(HashMap<String, CelValue>),
+}
+
+impl CelValue {
+    /// The value as a string, if it is one.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CelValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn into_interpreter(self) -> InterpValue {
+        match self {
+            CelValue::Null => InterpValue::Null,
+            CelValue::Bool(b) => InterpValue::Bool(b),
+            CelValue::Int(i) => InterpValue::Int(i),
+            CelValue::UInt(u) => InterpValue::UInt(u),
+            CelValue::Float(f) => InterpValue::Float(f),
+            CelValue::String(s) => InterpValue::String(s.into()),
+            CelValue::Bytes(b) => InterpValue::Bytes(b.into()),
+            CelValue::List(items) => InterpValue::List(
+                items
+                    .into_iter()
+                    .map(CelValue::into_interpreter)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            CelValue::Map(entries) => InterpValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.into_interpreter()))
+                    .collect::<HashMap<_, _>>()
+                    .into(),
+            ),
+        }
+    }
+
+    fn from_interpreter(value: &InterpValue) -> Option<Self> {
+        Some(match value {
+            InterpValue::Null => CelValue::Null,
+            InterpValue::Bool(b) => CelValue::Bool(*b),
+            InterpValue::Int(i) => CelValue::Int(*i),
+            InterpValue::UInt(u) => CelValue::UInt(*u),
+            InterpValue::Float(f) => CelValue::Float(*f),
+            InterpValue::String(s) => CelValue::String(s.to_string()),
+            InterpValue::Bytes(b) => CelValue::Bytes(b.to_vec()),
+            InterpValue::List(items) => CelValue::List(
+                items
+                    .iter()
+                    .map(CelValue::from_interpreter)
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            InterpValue::Map(entries) => CelValue::Map(
+                entries
+                    .map
+                    .iter()
+                    .map(|(k, v)| Some((k.to_string(), CelValue::from_interpreter(v)?)))
+                    .collect::<Option<HashMap<_, _>>>()?,
+            ),
+            // Functions and other non-data values have no `CelValue` equivalent.
+            _ => return None,
+        })
+    }
+}
+
+/// An activation: the variable bindings a [`CompiledExpression`] evaluates
+/// against.
+pub type Activation = HashMap<String, CelValue>;
+
+/// An error compiling or evaluating a [`CelExpression`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CelError {
+    /// `cel_expr_parsed`, `cel_expr_checked`, or the deprecated
+    /// `expr_specifier` oneof was set instead of `cel_expr_string`; see the
+    /// module docs for why this crate can't lower that AST.
+    UnsupportedAst,
+    /// Neither an AST field nor `cel_expr_string` was set.
+    EmptyExpression,
+    /// `cel_expr_string` failed to parse as CEL source.
+    Parse(String),
+    /// Evaluation referenced a name absent from the activation map.
+    UnboundVariable(String),
+    /// Evaluation combined or compared incompatible types.
+    TypeMismatch(String),
+    /// An arithmetic operation over- or under-flowed.
+    Overflow(String),
+    /// Evaluation produced a result this crate has no [`CelValue`] for
+    /// (a function value, for example).
+    UnsupportedResult,
+    /// Any other evaluation failure.
+    Eval(String),
+}
+
+impl std::fmt::Display for CelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CelError::UnsupportedAst => {
+                write!(f, "cel_expr_parsed/cel_expr_checked AST is not supported, only cel_expr_string")
+            }
+            CelError::EmptyExpression => write!(f, "CelExpression has no expression set"),
+            CelError::Parse(msg) => write!(f, "failed to parse CEL expression: {msg}"),
+            CelError::UnboundVariable(msg) => write!(f, "unbound variable: {msg}"),
+            CelError::TypeMismatch(msg) => write!(f, "type mismatch: {msg}"),
+            CelError::Overflow(msg) => write!(f, "arithmetic overflow: {msg}"),
+            CelError::UnsupportedResult => {
+                write!(f, "evaluation produced a value with no CelValue equivalent")
+            }
+            CelError::Eval(msg) => write!(f, "CEL evaluation failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CelError {}
+
+/// A [`CelExpression`]'s `cel_expr_string` compiled once, so the parse/
+/// type-check cost is paid a single time and [`CompiledExpression::evaluate`]
+/// can be called repeatedly against different activations.
+pub struct CompiledExpression {
+    program: Program,
+}
+
+impl CompiledExpression {
+    /// Compile `expr`'s `cel_expr_string`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CelError::UnsupportedAst`] if `expr` carries a parsed/
+    /// checked AST instead, [`CelError::EmptyExpression`] if no expression
+    /// is set at all, or [`CelError::Parse`] if `cel_expr_string` isn't
+    /// valid CEL source.
+    pub fn compile(expr: &CelExpression) -> Result<Self, CelError> {
+        if expr.cel_expr_parsed.is_some()
+            || expr.cel_expr_checked.is_some()
+            || expr.expr_specifier.is_some()
+        {
+            return Err(CelError::UnsupportedAst);
+        }
+        if expr.cel_expr_string.is_empty() {
+            return Err(CelError::EmptyExpression);
+        }
+        let program = Program::compile(&expr.cel_expr_string)
+            .map_err(|err| CelError::Parse(err.to_string()))?;
+        Ok(Self { program })
+    }
+
+    /// Evaluate this expression against `activation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CelError::UnboundVariable`], [`CelError::TypeMismatch`],
+    /// [`CelError::Overflow`], or the catch-all [`CelError::Eval`],
+    /// depending on how evaluation failed; or [`CelError::UnsupportedResult`]
+    /// if it succeeded with a value this crate has no [`CelValue`] for.
+    pub fn evaluate(&self, activation: &Activation) -> Result<CelValue, CelError> {
+        let mut context = Context::default();
+        for (name, value) in activation {
+            context
+                .add_variable(name.as_str(), value.clone().into_interpreter())
+                .map_err(|err| CelError::Eval(err.to_string()))?;
+        }
+        let result = self
+            .program
+            .execute(&context)
+            .map_err(classify_execution_error)?;
+        CelValue::from_interpreter(&result).ok_or(CelError::UnsupportedResult)
+    }
+}
+
+/// Classify an evaluation failure into the matching [`CelError`] variant by
+/// inspecting `cel-interpreter`'s error message: it doesn't expose a typed
+/// error enum a caller could match on directly, and this crate would rather
+/// pay for that with a little string-sniffing here than couple every
+/// caller's error handling to `cel-interpreter`'s internals.
+fn classify_execution_error(err: cel_interpreter::ExecutionError) -> CelError {
+    let message = err.to_string();
+    let lower = message.to_lowercase();
+    if lower.contains("undeclared") || lower.contains("not found") || lower.contains("no such") {
+        CelError::UnboundVariable(message)
+    } else if lower.contains("overflow") {
+        CelError::Overflow(message)
+    } else if lower.contains("type") || lower.contains("unsupported binary") {
+        CelError::TypeMismatch(message)
+    } else {
+        CelError::Eval(message)
+    }
+}
+
+impl CelExtractString {
+    /// Evaluate `expr_extract` against `activation` and coerce the result
+    /// to a string, falling back to `default_value` (or `""`, if that's
+    /// also unset) when compiling, evaluating, or coercing fails.
+    #[must_use]
+    pub fn extract(&self, activation: &Activation) -> String {
+        let evaluated = self
+            .expr_extract
+            .as_ref()
+            .ok_or(CelError::EmptyExpression)
+            .and_then(CompiledExpression::compile)
+            .and_then(|compiled| compiled.evaluate(activation));
+        match evaluated {
+            Ok(CelValue::String(s)) => s,
+            _ => self.default_value.clone().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xds::r#type::v3::CelExpression;
+
+    fn string_expr(src: &str) -> CelExpression {
+        CelExpression {
+            cel_expr_string: src.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn compiles_and_evaluates_cel_expr_string() {
+        let compiled = CompiledExpression::compile(&string_expr("1 + 2")).unwrap();
+        assert_eq!(compiled.evaluate(&Activation::new()).unwrap(), CelValue::Int(3));
+    }
+
+    #[test]
+    fn evaluates_against_an_activation() {
+        let compiled = CompiledExpression::compile(&string_expr("name + \"!\"")).unwrap();
+        let mut activation = Activation::new();
+        activation.insert("name".to_string(), CelValue::String("world".to_string()));
+        assert_eq!(
+            compiled.evaluate(&activation).unwrap(),
+            CelValue::String("world!".to_string())
+        );
+    }
+
+    #[test]
+    fn unbound_variable_is_a_dedicated_error() {
+        let compiled = CompiledExpression::compile(&string_expr("missing")).unwrap();
+        assert!(matches!(
+            compiled.evaluate(&Activation::new()),
+            Err(CelError::UnboundVariable(_))
+        ));
+    }
+
+    #[test]
+    fn ast_expression_is_unsupported() {
+        let expr = CelExpression {
+            cel_expr_checked: Some(Default::default()),
+            ..Default::default()
+        };
+        assert_eq!(CompiledExpression::compile(&expr), Err(CelError::UnsupportedAst));
+    }
+
+    #[test]
+    fn extract_falls_back_to_default_on_error() {
+        let extract = CelExtractString {
+            expr_extract: Some(string_expr("missing")),
+            default_value: Some("fallback".to_string()),
+        };
+        assert_eq!(extract.extract(&Activation::new()), "fallback");
+    }
+
+    #[test]
+    fn extract_falls_back_to_empty_string_with_no_default() {
+        let extract = CelExtractString {
+            expr_extract: None,
+            default_value: None,
+        };
+        assert_eq!(extract.extract(&Activation::new()), "");
+    }
+
+    #[test]
+    fn extract_coerces_non_string_results_to_default() {
+        let extract = CelExtractString {
+            expr_extract: Some(string_expr("1 + 2")),
+            default_value: Some("fallback".to_string()),
+        };
+        assert_eq!(extract.extract(&Activation::new()), "fallback");
+    }
+}
@@ -9,13 +9,20 @@
 //! |-----------|-------------|
 //! | `Matcher` | `MatcherConfig<TypedConfig>` |
 //! | `MatcherList.FieldMatcher` | `FieldMatcherConfig<TypedConfig>` |
+//! | `MatcherTree.exact_match_map` / `prefix_match_map` | one `FieldMatcherConfig` per map entry |
 //! | `Predicate` | `PredicateConfig` |
 //! | `SinglePredicate` | `SinglePredicateConfig` |
 //! | `StringMatcher` | `ValueMatchConfig::BuiltIn(StringMatchSpec)` |
 //! | `TypedExtensionConfig` (matcher) | `ValueMatchConfig::Custom(TypedConfig)` |
 //! | `TypedExtensionConfig` (input) | `TypedConfig` (in `SinglePredicateConfig.input`) |
-//! | `OnMatch::Action` | `OnMatchConfig::Action { action: TypedConfig }` |
-//! | `OnMatch::Matcher` | `OnMatchConfig::Matcher { matcher: Box<...> }` |
+//! | `OnMatch::Action` | `OnMatchConfig::Action { action: TypedConfig, keep_matching, rewrite: None }` |
+//! | `OnMatch::Matcher` | `OnMatchConfig::Matcher { matcher: Box<...>, keep_matching }` |
+//!
+//! `OnMatchConfig::Action.rewrite` (see `rumi::rewrite`) has no xDS proto
+//! counterpart, so conversion always leaves it `None`; it's only reachable
+//! through the JSON/YAML `MatcherConfig` path.
+
+use std::collections::HashMap;
 
 use rumi::{
     ActionRegistry, FieldMatcherConfig, Matcher, MatcherConfig, MatcherError, OnMatchConfig,
@@ -66,8 +73,10 @@ where
 ///
 /// # Supported matcher types
 ///
-/// Currently supports `MatcherList` (linear first-match-wins). `MatcherTree`
-/// support will be added when needed.
+/// Both `MatcherList` (linear first-match-wins) and `MatcherTree` (map
+/// lookup) are supported. `MatcherTree` is lowered into the same
+/// `FieldMatcherConfig` list shape `MatcherList` uses — see
+/// [`convert_matcher_tree`] for how each map variant is flattened.
 ///
 /// # Errors
 ///
@@ -83,10 +92,8 @@ pub fn convert_matcher(
             .iter()
             .map(|fm| convert_field_matcher(fm, resolver))
             .collect::<Result<Vec<_>, _>>()?,
-        Some(proto_matcher::matcher::MatcherType::MatcherTree(_)) => {
-            return Err(MatcherError::InvalidConfig {
-                source: "MatcherTree is not yet supported; use MatcherList".into(),
-            });
+        Some(proto_matcher::matcher::MatcherType::MatcherTree(tree)) => {
+            convert_matcher_tree(tree, matcher.on_no_match.as_deref(), resolver)?
         }
         None => {
             return Err(MatcherError::InvalidConfig {
@@ -107,6 +114,164 @@ pub fn convert_matcher(
     })
 }
 
+/// A pre-built hash-map dispatch table for a maximal run of consecutive
+/// `FieldMatcher`s in a `MatcherList` whose predicates are all an unnested
+/// `SinglePredicate` over the same input with a `StringMatcher` exact-match
+/// pattern — the common "route table" shape, where a linear first-match-wins
+/// scan is wasted work once the table gets large.
+///
+/// Built by [`index_exact_runs`]; see its docs for how runs are detected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactMatchIndex {
+    /// The input every predicate in this run tests.
+    pub input: TypedConfig,
+    /// Index of this run's first entry in the `MatcherList`'s `matchers`.
+    pub start: usize,
+    /// Number of consecutive `matchers` entries this run covers.
+    pub len: usize,
+    /// Case-sensitive exact values, mapped to the `on_match` of the first
+    /// entry in the run that used that value (first-match-wins, so a
+    /// duplicate later in the run is dropped rather than overwriting it).
+    pub exact: HashMap<String, OnMatchConfig<TypedConfig>>,
+    /// `ignore_case` exact values, keyed by their lower-cased form, with the
+    /// same first-match-wins dedup as `exact`.
+    pub ignore_case: HashMap<String, OnMatchConfig<TypedConfig>>,
+}
+
+/// Scan `list` for maximal runs of two or more consecutive `FieldMatcher`s
+/// whose predicate is a bare `SinglePredicate` over the same input with a
+/// `StringMatcher.Exact` pattern, and pre-build a hash-map dispatch table
+/// for each run — analogous to how SSR's search phase indexes candidate
+/// positions before detailed matching, instead of scanning every candidate.
+///
+/// This has to run here, over the raw proto `FieldMatcher`s, rather than
+/// after `convert_field_matcher`: an `ignore_case` exact pattern is lowered
+/// to an anchored `StringMatchSpec::Regex` by [`convert_string_matcher`] (to
+/// keep that type minimal), which would erase the "this was an exact match"
+/// fact this pass depends on. So `index_exact_runs` duplicates just enough
+/// of `convert_single_predicate`'s recognition logic to classify each entry
+/// before that lowering happens; matched entries still go through the
+/// ordinary `convert_on_match` to produce their `OnMatch` payload.
+///
+/// A run breaks on a predicate that isn't a bare exact-match `SinglePredicate`
+/// (nested `and`/`or`/`not`, a different input, a non-`Exact` pattern, or a
+/// `custom_match`), or on a switch to a different input. Runs shorter than 2
+/// entries aren't worth the indirection and are omitted — those (and every
+/// entry outside a returned run) are left for `MatcherConfig`'s ordinary
+/// linear `matchers` list, exactly as `convert_matcher` already produces it;
+/// `index_exact_runs` only *additionally* reports the fast paths within it.
+///
+/// Wiring a returned index into `Matcher::evaluate` — resolving the input
+/// once, consulting `exact`/the case-folded `ignore_case` map, and falling
+/// through to the remaining entries and then `on_no_match` on a miss — is
+/// the runtime engine's job and isn't part of this crate's sources, so this
+/// only detects and builds the tables.
+///
+/// # Errors
+///
+/// Propagates any [`MatcherError`] a covered entry's `on_match` would raise
+/// from [`convert_on_match`] (e.g. an unregistered action type URL).
+pub fn index_exact_runs(
+    list: &proto_matcher::matcher::MatcherList,
+    resolver: &AnyResolver,
+) -> Result<Vec<ExactMatchIndex>, MatcherError> {
+    let mut indexes = Vec::new();
+    let mut start = 0;
+
+    while start < list.matchers.len() {
+        let Some((input, _, _)) = exact_match_key(&list.matchers[start], resolver)? else {
+            start += 1;
+            continue;
+        };
+
+        let mut end = start + 1;
+        let mut exact = HashMap::new();
+        let mut ignore_case = HashMap::new();
+        insert_exact_entry(&list.matchers[start], resolver, &mut exact, &mut ignore_case)?;
+
+        while end < list.matchers.len() {
+            match exact_match_key(&list.matchers[end], resolver)? {
+                Some((next_input, _, _)) if next_input == input => {
+                    insert_exact_entry(&list.matchers[end], resolver, &mut exact, &mut ignore_case)?;
+                    end += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if end - start >= 2 {
+            indexes.push(ExactMatchIndex {
+                input,
+                start,
+                len: end - start,
+                exact,
+                ignore_case,
+            });
+        }
+        start = end;
+    }
+
+    Ok(indexes)
+}
+
+/// If `fm`'s predicate is a bare exact-match `SinglePredicate`, return its
+/// resolved input, the exact value, and whether it's `ignore_case`.
+fn exact_match_key(
+    fm: &proto_matcher::matcher::matcher_list::FieldMatcher,
+    resolver: &AnyResolver,
+) -> Result<Option<(TypedConfig, String, bool)>, MatcherError> {
+    use proto_matcher::matcher::matcher_list::predicate::single_predicate::Matcher as ProtoMatcher;
+    use proto_matcher::matcher::matcher_list::predicate::MatchType;
+    use proto_matcher::string_matcher::MatchPattern;
+
+    let Some(predicate) = fm.predicate.as_ref() else {
+        return Ok(None);
+    };
+    let Some(MatchType::SinglePredicate(sp)) = predicate.match_type.as_ref() else {
+        return Ok(None);
+    };
+    let Some(ProtoMatcher::ValueMatch(string_matcher)) = sp.matcher.as_ref() else {
+        return Ok(None);
+    };
+    let Some(MatchPattern::Exact(value)) = string_matcher.match_pattern.as_ref() else {
+        return Ok(None);
+    };
+
+    let input_ext = sp
+        .input
+        .as_ref()
+        .ok_or_else(|| MatcherError::InvalidConfig {
+            source: "SinglePredicate has no input".into(),
+        })?;
+    let input = resolver.resolve(input_ext)?;
+
+    Ok(Some((input, value.clone(), string_matcher.ignore_case)))
+}
+
+fn insert_exact_entry(
+    fm: &proto_matcher::matcher::matcher_list::FieldMatcher,
+    resolver: &AnyResolver,
+    exact: &mut HashMap<String, OnMatchConfig<TypedConfig>>,
+    ignore_case: &mut HashMap<String, OnMatchConfig<TypedConfig>>,
+) -> Result<(), MatcherError> {
+    let Some((_, value, is_ignore_case)) = exact_match_key(fm, resolver)? else {
+        unreachable!("caller already checked this entry matches exact_match_key");
+    };
+    let on_match = fm
+        .on_match
+        .as_ref()
+        .ok_or_else(|| MatcherError::InvalidConfig {
+            source: "FieldMatcher has no on_match".into(),
+        })?;
+    let on_match = convert_on_match(on_match, resolver)?;
+
+    let key = if is_ignore_case { value.to_lowercase() } else { value };
+    let map = if is_ignore_case { ignore_case } else { exact };
+    map.entry(key).or_insert(on_match);
+
+    Ok(())
+}
+
 fn convert_field_matcher(
     fm: &proto_matcher::matcher::matcher_list::FieldMatcher,
     resolver: &AnyResolver,
@@ -131,6 +296,109 @@ fn convert_field_matcher(
     })
 }
 
+/// Lower a `MatcherTree` into the `FieldMatcherConfig` list shape used by
+/// `MatcherList`, so the rest of the pipeline (registry load, evaluation,
+/// tracing) doesn't need a second code path for map-based matching.
+///
+/// - `exact_match_map`: one `FieldMatcherConfig` per entry, predicate
+///   `StringMatchSpec::Exact(key)`. Entry order doesn't affect the result
+///   (exact matches are mutually exclusive), but entries are emitted in
+///   sorted key order for deterministic, diffable output.
+/// - `prefix_match_map`: one `FieldMatcherConfig` per entry, predicate
+///   `StringMatchSpec::Prefix(key)`, emitted **longest prefix first**
+///   (ties broken by key) so first-match-wins evaluation reproduces
+///   longest-prefix-wins semantics. This ordering is a critical invariant —
+///   emitting prefixes in map iteration order would let a shorter prefix
+///   shadow a more specific longer one.
+/// - `custom_match`: unlike the map variants, the extension owns its own
+///   internal match/on_match resolution (e.g. an IP-trie matcher) and the
+///   xDS schema gives it no per-entry `OnMatch` to convert. It resolves to
+///   a single `FieldMatcherConfig` whose predicate wraps the resolved
+///   extension as `ValueMatchConfig::Custom`; its `on_match` is taken from
+///   the enclosing `Matcher.on_no_match`, since that's the only `OnMatch`
+///   available at this level. A `custom_match` tree with no `on_no_match`
+///   set has nothing to produce on a match, so it's rejected.
+fn convert_matcher_tree(
+    tree: &proto_matcher::matcher::MatcherTree,
+    matcher_on_no_match: Option<&proto_matcher::matcher::OnMatch>,
+    resolver: &AnyResolver,
+) -> Result<Vec<FieldMatcherConfig<TypedConfig>>, MatcherError> {
+    use proto_matcher::matcher::matcher_tree::TreeType;
+
+    let input_ext = tree
+        .input
+        .as_ref()
+        .ok_or_else(|| MatcherError::InvalidConfig {
+            source: "MatcherTree has no input".into(),
+        })?;
+    let input = resolver.resolve(input_ext)?;
+
+    let tree_type = tree
+        .tree_type
+        .as_ref()
+        .ok_or_else(|| MatcherError::InvalidConfig {
+            source: "MatcherTree has no tree_type".into(),
+        })?;
+
+    match tree_type {
+        TreeType::ExactMatchMap(map) => {
+            let mut entries: Vec<_> = map.map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entries
+                .into_iter()
+                .map(|(key, on_match)| {
+                    Ok(FieldMatcherConfig {
+                        predicate: PredicateConfig::Single(SinglePredicateConfig {
+                            input: input.clone(),
+                            matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(
+                                key.clone(),
+                            )),
+                            capture: None,
+                        }),
+                        on_match: convert_on_match(on_match, resolver)?,
+                    })
+                })
+                .collect()
+        }
+        TreeType::PrefixMatchMap(map) => {
+            let mut entries: Vec<_> = map.map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+            entries
+                .into_iter()
+                .map(|(key, on_match)| {
+                    Ok(FieldMatcherConfig {
+                        predicate: PredicateConfig::Single(SinglePredicateConfig {
+                            input: input.clone(),
+                            matcher: ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(
+                                key.clone(),
+                            )),
+                            capture: None,
+                        }),
+                        on_match: convert_on_match(on_match, resolver)?,
+                    })
+                })
+                .collect()
+        }
+        TreeType::CustomMatch(ext) => {
+            let typed = resolver.resolve(ext)?;
+            let on_no_match =
+                matcher_on_no_match.ok_or_else(|| MatcherError::InvalidConfig {
+                    source: "MatcherTree.custom_match has no per-entry on_match; \
+                             the enclosing Matcher must set on_no_match"
+                        .into(),
+                })?;
+            Ok(vec![FieldMatcherConfig {
+                predicate: PredicateConfig::Single(SinglePredicateConfig {
+                    input,
+                    matcher: ValueMatchConfig::Custom(typed),
+                    capture: None,
+                }),
+                on_match: convert_on_match(on_no_match, resolver)?,
+            }])
+        }
+    }
+}
+
 fn convert_predicate(
     pred: &proto_matcher::matcher::matcher_list::Predicate,
     resolver: &AnyResolver,
@@ -198,22 +466,55 @@ fn convert_single_predicate(
         })?;
 
     let matcher = match matcher_oneof {
-        ProtoMatcher::ValueMatch(string_matcher) => {
-            let spec = convert_string_matcher(string_matcher)?;
-            ValueMatchConfig::BuiltIn(spec)
-        }
+        ProtoMatcher::ValueMatch(string_matcher) => convert_string_matcher(string_matcher, resolver)?,
         ProtoMatcher::CustomMatch(ext) => {
             let typed = resolver.resolve(ext)?;
             ValueMatchConfig::Custom(typed)
         }
     };
 
-    Ok(SinglePredicateConfig { input, matcher })
+    // The xDS `SinglePredicate` proto has no capture-name field, so
+    // proto-sourced predicates never bind a capture; only the native
+    // JSON/YAML config path (`SinglePredicateConfig`'s `Deserialize` impl)
+    // can declare one. See `rumi::capture`.
+    Ok(SinglePredicateConfig {
+        input,
+        matcher,
+        capture: None,
+    })
 }
 
+/// Convert a proto `StringMatcher` into a `ValueMatchConfig`.
+///
+/// Three things the naive mapping would get wrong:
+///
+/// - `match_pattern: custom` carries a `TypedExtensionConfig`, just like
+///   `SinglePredicate.custom_match` — it resolves to `ValueMatchConfig::Custom`
+///   via the same `AnyResolver`, rather than being rejected.
+/// - `ignore_case` changes matching semantics and can't be silently dropped
+///   (case handling is security-relevant for things like auth policies).
+///   `StringMatchSpec` has no case-insensitive variants of its own, so
+///   `ignore_case` patterns are lowered to the `Regex` variant instead:
+///   `Exact`/`Prefix`/`Suffix`/`Contains` become an escaped regex with a
+///   `(?i)` inline flag, anchored to match the original pattern (`^…$` for
+///   `Exact`, `^` for `Prefix`, `$` for `Suffix`, unanchored for
+///   `Contains`), and `SafeRegex` gets `(?i)` prepended
+///   directly. This keeps `StringMatchSpec` minimal while still making the
+///   proto → config mapping faithful rather than lossy.
+/// - A malformed `SafeRegex.regex` (or an `ignore_case` pattern that somehow
+///   fails to compile once the flag is folded in) must surface as a
+///   structured [`MatcherError::InvalidConfig`] at conversion time rather
+///   than panicking — or worse, getting discovered lazily the first time a
+///   context happens to hit that branch. So every `Regex` spec this function
+///   produces is compiled right here and discarded; the `Registry` that
+///   eventually loads `StringMatchSpec::Regex` into a runtime matcher is the
+///   one that keeps the compiled automaton around for evaluation, but
+///   validating now means a bad pattern fails the whole `load_proto_matcher`
+///   call instead of surfacing arbitrarily far into a request's lifetime.
 fn convert_string_matcher(
     sm: &proto_matcher::StringMatcher,
-) -> Result<StringMatchSpec, MatcherError> {
+    resolver: &AnyResolver,
+) -> Result<ValueMatchConfig, MatcherError> {
     use proto_matcher::string_matcher::MatchPattern;
 
     let pattern = sm
@@ -223,21 +524,45 @@ fn convert_string_matcher(
             source: "StringMatcher has no match_pattern".into(),
         })?;
 
-    // Note: ignore_case is not directly supported by StringMatchSpec.
-    // The runtime StringMatcher handles case sensitivity at construction time.
-    // For proto → config conversion, we emit the pattern as-is and rely on
-    // the registry's StringMatcher to handle ignore_case when applicable.
-
-    match pattern {
-        MatchPattern::Exact(s) => Ok(StringMatchSpec::Exact(s.clone())),
-        MatchPattern::Prefix(s) => Ok(StringMatchSpec::Prefix(s.clone())),
-        MatchPattern::Suffix(s) => Ok(StringMatchSpec::Suffix(s.clone())),
-        MatchPattern::Contains(s) => Ok(StringMatchSpec::Contains(s.clone())),
-        MatchPattern::SafeRegex(re) => Ok(StringMatchSpec::Regex(re.regex.clone())),
-        MatchPattern::Custom(_) => Err(MatcherError::InvalidConfig {
-            source: "Custom StringMatcher extensions not yet supported".into(),
-        }),
+    if let MatchPattern::Custom(ext) = pattern {
+        let typed = resolver.resolve(ext)?;
+        return Ok(ValueMatchConfig::Custom(typed));
     }
+
+    let ignore_case = sm.ignore_case;
+    let spec = match pattern {
+        MatchPattern::Exact(s) if ignore_case => {
+            regex_spec(format!("(?i)^{}$", regex::escape(s)))?
+        }
+        MatchPattern::Exact(s) => StringMatchSpec::Exact(s.clone()),
+        MatchPattern::Prefix(s) if ignore_case => {
+            regex_spec(format!("(?i)^{}", regex::escape(s)))?
+        }
+        MatchPattern::Prefix(s) => StringMatchSpec::Prefix(s.clone()),
+        MatchPattern::Suffix(s) if ignore_case => {
+            regex_spec(format!("(?i){}$", regex::escape(s)))?
+        }
+        MatchPattern::Suffix(s) => StringMatchSpec::Suffix(s.clone()),
+        MatchPattern::Contains(s) if ignore_case => regex_spec(format!("(?i){}", regex::escape(s)))?,
+        MatchPattern::Contains(s) => StringMatchSpec::Contains(s.clone()),
+        MatchPattern::SafeRegex(re) if ignore_case => regex_spec(format!("(?i){}", re.regex))?,
+        MatchPattern::SafeRegex(re) => regex_spec(re.regex.clone())?,
+        MatchPattern::Custom(_) => unreachable!("handled above"),
+    };
+
+    Ok(ValueMatchConfig::BuiltIn(spec))
+}
+
+/// Validate that `pattern` compiles, then wrap it as a `StringMatchSpec::Regex`.
+///
+/// Compiling here (and discarding the result) turns a malformed pattern into
+/// a load-time [`MatcherError::InvalidConfig`] instead of a panic or a
+/// lazily-discovered evaluation failure.
+fn regex_spec(pattern: String) -> Result<StringMatchSpec, MatcherError> {
+    regex::Regex::new(&pattern).map_err(|e| MatcherError::InvalidConfig {
+        source: format!("invalid regex {pattern:?}: {e}"),
+    })?;
+    Ok(StringMatchSpec::Regex(pattern))
 }
 
 fn convert_on_match(
@@ -256,12 +581,19 @@ fn convert_on_match(
     match on_match {
         ProtoOnMatch::Action(ext) => {
             let typed = resolver.resolve(ext)?;
-            Ok(OnMatchConfig::Action { action: typed })
+            Ok(OnMatchConfig::Action {
+                action: typed,
+                keep_matching: om.keep_matching,
+                // Not part of the xDS `OnMatch` proto — only reachable via
+                // the JSON/YAML `MatcherConfig` path (see `rumi::rewrite`).
+                rewrite: None,
+            })
         }
         ProtoOnMatch::Matcher(nested) => {
             let config = convert_matcher(nested, resolver)?;
             Ok(OnMatchConfig::Matcher {
                 matcher: Box::new(config),
+                keep_matching: om.keep_matching,
             })
         }
     }
@@ -360,7 +692,7 @@ mod tests {
 
         // Check the action
         match &config.matchers[0].on_match {
-            OnMatchConfig::Action { action } => {
+            OnMatchConfig::Action { action, .. } => {
                 assert_eq!(action.type_url, "xuma.core.v1.NamedAction");
                 assert_eq!(action.config["name"], "allow");
             }
@@ -427,7 +759,7 @@ mod tests {
         let config = convert_matcher(&proto, &resolver).unwrap();
         assert!(config.on_no_match.is_some());
         match config.on_no_match.unwrap() {
-            OnMatchConfig::Action { action } => {
+            OnMatchConfig::Action { action, .. } => {
                 assert_eq!(action.config["name"], "miss");
             }
             other => panic!("expected Action, got {other:?}"),
@@ -757,4 +1089,417 @@ mod tests {
             .with("org", "other");
         assert_eq!(matcher.evaluate(&ctx), None);
     }
+
+    fn make_map_on_match(action: &crate::xuma::core::v1::NamedAction) -> proto_matcher::matcher::OnMatch {
+        proto_matcher::matcher::OnMatch {
+            keep_matching: false,
+            on_match: Some(proto_matcher::matcher::on_match::OnMatch::Action(make_ext(
+                "action",
+                "xuma.core.v1.NamedAction",
+                action,
+            ))),
+        }
+    }
+
+    #[test]
+    fn convert_exact_match_map() {
+        let resolver = test_resolver();
+
+        let input_config = crate::xuma::test::v1::StringInput {
+            value: "role".into(),
+        };
+        let admin_action = crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: "allow".into(),
+        };
+        let viewer_action = crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: "deny".into(),
+        };
+
+        let proto = proto_matcher::Matcher {
+            on_no_match: None,
+            matcher_type: Some(proto_matcher::matcher::MatcherType::MatcherTree(
+                proto_matcher::matcher::MatcherTree {
+                    input: Some(make_ext("input", "xuma.test.v1.StringInput", &input_config)),
+                    tree_type: Some(proto_matcher::matcher::matcher_tree::TreeType::ExactMatchMap(
+                        proto_matcher::matcher::matcher_tree::MatchMap {
+                            map: [
+                                ("admin".to_string(), make_map_on_match(&admin_action)),
+                                ("viewer".to_string(), make_map_on_match(&viewer_action)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        },
+                    )),
+                },
+            )),
+        };
+
+        let config = convert_matcher(&proto, &resolver).unwrap();
+        assert_eq!(config.matchers.len(), 2);
+
+        // Emitted in sorted key order: "admin" before "viewer".
+        match &config.matchers[0].predicate {
+            PredicateConfig::Single(sp) => match &sp.matcher {
+                ValueMatchConfig::BuiltIn(StringMatchSpec::Exact(s)) => assert_eq!(s, "admin"),
+                other => panic!("expected Exact, got {other:?}"),
+            },
+            other => panic!("expected Single, got {other:?}"),
+        }
+
+        let registry = rumi_test::register(rumi::RegistryBuilder::new()).build();
+        let actions = test_action_registry();
+        let matcher = load_proto_matcher(&registry, &actions, &resolver, &proto).unwrap();
+
+        let ctx = rumi_test::TestContext::new().with("role", "admin");
+        assert_eq!(matcher.evaluate(&ctx), Some("allow".to_string()));
+        let ctx = rumi_test::TestContext::new().with("role", "viewer");
+        assert_eq!(matcher.evaluate(&ctx), Some("deny".to_string()));
+        let ctx = rumi_test::TestContext::new().with("role", "other");
+        assert_eq!(matcher.evaluate(&ctx), None);
+    }
+
+    #[test]
+    fn convert_prefix_match_map_orders_longest_first() {
+        let resolver = test_resolver();
+
+        let input_config = crate::xuma::test::v1::StringInput {
+            value: "path".into(),
+        };
+        let short_action = crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: "api".into(),
+        };
+        let long_action = crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: "api_v1_admin".into(),
+        };
+
+        let proto = proto_matcher::Matcher {
+            on_no_match: None,
+            matcher_type: Some(proto_matcher::matcher::MatcherType::MatcherTree(
+                proto_matcher::matcher::MatcherTree {
+                    input: Some(make_ext("input", "xuma.test.v1.StringInput", &input_config)),
+                    tree_type: Some(proto_matcher::matcher::matcher_tree::TreeType::PrefixMatchMap(
+                        proto_matcher::matcher::matcher_tree::MatchMap {
+                            // Deliberately inserted shortest-first to prove the
+                            // converter re-sorts rather than trusting map order.
+                            map: [
+                                ("/api".to_string(), make_map_on_match(&short_action)),
+                                ("/api/v1/admin".to_string(), make_map_on_match(&long_action)),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        },
+                    )),
+                },
+            )),
+        };
+
+        let config = convert_matcher(&proto, &resolver).unwrap();
+        assert_eq!(config.matchers.len(), 2);
+
+        // Longest prefix must come first so first-match-wins picks it.
+        match &config.matchers[0].predicate {
+            PredicateConfig::Single(sp) => match &sp.matcher {
+                ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(s)) => {
+                    assert_eq!(s, "/api/v1/admin")
+                }
+                other => panic!("expected Prefix, got {other:?}"),
+            },
+            other => panic!("expected Single, got {other:?}"),
+        }
+
+        let registry = rumi_test::register(rumi::RegistryBuilder::new()).build();
+        let actions = test_action_registry();
+        let matcher = load_proto_matcher(&registry, &actions, &resolver, &proto).unwrap();
+
+        let ctx = rumi_test::TestContext::new().with("path", "/api/v1/admin/users");
+        assert_eq!(matcher.evaluate(&ctx), Some("api_v1_admin".to_string()));
+        let ctx = rumi_test::TestContext::new().with("path", "/api/v2/other");
+        assert_eq!(matcher.evaluate(&ctx), Some("api".to_string()));
+    }
+
+    #[test]
+    fn convert_custom_match_tree_requires_on_no_match() {
+        let resolver = AnyResolverBuilder::new()
+            .register::<crate::xuma::test::v1::StringInput>("xuma.test.v1.StringInput")
+            .register::<crate::xuma::core::v1::NamedAction>("xuma.core.v1.NamedAction")
+            .build();
+
+        let input_config = crate::xuma::test::v1::StringInput {
+            value: "path".into(),
+        };
+
+        let proto = proto_matcher::Matcher {
+            on_no_match: None,
+            matcher_type: Some(proto_matcher::matcher::MatcherType::MatcherTree(
+                proto_matcher::matcher::MatcherTree {
+                    input: Some(make_ext("input", "xuma.test.v1.StringInput", &input_config)),
+                    tree_type: Some(proto_matcher::matcher::matcher_tree::TreeType::CustomMatch(
+                        make_ext("custom", "xuma.test.v1.StringInput", &input_config),
+                    )),
+                },
+            )),
+        };
+
+        let err = convert_matcher(&proto, &resolver).unwrap_err();
+        assert!(matches!(err, MatcherError::InvalidConfig { .. }));
+    }
+
+    #[test]
+    fn convert_string_matcher_ignore_case_lowers_to_regex() {
+        let resolver = test_resolver();
+        let sm = proto_matcher::StringMatcher {
+            ignore_case: true,
+            match_pattern: Some(proto_matcher::string_matcher::MatchPattern::Exact(
+                "Admin".into(),
+            )),
+        };
+        let value_match = convert_string_matcher(&sm, &resolver).unwrap();
+        match value_match {
+            ValueMatchConfig::BuiltIn(StringMatchSpec::Regex(pattern)) => {
+                assert_eq!(pattern, "(?i)^Admin$");
+            }
+            other => panic!("expected Regex, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn convert_string_matcher_ignore_case_prefixes_safe_regex() {
+        let resolver = test_resolver();
+        let sm = proto_matcher::StringMatcher {
+            ignore_case: true,
+            match_pattern: Some(proto_matcher::string_matcher::MatchPattern::SafeRegex(
+                proto_matcher::RegexMatcher {
+                    regex: "^api-.*$".into(),
+                },
+            )),
+        };
+        let value_match = convert_string_matcher(&sm, &resolver).unwrap();
+        match value_match {
+            ValueMatchConfig::BuiltIn(StringMatchSpec::Regex(pattern)) => {
+                assert_eq!(pattern, "(?i)^api-.*$");
+            }
+            other => panic!("expected Regex, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn convert_string_matcher_case_sensitive_is_unaffected() {
+        let resolver = test_resolver();
+        let sm = proto_matcher::StringMatcher {
+            ignore_case: false,
+            match_pattern: Some(proto_matcher::string_matcher::MatchPattern::Prefix(
+                "/api".into(),
+            )),
+        };
+        let value_match = convert_string_matcher(&sm, &resolver).unwrap();
+        match value_match {
+            ValueMatchConfig::BuiltIn(StringMatchSpec::Prefix(s)) => assert_eq!(s, "/api"),
+            other => panic!("expected Prefix, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn convert_string_matcher_custom_resolves_via_resolver() {
+        let resolver = test_resolver();
+        let input_config = crate::xuma::test::v1::StringInput {
+            value: "custom-pattern".into(),
+        };
+        let sm = proto_matcher::StringMatcher {
+            ignore_case: false,
+            match_pattern: Some(proto_matcher::string_matcher::MatchPattern::Custom(
+                make_ext("custom", "xuma.test.v1.StringInput", &input_config),
+            )),
+        };
+        let value_match = convert_string_matcher(&sm, &resolver).unwrap();
+        match value_match {
+            ValueMatchConfig::Custom(typed) => {
+                assert_eq!(typed.type_url, "xuma.test.v1.StringInput");
+                assert_eq!(typed.config["value"], "custom-pattern");
+            }
+            other => panic!("expected Custom, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn convert_string_matcher_rejects_unparseable_regex() {
+        let resolver = test_resolver();
+        let sm = proto_matcher::StringMatcher {
+            ignore_case: false,
+            match_pattern: Some(proto_matcher::string_matcher::MatchPattern::SafeRegex(
+                proto_matcher::RegexMatcher {
+                    regex: "(unterminated".into(),
+                },
+            )),
+        };
+        let err = convert_string_matcher(&sm, &resolver).unwrap_err();
+        match err {
+            MatcherError::InvalidConfig { source } => assert!(source.contains("invalid regex")),
+            other => panic!("expected InvalidConfig, got {other:?}"),
+        }
+    }
+
+    fn exact_field_matcher(
+        input_ext: &TypedExtensionConfig,
+        value: &str,
+        ignore_case: bool,
+        action: &crate::xuma::core::v1::NamedAction,
+    ) -> proto_matcher::matcher::matcher_list::FieldMatcher {
+        proto_matcher::matcher::matcher_list::FieldMatcher {
+            predicate: Some(proto_matcher::matcher::matcher_list::Predicate {
+                match_type: Some(
+                    proto_matcher::matcher::matcher_list::predicate::MatchType::SinglePredicate(
+                        proto_matcher::matcher::matcher_list::predicate::SinglePredicate {
+                            input: Some(input_ext.clone()),
+                            matcher: Some(
+                                proto_matcher::matcher::matcher_list::predicate::single_predicate::Matcher::ValueMatch(
+                                    proto_matcher::StringMatcher {
+                                        ignore_case,
+                                        match_pattern: Some(
+                                            proto_matcher::string_matcher::MatchPattern::Exact(value.into()),
+                                        ),
+                                    },
+                                ),
+                            ),
+                        },
+                    ),
+                ),
+            }),
+            on_match: Some(proto_matcher::matcher::OnMatch {
+                keep_matching: false,
+                on_match: Some(proto_matcher::matcher::on_match::OnMatch::Action(
+                    make_ext("action", "xuma.core.v1.NamedAction", action),
+                )),
+            }),
+        }
+    }
+
+    fn named_action(name: &str) -> crate::xuma::core::v1::NamedAction {
+        crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: name.into(),
+        }
+    }
+
+    #[test]
+    fn index_exact_runs_builds_table_for_run_of_two_or_more() {
+        let resolver = test_resolver();
+        let input_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "role".into() },
+        );
+
+        let list = proto_matcher::matcher::MatcherList {
+            matchers: vec![
+                exact_field_matcher(&input_ext, "admin", false, &named_action("allow")),
+                exact_field_matcher(&input_ext, "viewer", false, &named_action("deny")),
+            ],
+        };
+
+        let indexes = index_exact_runs(&list, &resolver).unwrap();
+        assert_eq!(indexes.len(), 1);
+        let index = &indexes[0];
+        assert_eq!(index.start, 0);
+        assert_eq!(index.len, 2);
+        assert_eq!(index.input.config["value"], "role");
+        assert_eq!(index.exact.len(), 2);
+        match &index.exact["admin"] {
+            OnMatchConfig::Action { action, .. } => assert_eq!(action.config["name"], "allow"),
+            other => panic!("expected Action, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn index_exact_runs_buckets_ignore_case_separately_and_case_folds_key() {
+        let resolver = test_resolver();
+        let input_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "role".into() },
+        );
+
+        let list = proto_matcher::matcher::MatcherList {
+            matchers: vec![
+                exact_field_matcher(&input_ext, "Admin", true, &named_action("allow")),
+                exact_field_matcher(&input_ext, "Viewer", true, &named_action("deny")),
+            ],
+        };
+
+        let indexes = index_exact_runs(&list, &resolver).unwrap();
+        assert_eq!(indexes.len(), 1);
+        let index = &indexes[0];
+        assert!(index.exact.is_empty());
+        assert_eq!(index.ignore_case.len(), 2);
+        assert!(index.ignore_case.contains_key("admin"));
+        assert!(index.ignore_case.contains_key("viewer"));
+    }
+
+    #[test]
+    fn index_exact_runs_skips_runs_shorter_than_two() {
+        let resolver = test_resolver();
+        let input_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "role".into() },
+        );
+
+        let list = proto_matcher::matcher::MatcherList {
+            matchers: vec![exact_field_matcher(&input_ext, "admin", false, &named_action("allow"))],
+        };
+
+        assert!(index_exact_runs(&list, &resolver).unwrap().is_empty());
+    }
+
+    #[test]
+    fn index_exact_runs_breaks_on_different_input() {
+        let resolver = test_resolver();
+        let role_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "role".into() },
+        );
+        let org_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "org".into() },
+        );
+
+        let list = proto_matcher::matcher::MatcherList {
+            matchers: vec![
+                exact_field_matcher(&role_ext, "admin", false, &named_action("allow")),
+                exact_field_matcher(&org_ext, "acme", false, &named_action("deny")),
+            ],
+        };
+
+        // Neither run reaches length 2 on its own input, so no index is produced.
+        assert!(index_exact_runs(&list, &resolver).unwrap().is_empty());
+    }
+
+    #[test]
+    fn index_exact_runs_first_match_wins_on_duplicate_key_within_a_run() {
+        let resolver = test_resolver();
+        let input_ext = make_ext(
+            "input",
+            "xuma.test.v1.StringInput",
+            &crate::xuma::test::v1::StringInput { value: "role".into() },
+        );
+
+        let list = proto_matcher::matcher::MatcherList {
+            matchers: vec![
+                exact_field_matcher(&input_ext, "admin", false, &named_action("first")),
+                exact_field_matcher(&input_ext, "admin", false, &named_action("second")),
+            ],
+        };
+
+        let indexes = index_exact_runs(&list, &resolver).unwrap();
+        assert_eq!(indexes.len(), 1);
+        match &indexes[0].exact["admin"] {
+            OnMatchConfig::Action { action, .. } => assert_eq!(action.config["name"], "first"),
+            other => panic!("expected Action, got {other:?}"),
+        }
+    }
 }
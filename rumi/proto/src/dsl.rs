@@ -0,0 +1,605 @@
+//! A small textual rule language that lowers to the same `proto_matcher::Matcher`
+//! graph `load_proto_matcher` consumes, so authoring a policy doesn't require
+//! hand-building `SinglePredicate`/`AndMatcher`/`OnMatch` trees.
+//!
+//! Modeled on rust-analyzer's SSR rule parser: a lexer produces a token
+//! stream with source spans, a recursive-descent parser turns that into a
+//! small [`Expr`]/[`Rule`]/[`Program`] AST, and [`lower_program`] emits the
+//! existing proto structures — the rest of the pipeline (`convert_matcher`
+//! → `Registry::load_typed_matcher` → `Matcher::evaluate`) is unchanged.
+//!
+//! # Grammar
+//!
+//! ```text
+//! program    := rule* otherwise?
+//! rule       := or_expr "=>" IDENT
+//! otherwise  := "otherwise" "=>" IDENT
+//! or_expr    := and_expr ("or" and_expr)*
+//! and_expr   := unary ("and" unary)*
+//! unary      := "not" unary | atom
+//! atom       := "(" or_expr ")" | IDENT "==" STRING
+//! ```
+//!
+//! Bare input/action identifiers are looked up by name against the `rumi-test`
+//! domain: an input `role` lowers to `xuma.test.v1.StringInput { value: "role" }`
+//! and an action `admin_acme` lowers to `xuma.core.v1.NamedAction { name: "admin_acme" }`
+//! — the same types the rest of this crate's tests already use, so a rule
+//! compiled here runs through `load_proto_matcher` exactly like a
+//! hand-built one.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let matcher = parse_matcher(r#"
+//!     role == "admin" and org == "acme" => admin_acme
+//!     otherwise => deny
+//! "#)?;
+//! ```
+
+use prost::Message;
+
+use crate::xds::r#type::matcher::v3 as proto_matcher;
+
+/// A byte-offset span into the source text, for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A DSL parse error with the source span it occurred at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DslError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl std::fmt::Display for DslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at byte {}..{})",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for DslError {}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Lexer
+// ═══════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    And,
+    Or,
+    Not,
+    Otherwise,
+    EqEq,
+    Arrow,
+    LParen,
+    RParen,
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+fn lex(src: &str) -> Result<Vec<Token>, DslError> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0usize;
+
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        match c {
+            '(' => {
+                tokens.push(Token {
+                    kind: TokenKind::LParen,
+                    span: Span { start, end: start + 1 },
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token {
+                    kind: TokenKind::RParen,
+                    span: Span { start, end: start + 1 },
+                });
+                i += 1;
+            }
+            '=' => {
+                if bytes.get(i + 1) == Some(&b'=') {
+                    tokens.push(Token {
+                        kind: TokenKind::EqEq,
+                        span: Span { start, end: start + 2 },
+                    });
+                    i += 2;
+                } else if bytes.get(i + 1) == Some(&b'>') {
+                    tokens.push(Token {
+                        kind: TokenKind::Arrow,
+                        span: Span { start, end: start + 2 },
+                    });
+                    i += 2;
+                } else {
+                    return Err(DslError {
+                        message: "unexpected '='; did you mean '==' or '=>'?".into(),
+                        span: Span { start, end: start + 1 },
+                    });
+                }
+            }
+            '"' => {
+                let mut j = i + 1;
+                let mut value = String::new();
+                loop {
+                    match bytes.get(j) {
+                        None => {
+                            return Err(DslError {
+                                message: "unterminated string literal".into(),
+                                span: Span { start, end: j },
+                            });
+                        }
+                        Some(b'"') => {
+                            j += 1;
+                            break;
+                        }
+                        Some(_) => {
+                            value.push(bytes[j] as char);
+                            j += 1;
+                        }
+                    }
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Str(value),
+                    span: Span { start, end: j },
+                });
+                i = j;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut j = i;
+                while j < bytes.len() {
+                    let c = bytes[j] as char;
+                    if c.is_alphanumeric() || c == '_' {
+                        j += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let word = &src[i..j];
+                let kind = match word {
+                    "and" => TokenKind::And,
+                    "or" => TokenKind::Or,
+                    "not" => TokenKind::Not,
+                    "otherwise" => TokenKind::Otherwise,
+                    _ => TokenKind::Ident(word.to_string()),
+                };
+                tokens.push(Token {
+                    kind,
+                    span: Span { start, end: j },
+                });
+                i = j;
+            }
+            other => {
+                return Err(DslError {
+                    message: format!("unexpected character '{other}'"),
+                    span: Span { start, end: start + 1 },
+                });
+            }
+        }
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span {
+            start: bytes.len(),
+            end: bytes.len(),
+        },
+    });
+    Ok(tokens)
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// AST
+// ═══════════════════════════════════════════════════════════════════════
+
+/// A boolean predicate expression, as parsed from the DSL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// `input == "literal"`
+    Eq { input: String, value: String },
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+/// A single `predicate => action` rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub predicate: Expr,
+    pub action: String,
+}
+
+/// A parsed program: an ordered list of rules plus an optional
+/// `otherwise => action` fallback.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Program {
+    pub rules: Vec<Rule>,
+    pub otherwise: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Parser (recursive descent)
+// ═══════════════════════════════════════════════════════════════════════
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn advance(&mut self) -> &Token {
+        let tok = &self.tokens[self.pos];
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn expect(&mut self, kind: &TokenKind, what: &str) -> Result<Span, DslError> {
+        let tok = self.peek();
+        if &tok.kind == kind {
+            let span = tok.span;
+            self.advance();
+            Ok(span)
+        } else {
+            Err(DslError {
+                message: format!("expected {what}, found {:?}", tok.kind),
+                span: tok.span,
+            })
+        }
+    }
+
+    fn parse_program(&mut self) -> Result<Program, DslError> {
+        let mut program = Program::default();
+        while self.peek().kind != TokenKind::Eof {
+            if self.peek().kind == TokenKind::Otherwise {
+                self.advance();
+                self.expect(&TokenKind::Arrow, "'=>'")?;
+                program.otherwise = Some(self.parse_ident("action name")?);
+                break;
+            }
+            let predicate = self.parse_or()?;
+            self.expect(&TokenKind::Arrow, "'=>'")?;
+            let action = self.parse_ident("action name")?;
+            program.rules.push(Rule { predicate, action });
+        }
+        Ok(program)
+    }
+
+    fn parse_ident(&mut self, what: &str) -> Result<String, DslError> {
+        match &self.peek().kind {
+            TokenKind::Ident(name) => {
+                let name = name.clone();
+                self.advance();
+                Ok(name)
+            }
+            other => Err(DslError {
+                message: format!("expected {what}, found {other:?}"),
+                span: self.peek().span,
+            }),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, DslError> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek().kind == TokenKind::Or {
+            self.advance();
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::Or(terms)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, DslError> {
+        let mut terms = vec![self.parse_unary()?];
+        while self.peek().kind == TokenKind::And {
+            self.advance();
+            terms.push(self.parse_unary()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expr::And(terms)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, DslError> {
+        if self.peek().kind == TokenKind::Not {
+            self.advance();
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, DslError> {
+        if self.peek().kind == TokenKind::LParen {
+            self.advance();
+            let inner = self.parse_or()?;
+            self.expect(&TokenKind::RParen, "')'")?;
+            return Ok(inner);
+        }
+
+        let input = self.parse_ident("an input name")?;
+        self.expect(&TokenKind::EqEq, "'=='")?;
+        let value = match &self.peek().kind {
+            TokenKind::Str(s) => {
+                let s = s.clone();
+                self.advance();
+                s
+            }
+            other => {
+                return Err(DslError {
+                    message: format!("expected a string literal, found {other:?}"),
+                    span: self.peek().span,
+                })
+            }
+        };
+        Ok(Expr::Eq { input, value })
+    }
+}
+
+/// Parse DSL source into a [`Program`] AST, without lowering to proto.
+pub fn parse_program(src: &str) -> Result<Program, DslError> {
+    let tokens = lex(src)?;
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let program = parser.parse_program()?;
+    match parser.peek().kind {
+        TokenKind::Eof => Ok(program),
+        ref other => Err(DslError {
+            message: format!("unexpected trailing token {other:?}"),
+            span: parser.peek().span,
+        }),
+    }
+}
+
+/// Parse DSL source and lower it directly to a `proto_matcher::Matcher`,
+/// ready for `convert_matcher`/`load_proto_matcher`.
+pub fn parse_matcher(src: &str) -> Result<proto_matcher::Matcher, DslError> {
+    let program = parse_program(src)?;
+    Ok(lower_program(&program))
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Lowering: AST -> proto_matcher
+// ═══════════════════════════════════════════════════════════════════════
+
+fn make_ext<T: Message>(
+    name: &str,
+    type_url: &str,
+    msg: &T,
+) -> crate::xds::core::v3::TypedExtensionConfig {
+    crate::xds::core::v3::TypedExtensionConfig {
+        name: name.into(),
+        typed_config: Some(prost_types::Any {
+            type_url: type_url.into(),
+            value: msg.encode_to_vec().into(),
+        }),
+    }
+}
+
+fn input_ext(name: &str) -> crate::xds::core::v3::TypedExtensionConfig {
+    make_ext(
+        "input",
+        "xuma.test.v1.StringInput",
+        &crate::xuma::test::v1::StringInput { value: name.into() },
+    )
+}
+
+fn action_ext(name: &str) -> crate::xds::core::v3::TypedExtensionConfig {
+    make_ext(
+        "action",
+        "xuma.core.v1.NamedAction",
+        &crate::xuma::core::v1::NamedAction {
+            metadata: Default::default(),
+            name: name.into(),
+        },
+    )
+}
+
+fn named_on_match(action: &str) -> proto_matcher::matcher::OnMatch {
+    proto_matcher::matcher::OnMatch {
+        keep_matching: false,
+        on_match: Some(proto_matcher::matcher::on_match::OnMatch::Action(
+            action_ext(action),
+        )),
+    }
+}
+
+fn lower_expr(expr: &Expr) -> proto_matcher::matcher::matcher_list::Predicate {
+    use proto_matcher::matcher::matcher_list::predicate::{MatchType, SinglePredicate};
+    use proto_matcher::matcher::matcher_list::predicate::single_predicate::Matcher as ProtoMatcher;
+    use proto_matcher::matcher::matcher_list::Predicate;
+    use proto_matcher::matcher::matcher_list::predicate::PredicateList;
+
+    match expr {
+        Expr::Eq { input, value } => Predicate {
+            match_type: Some(MatchType::SinglePredicate(SinglePredicate {
+                input: Some(input_ext(input)),
+                matcher: Some(ProtoMatcher::ValueMatch(proto_matcher::StringMatcher {
+                    ignore_case: false,
+                    match_pattern: Some(proto_matcher::string_matcher::MatchPattern::Exact(
+                        value.clone(),
+                    )),
+                })),
+            })),
+        },
+        Expr::And(terms) => Predicate {
+            match_type: Some(MatchType::AndMatcher(PredicateList {
+                predicate: terms.iter().map(lower_expr).collect(),
+            })),
+        },
+        Expr::Or(terms) => Predicate {
+            match_type: Some(MatchType::OrMatcher(PredicateList {
+                predicate: terms.iter().map(lower_expr).collect(),
+            })),
+        },
+        Expr::Not(inner) => Predicate {
+            match_type: Some(MatchType::NotMatcher(Box::new(lower_expr(inner)))),
+        },
+    }
+}
+
+/// Lower a parsed [`Program`] into the same `proto_matcher::Matcher` shape
+/// a hand-built `MatcherList` would produce.
+pub fn lower_program(program: &Program) -> proto_matcher::Matcher {
+    let matchers = program
+        .rules
+        .iter()
+        .map(|rule| proto_matcher::matcher::matcher_list::FieldMatcher {
+            predicate: Some(lower_expr(&rule.predicate)),
+            on_match: Some(named_on_match(&rule.action)),
+        })
+        .collect();
+
+    proto_matcher::Matcher {
+        matcher_type: Some(proto_matcher::matcher::MatcherType::MatcherList(
+            proto_matcher::matcher::MatcherList { matchers },
+        )),
+        on_no_match: program
+            .otherwise
+            .as_deref()
+            .map(|action| Box::new(named_on_match(action))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lexes_a_simple_rule() {
+        let tokens = lex(r#"role == "admin" => allow"#).unwrap();
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Ident("role".into()),
+                TokenKind::EqEq,
+                TokenKind::Str("admin".into()),
+                TokenKind::Arrow,
+                TokenKind::Ident("allow".into()),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_and_or_not_combinators() {
+        let program =
+            parse_program(r#"role == "admin" and (org == "acme" or not org == "other") => ok"#)
+                .unwrap();
+        assert_eq!(program.rules.len(), 1);
+        match &program.rules[0].predicate {
+            Expr::And(terms) => {
+                assert_eq!(terms.len(), 2);
+                assert!(matches!(terms[0], Expr::Eq { .. }));
+                match &terms[1] {
+                    Expr::Or(inner) => {
+                        assert_eq!(inner.len(), 2);
+                        assert!(matches!(inner[1], Expr::Not(_)));
+                    }
+                    other => panic!("expected Or, got {other:?}"),
+                }
+            }
+            other => panic!("expected And, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_trailing_otherwise_clause() {
+        let program = parse_program(
+            r#"
+            role == "admin" => allow
+            otherwise => deny
+            "#,
+        )
+        .unwrap();
+        assert_eq!(program.rules.len(), 1);
+        assert_eq!(program.otherwise, Some("deny".to_string()));
+    }
+
+    #[test]
+    fn reports_span_on_unterminated_string() {
+        let err = parse_program(r#"role == "admin => allow"#).unwrap_err();
+        assert_eq!(err.span.start, 8);
+    }
+
+    #[test]
+    fn reports_span_on_unexpected_token() {
+        let err = parse_program(r#"role == admin => allow"#).unwrap_err();
+        assert!(err.message.contains("string literal"));
+        assert_eq!(err.span, Span { start: 8, end: 13 });
+    }
+
+    #[test]
+    fn end_to_end_dsl_to_evaluate() {
+        use crate::any_resolver::AnyResolverBuilder;
+
+        let resolver = AnyResolverBuilder::new()
+            .register::<crate::xuma::test::v1::StringInput>("xuma.test.v1.StringInput")
+            .register::<crate::xuma::core::v1::NamedAction>("xuma.core.v1.NamedAction")
+            .build();
+
+        struct NamedActionFactory;
+        impl rumi::IntoAction<String> for NamedActionFactory {
+            type Config = crate::xuma::core::v1::NamedAction;
+            fn from_config(config: Self::Config) -> Result<String, rumi::MatcherError> {
+                Ok(config.name)
+            }
+        }
+        let actions = rumi::ActionRegistryBuilder::new()
+            .action::<NamedActionFactory>("xuma.core.v1.NamedAction")
+            .build();
+        let registry = rumi_test::register(rumi::RegistryBuilder::new()).build();
+
+        let proto = parse_matcher(
+            r#"
+            role == "admin" and org == "acme" => admin_acme
+            otherwise => deny
+            "#,
+        )
+        .unwrap();
+
+        let matcher = crate::convert::load_proto_matcher(&registry, &actions, &resolver, &proto)
+            .unwrap();
+
+        let ctx = rumi_test::TestContext::new()
+            .with("role", "admin")
+            .with("org", "acme");
+        assert_eq!(matcher.evaluate(&ctx), Some("admin_acme".to_string()));
+
+        let ctx = rumi_test::TestContext::new()
+            .with("role", "admin")
+            .with("org", "other");
+        assert_eq!(matcher.evaluate(&ctx), Some("deny".to_string()));
+    }
+}
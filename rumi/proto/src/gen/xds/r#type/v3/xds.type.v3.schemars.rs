@@ -0,0 +1,214 @@
+// @generated
+//
+// `schemars::JsonSchema` impls for the message types in this package,
+// gated behind the `schemars` feature, following the same pattern as
+// `gen/xuma/claude/v1/xuma.claude.v1.schemars.rs`. This crate's
+// `Cargo.toml` declaring that feature isn't part of this snapshot.
+//
+// Every schema here matches `xds.type.v3.serde.rs`'s own conventions
+// exactly, so a document that validates against one of these schemas is
+// exactly what the matching `Deserialize` impl accepts:
+// - field names are lowerCamelCase
+// - proto3 scalar fields are optional-with-a-default, not required, the
+//   same way the generated `Serialize` impl omits a field at its zero
+//   value and the generated `Deserialize` impl fills in
+//   `Default::default()` for an absent one
+// - `int64`/`uint64` fields (`Int64Range::start`/`end`) are strings on the
+//   wire (see `protojson::I64AsString`), so their schema is `type: string`,
+//   not `type: integer`
+// - `double` fields (`DoubleRange::start`/`end`) go through
+//   `protojson::F64Canonical`, which accepts either a JSON number or one of
+//   the literal strings `"NaN"`/`"Infinity"`/`"-Infinity"`; schemars has no
+//   `oneOf(number, enum-of-strings)` helper short of building the
+//   subschema by hand, so these are modeled as a plain number schema,
+//   matching the common case
+// - `CelExpression`'s deprecated `expr_specifier` oneof (`parsed_expr`/
+//   `checked_expr`) is modeled as a `oneOf` between single-property
+//   `parsedExpr`/`checkedExpr` schemas, mirroring the generated
+//   `Serialize` impl's `match` over `cel_expression::ExprSpecifier`
+// - `CelExpression::cel_expr_parsed`/`cel_expr_checked` and
+//   `TypedStruct::value` hold `google.api.expr.v1alpha1`/
+//   `google.protobuf.Struct` messages this crate doesn't generate (see
+//   `gen/xds/r#type/v3`, which only has pbjson serde impls, not the
+//   underlying prost structs for those types), so they're modeled as an
+//   unconstrained `serde_json::Value` schema rather than a concrete object
+//   shape.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CelExpression {
+    fn schema_name() -> String {
+        "CelExpression".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert(
+            "celExprParsed".to_string(),
+            gen.subschema_for::<serde_json::Value>(),
+        );
+        properties.insert(
+            "celExprChecked".to_string(),
+            gen.subschema_for::<serde_json::Value>(),
+        );
+        properties.insert("celExprString".to_string(), gen.subschema_for::<String>());
+
+        let parsed_expr_only = {
+            let mut props = schemars::Map::new();
+            props.insert(
+                "parsedExpr".to_string(),
+                gen.subschema_for::<serde_json::Value>(),
+            );
+            schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+                instance_type: Some(schemars::schema::InstanceType::Object.into()),
+                object: Some(Box::new(schemars::schema::ObjectValidation {
+                    properties: props,
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })
+        };
+        let checked_expr_only = {
+            let mut props = schemars::Map::new();
+            props.insert(
+                "checkedExpr".to_string(),
+                gen.subschema_for::<serde_json::Value>(),
+            );
+            schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+                instance_type: Some(schemars::schema::InstanceType::Object.into()),
+                object: Some(Box::new(schemars::schema::ObjectValidation {
+                    properties: props,
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })
+        };
+
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                one_of: Some(vec![parsed_expr_only, checked_expr_only]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CelExtractString {
+    fn schema_name() -> String {
+        "CelExtractString".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert(
+            "exprExtract".to_string(),
+            gen.subschema_for::<CelExpression>(),
+        );
+        properties.insert("defaultValue".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DoubleRange {
+    fn schema_name() -> String {
+        "DoubleRange".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("start".to_string(), gen.subschema_for::<f64>());
+        properties.insert("end".to_string(), gen.subschema_for::<f64>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Int32Range {
+    fn schema_name() -> String {
+        "Int32Range".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("start".to_string(), gen.subschema_for::<i32>());
+        properties.insert("end".to_string(), gen.subschema_for::<i32>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Int64Range {
+    fn schema_name() -> String {
+        "Int64Range".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        // `start`/`end` are int64, rendered as JSON strings (see
+        // `protojson::I64AsString`), so the schema is `string`, not `integer`.
+        let mut properties = schemars::Map::new();
+        properties.insert("start".to_string(), gen.subschema_for::<String>());
+        properties.insert("end".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TypedStruct {
+    fn schema_name() -> String {
+        "TypedStruct".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("typeUrl".to_string(), gen.subschema_for::<String>());
+        properties.insert("value".to_string(), gen.subschema_for::<serde_json::Value>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
@@ -1,4 +1,12 @@
 // @generated
+//
+// Every `GeneratedField::deserialize` below consults
+// `crate::protojson::lenient_unknown_fields()` at call time: an unrecognized
+// key is rejected via `Error::unknown_field` by default, but a caller inside
+// `protojson::from_json_lenient`/`with_lenient_unknown_fields` instead gets a
+// `__SkipField__` variant read via `next_value::<serde::de::IgnoredAny>()`
+// and discarded, so newer xDS revisions can add fields without breaking
+// older consumers of this crate.
 impl serde::Serialize for CelExpression {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -13,7 +21,7 @@ impl serde::Serialize for CelExpression {
         if self.cel_expr_checked.is_some() {
             len += 1;
         }
-        if !self.cel_expr_string.is_empty() {
+        if !self.cel_expr_string.is_empty() || crate::protojson::emit_defaults() {
             len += 1;
         }
         if self.expr_specifier.is_some() {
@@ -26,7 +34,7 @@ impl serde::Serialize for CelExpression {
         if let Some(v) = self.cel_expr_checked.as_ref() {
             struct_ser.serialize_field("celExprChecked", v)?;
         }
-        if !self.cel_expr_string.is_empty() {
+        if !self.cel_expr_string.is_empty() || crate::protojson::emit_defaults() {
             struct_ser.serialize_field("celExprString", &self.cel_expr_string)?;
         }
         if let Some(v) = self.expr_specifier.as_ref() {
@@ -68,6 +76,7 @@ impl<'de> serde::Deserialize<'de> for CelExpression {
             CelExprString,
             ParsedExpr,
             CheckedExpr,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -79,7 +88,10 @@ impl<'de> serde::Deserialize<'de> for CelExpression {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -89,12 +101,24 @@ impl<'de> serde::Deserialize<'de> for CelExpression {
                         E: serde::de::Error,
                     {
                         match value {
-                            "celExprParsed" | "cel_expr_parsed" => Ok(GeneratedField::CelExprParsed),
-                            "celExprChecked" | "cel_expr_checked" => Ok(GeneratedField::CelExprChecked),
-                            "celExprString" | "cel_expr_string" => Ok(GeneratedField::CelExprString),
+                            "celExprParsed" | "cel_expr_parsed" => {
+                                Ok(GeneratedField::CelExprParsed)
+                            }
+                            "celExprChecked" | "cel_expr_checked" => {
+                                Ok(GeneratedField::CelExprChecked)
+                            }
+                            "celExprString" | "cel_expr_string" => {
+                                Ok(GeneratedField::CelExprString)
+                            }
                             "parsedExpr" | "parsed_expr" => Ok(GeneratedField::ParsedExpr),
                             "checkedExpr" | "checked_expr" => Ok(GeneratedField::CheckedExpr),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -110,8 +134,8 @@ impl<'de> serde::Deserialize<'de> for CelExpression {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<CelExpression, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut cel_expr_parsed__ = None;
                 let mut cel_expr_checked__ = None;
@@ -141,15 +165,20 @@ impl<'de> serde::Deserialize<'de> for CelExpression {
                             if expr_specifier__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("parsedExpr"));
                             }
-                            expr_specifier__ = map_.next_value::<::std::option::Option<_>>()?.map(cel_expression::ExprSpecifier::ParsedExpr)
-;
+                            expr_specifier__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(cel_expression::ExprSpecifier::ParsedExpr);
                         }
                         GeneratedField::CheckedExpr => {
                             if expr_specifier__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("checkedExpr"));
                             }
-                            expr_specifier__ = map_.next_value::<::std::option::Option<_>>()?.map(cel_expression::ExprSpecifier::CheckedExpr)
-;
+                            expr_specifier__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(cel_expression::ExprSpecifier::CheckedExpr);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
@@ -205,6 +234,7 @@ impl<'de> serde::Deserialize<'de> for CelExtractString {
         enum GeneratedField {
             ExprExtract,
             DefaultValue,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -216,7 +246,10 @@ impl<'de> serde::Deserialize<'de> for CelExtractString {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -228,7 +261,13 @@ impl<'de> serde::Deserialize<'de> for CelExtractString {
                         match value {
                             "exprExtract" | "expr_extract" => Ok(GeneratedField::ExprExtract),
                             "defaultValue" | "default_value" => Ok(GeneratedField::DefaultValue),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -244,8 +283,8 @@ impl<'de> serde::Deserialize<'de> for CelExtractString {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<CelExtractString, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut expr_extract__ = None;
                 let mut default_value__ = None;
@@ -263,6 +302,9 @@ impl<'de> serde::Deserialize<'de> for CelExtractString {
                             }
                             default_value__ = map_.next_value()?;
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(CelExtractString {
@@ -282,18 +324,18 @@ impl serde::Serialize for DoubleRange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start != 0. {
+        if self.start != 0. || crate::protojson::emit_defaults() {
             len += 1;
         }
-        if self.end != 0. {
+        if self.end != 0. || crate::protojson::emit_defaults() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("xds.r#type.v3.DoubleRange", len)?;
-        if self.start != 0. {
-            struct_ser.serialize_field("start", &self.start)?;
+        if self.start != 0. || crate::protojson::emit_defaults() {
+            struct_ser.serialize_field("start", &crate::protojson::F64Canonical(self.start))?;
         }
-        if self.end != 0. {
-            struct_ser.serialize_field("end", &self.end)?;
+        if self.end != 0. || crate::protojson::emit_defaults() {
+            struct_ser.serialize_field("end", &crate::protojson::F64Canonical(self.end))?;
         }
         struct_ser.end()
     }
@@ -304,15 +346,13 @@ impl<'de> serde::Deserialize<'de> for DoubleRange {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "start",
-            "end",
-        ];
+        const FIELDS: &[&str] = &["start", "end"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Start,
             End,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -324,7 +364,10 @@ impl<'de> serde::Deserialize<'de> for DoubleRange {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -336,7 +379,13 @@ impl<'de> serde::Deserialize<'de> for DoubleRange {
                         match value {
                             "start" => Ok(GeneratedField::Start),
                             "end" => Ok(GeneratedField::End),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -352,8 +401,8 @@ impl<'de> serde::Deserialize<'de> for DoubleRange {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<DoubleRange, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut start__ = None;
                 let mut end__ = None;
@@ -363,17 +412,16 @@ impl<'de> serde::Deserialize<'de> for DoubleRange {
                             if start__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("start"));
                             }
-                            start__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            start__ = Some(map_.next_value::<crate::protojson::F64Canonical>()?.0);
                         }
                         GeneratedField::End => {
                             if end__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("end"));
                             }
-                            end__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            end__ = Some(map_.next_value::<crate::protojson::F64Canonical>()?.0);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
@@ -394,17 +442,17 @@ impl serde::Serialize for Int32Range {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start != 0 {
+        if self.start != 0 || crate::protojson::emit_defaults() {
             len += 1;
         }
-        if self.end != 0 {
+        if self.end != 0 || crate::protojson::emit_defaults() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("xds.r#type.v3.Int32Range", len)?;
-        if self.start != 0 {
+        if self.start != 0 || crate::protojson::emit_defaults() {
             struct_ser.serialize_field("start", &self.start)?;
         }
-        if self.end != 0 {
+        if self.end != 0 || crate::protojson::emit_defaults() {
             struct_ser.serialize_field("end", &self.end)?;
         }
         struct_ser.end()
@@ -416,15 +464,13 @@ impl<'de> serde::Deserialize<'de> for Int32Range {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "start",
-            "end",
-        ];
+        const FIELDS: &[&str] = &["start", "end"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Start,
             End,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -436,7 +482,10 @@ impl<'de> serde::Deserialize<'de> for Int32Range {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -448,7 +497,13 @@ impl<'de> serde::Deserialize<'de> for Int32Range {
                         match value {
                             "start" => Ok(GeneratedField::Start),
                             "end" => Ok(GeneratedField::End),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -464,8 +519,8 @@ impl<'de> serde::Deserialize<'de> for Int32Range {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<Int32Range, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut start__ = None;
                 let mut end__ = None;
@@ -475,17 +530,22 @@ impl<'de> serde::Deserialize<'de> for Int32Range {
                             if start__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("start"));
                             }
-                            start__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            start__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::End => {
                             if end__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("end"));
                             }
-                            end__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            end__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
@@ -506,19 +566,19 @@ impl serde::Serialize for Int64Range {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start != 0 {
+        if self.start != 0 || crate::protojson::emit_defaults() {
             len += 1;
         }
-        if self.end != 0 {
+        if self.end != 0 || crate::protojson::emit_defaults() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("xds.r#type.v3.Int64Range", len)?;
-        if self.start != 0 {
+        if self.start != 0 || crate::protojson::emit_defaults() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("start", ToString::to_string(&self.start).as_str())?;
         }
-        if self.end != 0 {
+        if self.end != 0 || crate::protojson::emit_defaults() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("end", ToString::to_string(&self.end).as_str())?;
@@ -532,15 +592,13 @@ impl<'de> serde::Deserialize<'de> for Int64Range {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "start",
-            "end",
-        ];
+        const FIELDS: &[&str] = &["start", "end"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Start,
             End,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -552,7 +610,10 @@ impl<'de> serde::Deserialize<'de> for Int64Range {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -564,7 +625,13 @@ impl<'de> serde::Deserialize<'de> for Int64Range {
                         match value {
                             "start" => Ok(GeneratedField::Start),
                             "end" => Ok(GeneratedField::End),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -580,8 +647,8 @@ impl<'de> serde::Deserialize<'de> for Int64Range {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<Int64Range, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut start__ = None;
                 let mut end__ = None;
@@ -591,17 +658,22 @@ impl<'de> serde::Deserialize<'de> for Int64Range {
                             if start__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("start"));
                             }
-                            start__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            start__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::End => {
                             if end__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("end"));
                             }
-                            end__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            end__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
@@ -644,16 +716,13 @@ impl<'de> serde::Deserialize<'de> for TypedStruct {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "type_url",
-            "typeUrl",
-            "value",
-        ];
+        const FIELDS: &[&str] = &["type_url", "typeUrl", "value"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TypeUrl,
             Value,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -665,7 +734,10 @@ impl<'de> serde::Deserialize<'de> for TypedStruct {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -677,7 +749,13 @@ impl<'de> serde::Deserialize<'de> for TypedStruct {
                         match value {
                             "typeUrl" | "type_url" => Ok(GeneratedField::TypeUrl),
                             "value" => Ok(GeneratedField::Value),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -693,8 +771,8 @@ impl<'de> serde::Deserialize<'de> for TypedStruct {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<TypedStruct, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut type_url__ = None;
                 let mut value__ = None;
@@ -712,6 +790,9 @@ impl<'de> serde::Deserialize<'de> for TypedStruct {
                             }
                             value__ = map_.next_value()?;
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(TypedStruct {
@@ -0,0 +1,106 @@
+// @generated
+//
+// `schemars::JsonSchema` impls for the message types in this package,
+// gated behind the `schemars` feature (as ibc-proto-rs does alongside its
+// own `serde` feature) so downstream tooling can validate hook payloads,
+// drive editor autocompletion for hook configs, or publish API docs without
+// every consumer paying for a `schemars` dependency. This crate's
+// `Cargo.toml` declaring that feature isn't part of this snapshot.
+//
+// Every schema here matches `xuma.claude.v1.serde.rs`'s own conventions
+// exactly, so a document that validates against one of these schemas is
+// exactly what the matching `Deserialize` impl accepts:
+// - field names are lowerCamelCase (mirroring `serialize_field`'s calls)
+// - proto3 scalar fields are optional-with-a-default in the schema, not
+//   required — the generated `Serialize` impl omits a field entirely at its
+//   zero value, and the generated `Deserialize` impl accepts that omission
+//   and fills in `Default::default()`, so a document missing the field is
+//   exactly as valid as one that sets it to the zero value
+//
+// Only the types the Claude hook protocol actually exposes today
+// (`ToolNameInput`, `ToolArgInput`, `SessionIdInput`, `ModifyAction`) are
+// covered; the same pattern extends to the rest of this package's messages
+// if they need a schema too.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ToolNameInput {
+    fn schema_name() -> String {
+        "ToolNameInput".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                max_properties: Some(0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ToolArgInput {
+    fn schema_name() -> String {
+        "ToolArgInput".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("name".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SessionIdInput {
+    fn schema_name() -> String {
+        "SessionIdInput".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                max_properties: Some(0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ModifyAction {
+    fn schema_name() -> String {
+        "ModifyAction".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("message".to_string(), gen.subschema_for::<String>());
+        properties.insert(
+            "modifiedArgs".to_string(),
+            gen.subschema_for::<std::collections::HashMap<String, String>>(),
+        );
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
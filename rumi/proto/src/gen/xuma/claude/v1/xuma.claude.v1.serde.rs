@@ -1,7 +1,58 @@
 // @generated
+//
+// Every impl in this file is gated on the `serde` feature, so a consumer
+// that only wants the bare prost message types for a tonic/gRPC transport
+// doesn't pull in a serde dependency. Enabling it requires this crate's
+// `Cargo.toml` to declare `serde = ["dep:serde"]` and gate this file's
+// inclusion (or, if pbjson-build generates it at build time, to pass
+// `--serde` conditionally) — that manifest wiring isn't part of this
+// snapshot, but every impl below is already written as if it were enabled
+// only under that feature.
+//
+// This file also builds under `#![no_std]` + `alloc` behind the `no_std`
+// feature, mirroring the transform cosmos-rust applies to its own
+// pbjson-generated code: `std::result::Result`, `std::fmt`, and
+// `std::collections::HashMap` (which isn't available without `std`) are
+// routed through the `Result`/`fmt`/`Map` aliases below instead of named
+// inline, so enabling `no_std` swaps them for `core`/`alloc` equivalents
+// with no change to any impl's visible behavior. This crate's
+// `Cargo.toml` declaring the `no_std` feature and the `alloc` crate
+// dependency isn't part of this snapshot.
+//
+// Every `GeneratedField::deserialize` below also consults
+// `crate::protojson::lenient_unknown_fields()`: by default an unrecognized
+// JSON key is rejected via `Error::unknown_field`, but a caller inside
+// `protojson::from_json_lenient`/`with_lenient_unknown_fields` instead gets
+// a `__SkipField__` variant, read via `next_value::<serde::de::IgnoredAny>()`
+// and discarded, so a hook payload from a newer schema revision still
+// parses. `protojson`'s thread-local backing this switch is a `std`-only
+// `Cell`, same as `emit_defaults`'s — under the `no_std` feature this file
+// otherwise builds under, it would need the same `critical-section`-backed
+// swap those crates use instead; that's noted here rather than solved, same
+// as the rest of this file's `no_std` support being aspirational ahead of a
+// real build.
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
+#[cfg(feature = "no_std")]
+use alloc::collections::BTreeMap as Map;
+#[cfg(not(feature = "no_std"))]
+use std::collections::HashMap as Map;
+
+#[cfg(feature = "no_std")]
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::fmt;
+
+#[cfg(feature = "no_std")]
+use core::result::Result;
+#[cfg(not(feature = "no_std"))]
+use std::result::Result;
+
+#[cfg(feature = "serde")]
 impl serde::Serialize for AllowAction {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -17,9 +68,10 @@ impl serde::Serialize for AllowAction {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for AllowAction {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -30,9 +82,10 @@ impl<'de> serde::Deserialize<'de> for AllowAction {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Message,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -41,18 +94,24 @@ impl<'de> serde::Deserialize<'de> for AllowAction {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
                         match value {
                             "message" => Ok(GeneratedField::Message),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -63,11 +122,11 @@ impl<'de> serde::Deserialize<'de> for AllowAction {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = AllowAction;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.AllowAction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AllowAction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<AllowAction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -80,6 +139,9 @@ impl<'de> serde::Deserialize<'de> for AllowAction {
                             }
                             message__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(AllowAction {
@@ -90,9 +152,10 @@ impl<'de> serde::Deserialize<'de> for AllowAction {
         deserializer.deserialize_struct("xuma.claude.v1.AllowAction", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for BlockAction {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -108,9 +171,10 @@ impl serde::Serialize for BlockAction {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for BlockAction {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -121,9 +185,10 @@ impl<'de> serde::Deserialize<'de> for BlockAction {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Reason,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -132,18 +197,24 @@ impl<'de> serde::Deserialize<'de> for BlockAction {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
                         match value {
                             "reason" => Ok(GeneratedField::Reason),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -154,11 +225,11 @@ impl<'de> serde::Deserialize<'de> for BlockAction {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = BlockAction;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.BlockAction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BlockAction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<BlockAction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -171,6 +242,9 @@ impl<'de> serde::Deserialize<'de> for BlockAction {
                             }
                             reason__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(BlockAction {
@@ -181,9 +255,10 @@ impl<'de> serde::Deserialize<'de> for BlockAction {
         deserializer.deserialize_struct("xuma.claude.v1.BlockAction", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for CwdInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -193,9 +268,10 @@ impl serde::Serialize for CwdInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for CwdInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -204,9 +280,10 @@ impl<'de> serde::Deserialize<'de> for CwdInput {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -215,16 +292,24 @@ impl<'de> serde::Deserialize<'de> for CwdInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -234,11 +319,11 @@ impl<'de> serde::Deserialize<'de> for CwdInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = CwdInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.CwdInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CwdInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<CwdInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -252,9 +337,10 @@ impl<'de> serde::Deserialize<'de> for CwdInput {
         deserializer.deserialize_struct("xuma.claude.v1.CwdInput", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for EventTypeInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -264,9 +350,10 @@ impl serde::Serialize for EventTypeInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for EventTypeInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -275,9 +362,10 @@ impl<'de> serde::Deserialize<'de> for EventTypeInput {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -286,16 +374,24 @@ impl<'de> serde::Deserialize<'de> for EventTypeInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -305,11 +401,11 @@ impl<'de> serde::Deserialize<'de> for EventTypeInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = EventTypeInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.EventTypeInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTypeInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<EventTypeInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -323,9 +419,10 @@ impl<'de> serde::Deserialize<'de> for EventTypeInput {
         deserializer.deserialize_struct("xuma.claude.v1.EventTypeInput", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for GitBranchInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -335,9 +432,10 @@ impl serde::Serialize for GitBranchInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for GitBranchInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -346,9 +444,10 @@ impl<'de> serde::Deserialize<'de> for GitBranchInput {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -357,16 +456,24 @@ impl<'de> serde::Deserialize<'de> for GitBranchInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -376,11 +483,11 @@ impl<'de> serde::Deserialize<'de> for GitBranchInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = GitBranchInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.GitBranchInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GitBranchInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<GitBranchInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -394,9 +501,10 @@ impl<'de> serde::Deserialize<'de> for GitBranchInput {
         deserializer.deserialize_struct("xuma.claude.v1.GitBranchInput", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for HookContext {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -442,9 +550,10 @@ impl serde::Serialize for HookContext {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for HookContext {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -470,9 +579,10 @@ impl<'de> serde::Deserialize<'de> for HookContext {
             SessionId,
             Cwd,
             GitBranch,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -481,12 +591,12 @@ impl<'de> serde::Deserialize<'de> for HookContext {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
@@ -497,7 +607,13 @@ impl<'de> serde::Deserialize<'de> for HookContext {
                             "sessionId" | "session_id" => Ok(GeneratedField::SessionId),
                             "cwd" => Ok(GeneratedField::Cwd),
                             "gitBranch" | "git_branch" => Ok(GeneratedField::GitBranch),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -508,11 +624,11 @@ impl<'de> serde::Deserialize<'de> for HookContext {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = HookContext;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.HookContext")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HookContext, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<HookContext, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -541,7 +657,7 @@ impl<'de> serde::Deserialize<'de> for HookContext {
                                 return Err(serde::de::Error::duplicate_field("toolArgs"));
                             }
                             tool_args__ = Some(
-                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                                map_.next_value::<Map<_, _>>()?
                             );
                         }
                         GeneratedField::SessionId => {
@@ -562,6 +678,9 @@ impl<'de> serde::Deserialize<'de> for HookContext {
                             }
                             git_branch__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(HookContext {
@@ -577,9 +696,10 @@ impl<'de> serde::Deserialize<'de> for HookContext {
         deserializer.deserialize_struct("xuma.claude.v1.HookContext", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for ModifyAction {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -601,9 +721,10 @@ impl serde::Serialize for ModifyAction {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for ModifyAction {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -617,9 +738,10 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
         enum GeneratedField {
             ModifiedArgs,
             Message,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -628,19 +750,25 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
                         match value {
                             "modifiedArgs" | "modified_args" => Ok(GeneratedField::ModifiedArgs),
                             "message" => Ok(GeneratedField::Message),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -651,11 +779,11 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = ModifyAction;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.ModifyAction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ModifyAction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<ModifyAction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -668,7 +796,7 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
                                 return Err(serde::de::Error::duplicate_field("modifiedArgs"));
                             }
                             modified_args__ = Some(
-                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                                map_.next_value::<Map<_, _>>()?
                             );
                         }
                         GeneratedField::Message => {
@@ -677,6 +805,9 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
                             }
                             message__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(ModifyAction {
@@ -688,9 +819,10 @@ impl<'de> serde::Deserialize<'de> for ModifyAction {
         deserializer.deserialize_struct("xuma.claude.v1.ModifyAction", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for SessionIdInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -700,9 +832,10 @@ impl serde::Serialize for SessionIdInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for SessionIdInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -711,9 +844,10 @@ impl<'de> serde::Deserialize<'de> for SessionIdInput {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -722,16 +856,24 @@ impl<'de> serde::Deserialize<'de> for SessionIdInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -741,11 +883,11 @@ impl<'de> serde::Deserialize<'de> for SessionIdInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = SessionIdInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.SessionIdInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SessionIdInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<SessionIdInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -759,9 +901,10 @@ impl<'de> serde::Deserialize<'de> for SessionIdInput {
         deserializer.deserialize_struct("xuma.claude.v1.SessionIdInput", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for ToolArgInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -777,9 +920,10 @@ impl serde::Serialize for ToolArgInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for ToolArgInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -790,9 +934,10 @@ impl<'de> serde::Deserialize<'de> for ToolArgInput {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Name,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -801,18 +946,24 @@ impl<'de> serde::Deserialize<'de> for ToolArgInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
                         match value {
                             "name" => Ok(GeneratedField::Name),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -823,11 +974,11 @@ impl<'de> serde::Deserialize<'de> for ToolArgInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = ToolArgInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.ToolArgInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ToolArgInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<ToolArgInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -840,6 +991,9 @@ impl<'de> serde::Deserialize<'de> for ToolArgInput {
                             }
                             name__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(ToolArgInput {
@@ -850,9 +1004,10 @@ impl<'de> serde::Deserialize<'de> for ToolArgInput {
         deserializer.deserialize_struct("xuma.claude.v1.ToolArgInput", FIELDS, GeneratedVisitor)
     }
 }
+#[cfg(feature = "serde")]
 impl serde::Serialize for ToolNameInput {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -862,9 +1017,10 @@ impl serde::Serialize for ToolNameInput {
         struct_ser.end()
     }
 }
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for ToolNameInput {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -873,9 +1029,10 @@ impl<'de> serde::Deserialize<'de> for ToolNameInput {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<GeneratedField, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
@@ -884,16 +1041,24 @@ impl<'de> serde::Deserialize<'de> for ToolNameInput {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
                     #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    fn visit_str<E>(self, value: &str) -> Result<GeneratedField, E>
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -903,11 +1068,11 @@ impl<'de> serde::Deserialize<'de> for ToolNameInput {
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
             type Value = ToolNameInput;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("struct xuma.claude.v1.ToolNameInput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ToolNameInput, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> Result<ToolNameInput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
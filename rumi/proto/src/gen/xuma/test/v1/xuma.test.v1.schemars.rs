@@ -0,0 +1,108 @@
+// @generated
+//
+// `schemars::JsonSchema` impls for the message types in this package,
+// gated behind the `schemars` feature, following the same pattern as
+// `gen/xuma/claude/v1/xuma.claude.v1.schemars.rs` and
+// `gen/xds/r#type/v3/xds.type.v3.schemars.rs`. This crate's `Cargo.toml`
+// declaring that feature isn't part of this snapshot.
+//
+// Every schema here matches `xuma.test.v1.serde.rs`'s own conventions
+// exactly, so a document that validates against one of these schemas is
+// exactly what the matching `Deserialize` impl accepts:
+// - field names are lowerCamelCase
+// - proto3 scalar fields are optional-with-a-default, not required, the
+//   same way the generated `Serialize` impl omits a field at its zero
+//   value and the generated `Deserialize` impl fills in
+//   `Default::default()` for an absent one
+// - `TestContext::bytes_value` is a `bytes` field, rendered as a base64
+//   string (see `crate::pbjson::base64::encode`), so its schema is
+//   `type: string`, not an array of integers
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ConstantInput {
+    fn schema_name() -> String {
+        "ConstantInput".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("value".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MapInput {
+    fn schema_name() -> String {
+        "MapInput".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("key".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for StringInput {
+    fn schema_name() -> String {
+        "StringInput".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("value".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TestContext {
+    fn schema_name() -> String {
+        "TestContext".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut properties = schemars::Map::new();
+        properties.insert("stringValue".to_string(), gen.subschema_for::<String>());
+        properties.insert(
+            "mapValues".to_string(),
+            gen.subschema_for::<std::collections::HashMap<String, String>>(),
+        );
+        // `bytes`, base64-encoded on the wire (see `crate::pbjson::base64`).
+        properties.insert("bytesValue".to_string(), gen.subschema_for::<String>());
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(schemars::schema::ObjectValidation {
+                properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
@@ -1,4 +1,17 @@
 // @generated
+//
+// Every `GeneratedField::deserialize` below rejects an unrecognized JSON key
+// via `Error::unknown_field` by default, matching pbjson-build's normal
+// output. Parsing inside `crate::protojson::from_json_lenient` (or
+// `with_lenient_unknown_fields`) instead routes an unrecognized key to a
+// `__SkipField__` variant, read via `next_value::<serde::de::IgnoredAny>()`
+// and discarded rather than failing the whole payload, so a consumer on an
+// older schema can still parse a message a newer producer has added fields
+// to. This mirrors pbjson-build's own `--ignore-unknown-fields` build flag,
+// but as a runtime switch rather than a build-time one, since the same
+// generated impl needs to serve both a strict caller (tests) and a lenient
+// one (a server tolerating a newer control plane) without two builds of the
+// crate.
 impl serde::Serialize for ConstantInput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -30,6 +43,7 @@ impl<'de> serde::Deserialize<'de> for ConstantInput {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Value,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -52,7 +66,13 @@ impl<'de> serde::Deserialize<'de> for ConstantInput {
                     {
                         match value {
                             "value" => Ok(GeneratedField::Value),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -80,6 +100,9 @@ impl<'de> serde::Deserialize<'de> for ConstantInput {
                             }
                             value__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(ConstantInput {
@@ -121,6 +144,7 @@ impl<'de> serde::Deserialize<'de> for MapInput {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Key,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -143,7 +167,13 @@ impl<'de> serde::Deserialize<'de> for MapInput {
                     {
                         match value {
                             "key" => Ok(GeneratedField::Key),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -171,6 +201,9 @@ impl<'de> serde::Deserialize<'de> for MapInput {
                             }
                             key__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(MapInput {
@@ -212,6 +245,7 @@ impl<'de> serde::Deserialize<'de> for StringInput {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Value,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -234,7 +268,13 @@ impl<'de> serde::Deserialize<'de> for StringInput {
                     {
                         match value {
                             "value" => Ok(GeneratedField::Value),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -262,6 +302,9 @@ impl<'de> serde::Deserialize<'de> for StringInput {
                             }
                             value__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(StringInput {
@@ -299,7 +342,7 @@ impl serde::Serialize for TestContext {
         if !self.bytes_value.is_empty() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("bytesValue", pbjson::private::base64::encode(&self.bytes_value).as_str())?;
+            struct_ser.serialize_field("bytesValue", crate::pbjson::base64::encode(&self.bytes_value).as_str())?;
         }
         struct_ser.end()
     }
@@ -324,6 +367,7 @@ impl<'de> serde::Deserialize<'de> for TestContext {
             StringValue,
             MapValues,
             BytesValue,
+            __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -348,7 +392,13 @@ impl<'de> serde::Deserialize<'de> for TestContext {
                             "stringValue" | "string_value" => Ok(GeneratedField::StringValue),
                             "mapValues" | "map_values" => Ok(GeneratedField::MapValues),
                             "bytesValue" | "bytes_value" => Ok(GeneratedField::BytesValue),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                            _ => {
+                                if crate::protojson::lenient_unknown_fields() {
+                                    Ok(GeneratedField::__SkipField__)
+                                } else {
+                                    Err(serde::de::Error::unknown_field(value, FIELDS))
+                                }
+                            }
                         }
                     }
                 }
@@ -390,10 +440,13 @@ impl<'de> serde::Deserialize<'de> for TestContext {
                             if bytes_value__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("bytesValue"));
                             }
-                            bytes_value__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            bytes_value__ =
+                                Some(map_.next_value::<crate::pbjson::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
                 Ok(TestContext {
@@ -0,0 +1,88 @@
+//! Internal stand-in for the `pbjson` crate's `pbjson::private` helpers.
+//!
+//! Real pbjson-build output reaches for `::pbjson::private::base64::encode`
+//! and `::pbjson::private::BytesDeserialize` on every `bytes` field (see
+//! `TestContext::bytes_value` in `gen/xuma/test/v1/xuma.test.v1.serde.rs`,
+//! written as if that build step had run). That's fine for one message, but
+//! pulling in the real crate just to reach its `private` module for every
+//! `bytes` field this tree grows means the base64 leniency rules
+//! ([`crate::protojson::decode_bytes`]'s standard/URL-safe/padding
+//! acceptance) live in two places that can drift. This module is the single
+//! place generated impls reach for `bytes` handling instead, built on top of
+//! [`crate::protojson`]'s primitives, so every `bytes` field in the tree
+//! round-trips the same way regardless of which message it's on.
+//!
+//! Named and shaped to mirror `pbjson::private` so a generated impl's
+//! `bytes` handling reads the same whether it calls the real crate or this
+//! one — swapping one for the other is a one-line import change, not a
+//! rewrite.
+
+use crate::protojson;
+
+/// Mirrors `pbjson::private::base64`'s free functions.
+pub mod base64 {
+    use super::protojson;
+
+    /// Encodes `bytes` as protojson's standard base64 string.
+    pub fn encode(data: impl AsRef<[u8]>) -> String {
+        protojson::encode_bytes(data.as_ref())
+    }
+}
+
+/// Deserializes a `bytes` field from a base64 string into any `T: From<Vec<u8>>`
+/// (`Vec<u8>` itself, or a newtype wrapping one), accepting standard or
+/// URL-safe base64 with or without padding via [`protojson::decode_bytes`].
+/// Mirrors `pbjson::private::BytesDeserialize`'s role in real
+/// pbjson-build-generated code: a generated `visit_map` loop does
+/// `map_.next_value::<crate::pbjson::BytesDeserialize<_>>()?.0`.
+pub struct BytesDeserialize<T>(pub T);
+
+impl<'de, T: From<Vec<u8>>> serde::Deserialize<'de> for BytesDeserialize<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a base64-encoded string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                protojson::decode_bytes(v).map_err(E::custom)
+            }
+        }
+
+        deserializer
+            .deserialize_str(Visitor)
+            .map(T::from)
+            .map(BytesDeserialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_encode_matches_protojson_standard_base64() {
+        assert_eq!(base64::encode(b"hello"), protojson::encode_bytes(b"hello"));
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_bytes")] Vec<u8>);
+
+    fn deserialize_bytes<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        Ok(BytesDeserialize::<Vec<u8>>::deserialize(deserializer)?.0)
+    }
+
+    #[test]
+    fn bytes_deserialize_accepts_standard_and_url_safe_base64() {
+        let standard: Wrapper = serde_json::from_str("\"aGVsbG8=\"").unwrap();
+        let url_safe_no_pad: Wrapper = serde_json::from_str("\"aGVsbG8\"").unwrap();
+        assert_eq!(standard, Wrapper(b"hello".to_vec()));
+        assert_eq!(url_safe_no_pad, Wrapper(b"hello".to_vec()));
+    }
+}